@@ -0,0 +1,80 @@
+//! CPI interface for the `opportunity_market` program.
+//!
+//! Depends on the program with its `cpi` feature, so callers get the generated
+//! `cpi` module and account structs without pulling in the entrypoint.
+
+use anchor_lang::prelude::*;
+use anchor_lang::Event;
+
+pub use opportunity_market::computation::derive_computation_offset;
+pub use opportunity_market::constants;
+pub use opportunity_market::cpi;
+pub use opportunity_market::cpi::accounts;
+pub use opportunity_market::events;
+pub use opportunity_market::state;
+pub use opportunity_market::ErrorCode;
+pub use opportunity_market::ID;
+
+/// Reads the `schema_version` of a serialized account or event, which every layout stores
+/// right after its 8-byte discriminator. Compare against [`constants::SCHEMA_VERSION`]
+/// before decoding data that may have been written by another program version.
+pub fn schema_version(data: &[u8]) -> Option<u8> {
+    data.get(8).copied()
+}
+
+/// Decodes an event from its serialized form (discriminator followed by the event data).
+/// Returns `None` if the discriminator doesn't match `E` or the data is malformed.
+pub fn decode_event<E: Event>(data: &[u8]) -> Option<E> {
+    let payload = data.strip_prefix(E::DISCRIMINATOR)?;
+    E::try_from_slice(payload).ok()
+}
+
+/// Decodes an event from the data of the program's event self-CPI inner instruction.
+pub fn decode_cpi_event<E: Event>(ix_data: &[u8]) -> Option<E> {
+    decode_event(ix_data.strip_prefix(anchor_lang::event::EVENT_IX_TAG_LE)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{MarketOpenedEvent, StakeRevealedEvent};
+
+    fn market_opened() -> MarketOpenedEvent {
+        MarketOpenedEvent {
+            schema_version: constants::SCHEMA_VERSION,
+            market: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            stake_end_timestamp: 42,
+            timestamp: 7,
+        }
+    }
+
+    #[test]
+    fn decode_event_round_trip() {
+        let event = market_opened();
+        let decoded: MarketOpenedEvent = decode_event(&event.data()).unwrap();
+        assert_eq!(decoded.market, event.market);
+        assert_eq!(decoded.creator, event.creator);
+        assert_eq!(decoded.stake_end_timestamp, 42);
+        assert_eq!(decoded.timestamp, 7);
+        assert_eq!(
+            schema_version(&event.data()),
+            Some(constants::SCHEMA_VERSION)
+        );
+    }
+
+    #[test]
+    fn decode_event_rejects_other_discriminator() {
+        let event = market_opened();
+        assert!(decode_event::<StakeRevealedEvent>(&event.data()).is_none());
+    }
+
+    #[test]
+    fn decode_cpi_event_strips_ix_tag() {
+        let event = market_opened();
+        let ix_data = [anchor_lang::event::EVENT_IX_TAG_LE, &event.data()].concat();
+        let decoded: MarketOpenedEvent = decode_cpi_event(&ix_data).unwrap();
+        assert_eq!(decoded.market, event.market);
+        assert!(decode_cpi_event::<MarketOpenedEvent>(&event.data()).is_none());
+    }
+}
@@ -33,4 +33,33 @@ mod circuits {
         let stake_data = stake_account_ctx.to_arcis();
         stake_data.selected_option.reveal()
     }
+
+    // Batch reveal: decrypt the options of four stake accounts in one computation
+    #[instruction]
+    pub fn reveal_stake_batch(
+        stake_account_0_ctx: Enc<Shared, SelectedOption>,
+        stake_account_1_ctx: Enc<Shared, SelectedOption>,
+        stake_account_2_ctx: Enc<Shared, SelectedOption>,
+        stake_account_3_ctx: Enc<Shared, SelectedOption>,
+    ) -> (u64, u64, u64, u64) {
+        (
+            stake_account_0_ctx.to_arcis().selected_option.reveal(),
+            stake_account_1_ctx.to_arcis().selected_option.reveal(),
+            stake_account_2_ctx.to_arcis().selected_option.reveal(),
+            stake_account_3_ctx.to_arcis().selected_option.reveal(),
+        )
+    }
+
+    // Verify disclosure: check a claimed option against the stake account's encrypted option
+    #[instruction]
+    pub fn verify_stake_disclosure(
+        stake_account_ctx: Enc<Shared, SelectedOption>,
+        claimed_option: u64,
+    ) -> (u64, bool) {
+        let stake_data = stake_account_ctx.to_arcis();
+        (
+            claimed_option,
+            (stake_data.selected_option == claimed_option).reveal(),
+        )
+    }
 }
@@ -0,0 +1,19 @@
+import {
+  type Address,
+  getAddressEncoder,
+  getProgramDerivedAddress,
+  type ProgramDerivedAddress,
+} from "@solana/kit";
+import { OPPORTUNITY_MARKET_PROGRAM_ADDRESS } from "../generated";
+
+export const AUDIT_LOG_SEED = "audit_log";
+
+export async function getAuditLogAddress(
+  market: Address,
+  programId: Address = OPPORTUNITY_MARKET_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: programId,
+    seeds: [AUDIT_LOG_SEED, getAddressEncoder().encode(market)],
+  });
+}
@@ -0,0 +1,21 @@
+import {
+  type Address,
+  getAddressEncoder,
+  getProgramDerivedAddress,
+  type ProgramDerivedAddress,
+} from "@solana/kit";
+import { OPPORTUNITY_MARKET_PROGRAM_ADDRESS } from "../generated";
+import { type ByteArray } from "../utils";
+
+export const DEPOSIT_RECEIPT_SEED = "deposit_receipt";
+
+export async function getDepositReceiptAddress(
+  owner: Address,
+  clientId: ByteArray,
+  programId: Address = OPPORTUNITY_MARKET_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: programId,
+    seeds: [DEPOSIT_RECEIPT_SEED, getAddressEncoder().encode(owner), Uint8Array.from(clientId)],
+  });
+}
@@ -0,0 +1,17 @@
+import {
+  type Address,
+  getProgramDerivedAddress,
+  type ProgramDerivedAddress,
+} from "@solana/kit";
+import { OPPORTUNITY_MARKET_PROGRAM_ADDRESS } from "../generated";
+
+export const EVENT_AUTHORITY_SEED = "__event_authority";
+
+export async function getEventAuthorityAddress(
+  programId: Address = OPPORTUNITY_MARKET_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: programId,
+    seeds: [EVENT_AUTHORITY_SEED],
+  });
+}
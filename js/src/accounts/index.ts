@@ -4,3 +4,7 @@ export * from "./opportunityMarket";
 export * from "./opportunityMarketOption";
 export * from "./opportunityMarketSponsor";
 export * from "./stakeAccount";
+export * from "./eventAuthority";
+export * from "./marketResult";
+export * from "./auditLog";
+export * from "./depositReceipt";
@@ -0,0 +1,19 @@
+import {
+  type Address,
+  getAddressEncoder,
+  getProgramDerivedAddress,
+  type ProgramDerivedAddress,
+} from "@solana/kit";
+import { OPPORTUNITY_MARKET_PROGRAM_ADDRESS } from "../generated";
+
+export const MARKET_RESULT_SEED = "market_result";
+
+export async function getMarketResultAddress(
+  market: Address,
+  programId: Address = OPPORTUNITY_MARKET_PROGRAM_ADDRESS
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: programId,
+    seeds: [MARKET_RESULT_SEED, getAddressEncoder().encode(market)],
+  });
+}
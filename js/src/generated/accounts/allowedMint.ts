@@ -19,6 +19,8 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU8Decoder,
@@ -26,14 +28,16 @@ import {
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
 
@@ -49,42 +53,47 @@ export function getAllowedMintDiscriminatorBytes() {
 
 export type AllowedMint = {
   discriminator: ReadonlyUint8Array;
+  schemaVersion: number;
   bump: number;
   platform: Address;
   mint: Address;
+  transferHookProgram: Option<Address>;
 };
 
 export type AllowedMintArgs = {
+  schemaVersion: number;
   bump: number;
   platform: Address;
   mint: Address;
+  transferHookProgram: OptionOrNullable<Address>;
 };
 
-export function getAllowedMintEncoder(): FixedSizeEncoder<AllowedMintArgs> {
+export function getAllowedMintEncoder(): Encoder<AllowedMintArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['schemaVersion', getU8Encoder()],
       ['bump', getU8Encoder()],
       ['platform', getAddressEncoder()],
       ['mint', getAddressEncoder()],
+      ['transferHookProgram', getOptionEncoder(getAddressEncoder())],
     ]),
     (value) => ({ ...value, discriminator: ALLOWED_MINT_DISCRIMINATOR })
   );
 }
 
-export function getAllowedMintDecoder(): FixedSizeDecoder<AllowedMint> {
+export function getAllowedMintDecoder(): Decoder<AllowedMint> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['schemaVersion', getU8Decoder()],
     ['bump', getU8Decoder()],
     ['platform', getAddressDecoder()],
     ['mint', getAddressDecoder()],
+    ['transferHookProgram', getOptionDecoder(getAddressDecoder())],
   ]);
 }
 
-export function getAllowedMintCodec(): FixedSizeCodec<
-  AllowedMintArgs,
-  AllowedMint
-> {
+export function getAllowedMintCodec(): Codec<AllowedMintArgs, AllowedMint> {
   return combineCodec(getAllowedMintEncoder(), getAllowedMintDecoder());
 }
 
@@ -8,8 +8,18 @@
 
 export * from './allowedMint';
 export * from './arciumSignerAccount';
+export * from './auditLog';
+export * from './bootstrapState';
+export * from './category';
+export * from './categoryMarket';
+export * from './depositReceipt';
+export * from './marketResult';
 export * from './opportunityMarket';
 export * from './opportunityMarketOption';
 export * from './opportunityMarketSponsor';
+export * from './participantPass';
+export * from './pendingConfigChange';
 export * from './platformConfig';
+export * from './programMetrics';
 export * from './stakeAccount';
+export * from './userPortfolio';
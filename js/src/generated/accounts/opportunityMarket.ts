@@ -29,6 +29,8 @@ import {
   getStructEncoder,
   getU16Decoder,
   getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -67,6 +69,7 @@ export function getOpportunityMarketDiscriminatorBytes() {
 
 export type OpportunityMarket = {
   discriminator: ReadonlyUint8Array;
+  schemaVersion: number;
   bump: number;
   creator: Address;
   index: bigint;
@@ -90,9 +93,27 @@ export type OpportunityMarket = {
   revealPeriodSeconds: bigint;
   revealEnded: boolean;
   minStakeAmount: bigint;
+  rewardTranches: number;
+  rewardTrancheIntervalSeconds: bigint;
+  loserRebateBp: number;
+  losingStakeTotal: bigint;
+  successorMarket: Option<Address>;
+  participantsRestricted: boolean;
+  gateMint: Option<Address>;
+  minTotalStake: bigint;
+  minParticipants: number;
+  totalStake: bigint;
+  participantCount: number;
+  crankBudgetLamports: bigint;
+  crankBountyLamports: bigint;
+  mpcFeesReimbursed: bigint;
+  category: Option<Address>;
+  emergencyVoided: boolean;
+  auditLogEnabled: boolean;
 };
 
 export type OpportunityMarketArgs = {
+  schemaVersion: number;
   bump: number;
   creator: Address;
   index: number | bigint;
@@ -116,12 +137,30 @@ export type OpportunityMarketArgs = {
   revealPeriodSeconds: number | bigint;
   revealEnded: boolean;
   minStakeAmount: number | bigint;
+  rewardTranches: number;
+  rewardTrancheIntervalSeconds: number | bigint;
+  loserRebateBp: number;
+  losingStakeTotal: number | bigint;
+  successorMarket: OptionOrNullable<Address>;
+  participantsRestricted: boolean;
+  gateMint: OptionOrNullable<Address>;
+  minTotalStake: number | bigint;
+  minParticipants: number;
+  totalStake: number | bigint;
+  participantCount: number;
+  crankBudgetLamports: number | bigint;
+  crankBountyLamports: number | bigint;
+  mpcFeesReimbursed: number | bigint;
+  category: OptionOrNullable<Address>;
+  emergencyVoided: boolean;
+  auditLogEnabled: boolean;
 };
 
 export function getOpportunityMarketEncoder(): Encoder<OpportunityMarketArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['schemaVersion', getU8Encoder()],
       ['bump', getU8Encoder()],
       ['creator', getAddressEncoder()],
       ['index', getU64Encoder()],
@@ -145,6 +184,23 @@ export function getOpportunityMarketEncoder(): Encoder<OpportunityMarketArgs> {
       ['revealPeriodSeconds', getU64Encoder()],
       ['revealEnded', getBooleanEncoder()],
       ['minStakeAmount', getU64Encoder()],
+      ['rewardTranches', getU8Encoder()],
+      ['rewardTrancheIntervalSeconds', getU64Encoder()],
+      ['loserRebateBp', getU16Encoder()],
+      ['losingStakeTotal', getU64Encoder()],
+      ['successorMarket', getOptionEncoder(getAddressEncoder())],
+      ['participantsRestricted', getBooleanEncoder()],
+      ['gateMint', getOptionEncoder(getAddressEncoder())],
+      ['minTotalStake', getU64Encoder()],
+      ['minParticipants', getU32Encoder()],
+      ['totalStake', getU64Encoder()],
+      ['participantCount', getU32Encoder()],
+      ['crankBudgetLamports', getU64Encoder()],
+      ['crankBountyLamports', getU64Encoder()],
+      ['mpcFeesReimbursed', getU64Encoder()],
+      ['category', getOptionEncoder(getAddressEncoder())],
+      ['emergencyVoided', getBooleanEncoder()],
+      ['auditLogEnabled', getBooleanEncoder()],
     ]),
     (value) => ({ ...value, discriminator: OPPORTUNITY_MARKET_DISCRIMINATOR })
   );
@@ -153,6 +209,7 @@ export function getOpportunityMarketEncoder(): Encoder<OpportunityMarketArgs> {
 export function getOpportunityMarketDecoder(): Decoder<OpportunityMarket> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['schemaVersion', getU8Decoder()],
     ['bump', getU8Decoder()],
     ['creator', getAddressDecoder()],
     ['index', getU64Decoder()],
@@ -176,6 +233,23 @@ export function getOpportunityMarketDecoder(): Decoder<OpportunityMarket> {
     ['revealPeriodSeconds', getU64Decoder()],
     ['revealEnded', getBooleanDecoder()],
     ['minStakeAmount', getU64Decoder()],
+    ['rewardTranches', getU8Decoder()],
+    ['rewardTrancheIntervalSeconds', getU64Decoder()],
+    ['loserRebateBp', getU16Decoder()],
+    ['losingStakeTotal', getU64Decoder()],
+    ['successorMarket', getOptionDecoder(getAddressDecoder())],
+    ['participantsRestricted', getBooleanDecoder()],
+    ['gateMint', getOptionDecoder(getAddressDecoder())],
+    ['minTotalStake', getU64Decoder()],
+    ['minParticipants', getU32Decoder()],
+    ['totalStake', getU64Decoder()],
+    ['participantCount', getU32Decoder()],
+    ['crankBudgetLamports', getU64Decoder()],
+    ['crankBountyLamports', getU64Decoder()],
+    ['mpcFeesReimbursed', getU64Decoder()],
+    ['category', getOptionDecoder(getAddressDecoder())],
+    ['emergencyVoided', getBooleanDecoder()],
+    ['auditLogEnabled', getBooleanDecoder()],
   ]);
 }
 
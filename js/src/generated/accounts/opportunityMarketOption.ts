@@ -59,6 +59,7 @@ export function getOpportunityMarketOptionDiscriminatorBytes() {
 
 export type OpportunityMarketOption = {
   discriminator: ReadonlyUint8Array;
+  schemaVersion: number;
   bump: number;
   id: bigint;
   creator: Address;
@@ -69,6 +70,7 @@ export type OpportunityMarketOption = {
 };
 
 export type OpportunityMarketOptionArgs = {
+  schemaVersion: number;
   bump: number;
   id: number | bigint;
   creator: Address;
@@ -82,6 +84,7 @@ export function getOpportunityMarketOptionEncoder(): Encoder<OpportunityMarketOp
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['schemaVersion', getU8Encoder()],
       ['bump', getU8Encoder()],
       ['id', getU64Encoder()],
       ['creator', getAddressEncoder()],
@@ -100,6 +103,7 @@ export function getOpportunityMarketOptionEncoder(): Encoder<OpportunityMarketOp
 export function getOpportunityMarketOptionDecoder(): Decoder<OpportunityMarketOption> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['schemaVersion', getU8Decoder()],
     ['bump', getU8Decoder()],
     ['id', getU64Decoder()],
     ['creator', getAddressDecoder()],
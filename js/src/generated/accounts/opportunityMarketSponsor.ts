@@ -53,6 +53,7 @@ export function getOpportunityMarketSponsorDiscriminatorBytes() {
 
 export type OpportunityMarketSponsor = {
   discriminator: ReadonlyUint8Array;
+  schemaVersion: number;
   bump: number;
   sponsor: Address;
   market: Address;
@@ -61,6 +62,7 @@ export type OpportunityMarketSponsor = {
 };
 
 export type OpportunityMarketSponsorArgs = {
+  schemaVersion: number;
   bump: number;
   sponsor: Address;
   market: Address;
@@ -72,6 +74,7 @@ export function getOpportunityMarketSponsorEncoder(): FixedSizeEncoder<Opportuni
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['schemaVersion', getU8Encoder()],
       ['bump', getU8Encoder()],
       ['sponsor', getAddressEncoder()],
       ['market', getAddressEncoder()],
@@ -88,6 +91,7 @@ export function getOpportunityMarketSponsorEncoder(): FixedSizeEncoder<Opportuni
 export function getOpportunityMarketSponsorDecoder(): FixedSizeDecoder<OpportunityMarketSponsor> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['schemaVersion', getU8Decoder()],
     ['bump', getU8Decoder()],
     ['sponsor', getAddressDecoder()],
     ['market', getAddressDecoder()],
@@ -19,8 +19,14 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
@@ -42,6 +48,8 @@ import {
   type FetchAccountsConfig,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
@@ -63,6 +71,7 @@ export function getPlatformConfigDiscriminatorBytes() {
 
 export type PlatformConfig = {
   discriminator: ReadonlyUint8Array;
+  schemaVersion: number;
   bump: number;
   name: string;
   updateAuthority: Address;
@@ -72,9 +81,15 @@ export type PlatformConfig = {
   minTimeToStakeSeconds: bigint;
   revealAuthority: Address;
   revealPeriodSeconds: bigint;
+  paused: boolean;
+  pendingUpdateAuthority: Option<Address>;
+  defaultCuPriceMicro: bigint;
+  configChangeDelaySeconds: bigint;
+  transferHookPrograms: Array<Address>;
 };
 
 export type PlatformConfigArgs = {
+  schemaVersion: number;
   bump: number;
   name: string;
   updateAuthority: Address;
@@ -84,12 +99,18 @@ export type PlatformConfigArgs = {
   minTimeToStakeSeconds: number | bigint;
   revealAuthority: Address;
   revealPeriodSeconds: number | bigint;
+  paused: boolean;
+  pendingUpdateAuthority: OptionOrNullable<Address>;
+  defaultCuPriceMicro: number | bigint;
+  configChangeDelaySeconds: number | bigint;
+  transferHookPrograms: Array<Address>;
 };
 
 export function getPlatformConfigEncoder(): Encoder<PlatformConfigArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['schemaVersion', getU8Encoder()],
       ['bump', getU8Encoder()],
       ['name', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['updateAuthority', getAddressEncoder()],
@@ -99,6 +120,11 @@ export function getPlatformConfigEncoder(): Encoder<PlatformConfigArgs> {
       ['minTimeToStakeSeconds', getU64Encoder()],
       ['revealAuthority', getAddressEncoder()],
       ['revealPeriodSeconds', getU64Encoder()],
+      ['paused', getBooleanEncoder()],
+      ['pendingUpdateAuthority', getOptionEncoder(getAddressEncoder())],
+      ['defaultCuPriceMicro', getU64Encoder()],
+      ['configChangeDelaySeconds', getU64Encoder()],
+      ['transferHookPrograms', getArrayEncoder(getAddressEncoder())],
     ]),
     (value) => ({ ...value, discriminator: PLATFORM_CONFIG_DISCRIMINATOR })
   );
@@ -107,6 +133,7 @@ export function getPlatformConfigEncoder(): Encoder<PlatformConfigArgs> {
 export function getPlatformConfigDecoder(): Decoder<PlatformConfig> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['schemaVersion', getU8Decoder()],
     ['bump', getU8Decoder()],
     ['name', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['updateAuthority', getAddressDecoder()],
@@ -116,6 +143,11 @@ export function getPlatformConfigDecoder(): Decoder<PlatformConfig> {
     ['minTimeToStakeSeconds', getU64Decoder()],
     ['revealAuthority', getAddressDecoder()],
     ['revealPeriodSeconds', getU64Decoder()],
+    ['paused', getBooleanDecoder()],
+    ['pendingUpdateAuthority', getOptionDecoder(getAddressDecoder())],
+    ['defaultCuPriceMicro', getU64Decoder()],
+    ['configChangeDelaySeconds', getU64Decoder()],
+    ['transferHookPrograms', getArrayDecoder(getAddressDecoder())],
   ]);
 }
 
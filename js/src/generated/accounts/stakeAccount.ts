@@ -69,6 +69,7 @@ export function getStakeAccountDiscriminatorBytes() {
 
 export type StakeAccount = {
   discriminator: ReadonlyUint8Array;
+  schemaVersion: number;
   encryptedOption: Array<number>;
   stateNonce: bigint;
   bump: number;
@@ -87,9 +88,15 @@ export type StakeAccount = {
   id: number;
   pendingStakeComputation: Option<Address>;
   pendingReveal: boolean;
+  pendingComputationOffset: Option<bigint>;
+  rewardClaimed: bigint;
+  nextComputationSeq: bigint;
+  portfolioTracked: boolean;
+  migrated: boolean;
 };
 
 export type StakeAccountArgs = {
+  schemaVersion: number;
   encryptedOption: Array<number>;
   stateNonce: number | bigint;
   bump: number;
@@ -108,12 +115,18 @@ export type StakeAccountArgs = {
   id: number;
   pendingStakeComputation: OptionOrNullable<Address>;
   pendingReveal: boolean;
+  pendingComputationOffset: OptionOrNullable<number | bigint>;
+  rewardClaimed: number | bigint;
+  nextComputationSeq: number | bigint;
+  portfolioTracked: boolean;
+  migrated: boolean;
 };
 
 export function getStakeAccountEncoder(): Encoder<StakeAccountArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['schemaVersion', getU8Encoder()],
       ['encryptedOption', getArrayEncoder(getU8Encoder(), { size: 32 })],
       ['stateNonce', getU128Encoder()],
       ['bump', getU8Encoder()],
@@ -135,6 +148,11 @@ export function getStakeAccountEncoder(): Encoder<StakeAccountArgs> {
       ['id', getU32Encoder()],
       ['pendingStakeComputation', getOptionEncoder(getAddressEncoder())],
       ['pendingReveal', getBooleanEncoder()],
+      ['pendingComputationOffset', getOptionEncoder(getU64Encoder())],
+      ['rewardClaimed', getU64Encoder()],
+      ['nextComputationSeq', getU64Encoder()],
+      ['portfolioTracked', getBooleanEncoder()],
+      ['migrated', getBooleanEncoder()],
     ]),
     (value) => ({ ...value, discriminator: STAKE_ACCOUNT_DISCRIMINATOR })
   );
@@ -143,6 +161,7 @@ export function getStakeAccountEncoder(): Encoder<StakeAccountArgs> {
 export function getStakeAccountDecoder(): Decoder<StakeAccount> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['schemaVersion', getU8Decoder()],
     ['encryptedOption', getArrayDecoder(getU8Decoder(), { size: 32 })],
     ['stateNonce', getU128Decoder()],
     ['bump', getU8Decoder()],
@@ -164,6 +183,11 @@ export function getStakeAccountDecoder(): Decoder<StakeAccount> {
     ['id', getU32Decoder()],
     ['pendingStakeComputation', getOptionDecoder(getAddressDecoder())],
     ['pendingReveal', getBooleanDecoder()],
+    ['pendingComputationOffset', getOptionDecoder(getU64Decoder())],
+    ['rewardClaimed', getU64Decoder()],
+    ['nextComputationSeq', getU64Decoder()],
+    ['portfolioTracked', getBooleanDecoder()],
+    ['migrated', getBooleanDecoder()],
   ]);
 }
 
@@ -82,6 +82,38 @@ export const OPPORTUNITY_MARKET_ERROR__REVEAL_PERIOD_NOT_OVER = 0x178f; // 6031
 export const OPPORTUNITY_MARKET_ERROR__NO_REWARD_TO_CLAIM = 0x1790; // 6032
 /** RewardAlreadyClaimed: Reward already claimed */
 export const OPPORTUNITY_MARKET_ERROR__REWARD_ALREADY_CLAIMED = 0x1791; // 6033
+/** PlatformPaused: Platform is paused */
+export const OPPORTUNITY_MARKET_ERROR__PLATFORM_PAUSED = 0x1792; // 6034
+/** NoPendingUpdateAuthority: No pending update authority */
+export const OPPORTUNITY_MARKET_ERROR__NO_PENDING_UPDATE_AUTHORITY = 0x1793; // 6035
+/** ComputationOffsetInUse: Computation offset already in use */
+export const OPPORTUNITY_MARKET_ERROR__COMPUTATION_OFFSET_IN_USE = 0x1794; // 6036
+/** CallbackAlreadyApplied: Callback already applied */
+export const OPPORTUNITY_MARKET_ERROR__CALLBACK_ALREADY_APPLIED = 0x1795; // 6037
+/** BootstrapIncomplete: Computation definitions not initialized */
+export const OPPORTUNITY_MARKET_ERROR__BOOTSTRAP_INCOMPLETE = 0x1796; // 6038
+/** ConfigChangeNotReady: Config change delay has not passed */
+export const OPPORTUNITY_MARKET_ERROR__CONFIG_CHANGE_NOT_READY = 0x1797; // 6039
+/** RewardNotVested: Reward has not fully vested */
+export const OPPORTUNITY_MARKET_ERROR__REWARD_NOT_VESTED = 0x1798; // 6040
+/** ParticipantPassRequired: Participant pass required */
+export const OPPORTUNITY_MARKET_ERROR__PARTICIPANT_PASS_REQUIRED = 0x1799; // 6041
+/** GateTokenRequired: Gate token required */
+export const OPPORTUNITY_MARKET_ERROR__GATE_TOKEN_REQUIRED = 0x179a; // 6042
+/** MarketVoid: Market is void: it missed its participation threshold or was voided by the platform */
+export const OPPORTUNITY_MARKET_ERROR__MARKET_VOID = 0x179b; // 6043
+/** UnexpectedComputationOffset: Computation offset does not match the account's next derived offset */
+export const OPPORTUNITY_MARKET_ERROR__UNEXPECTED_COMPUTATION_OFFSET = 0x179c; // 6044
+/** AuditLogRequired: Audit log required */
+export const OPPORTUNITY_MARKET_ERROR__AUDIT_LOG_REQUIRED = 0x179d; // 6045
+/** TransferHookNotAllowed: Transfer hook program is not allowlisted or its hook is mutable */
+export const OPPORTUNITY_MARKET_ERROR__TRANSFER_HOOK_NOT_ALLOWED = 0x179e; // 6046
+/** UserPortfolioRequired: User portfolio required */
+export const OPPORTUNITY_MARKET_ERROR__USER_PORTFOLIO_REQUIRED = 0x179f; // 6047
+/** StakeMigrated: Stake was migrated to a successor market */
+export const OPPORTUNITY_MARKET_ERROR__STAKE_MIGRATED = 0x17a0; // 6048
+/** SchemaVersionMismatch: Account was written by an unsupported schema version */
+export const OPPORTUNITY_MARKET_ERROR__SCHEMA_VERSION_MISMATCH = 0x17a1; // 6049
 
 export type OpportunityMarketError =
   | typeof OPPORTUNITY_MARKET_ERROR__ABORTED_COMPUTATION
@@ -89,8 +121,14 @@ export type OpportunityMarketError =
   | typeof OPPORTUNITY_MARKET_ERROR__ALREADY_REVEALED
   | typeof OPPORTUNITY_MARKET_ERROR__ALREADY_STAKED
   | typeof OPPORTUNITY_MARKET_ERROR__ALREADY_UNSTAKED
+  | typeof OPPORTUNITY_MARKET_ERROR__AUDIT_LOG_REQUIRED
+  | typeof OPPORTUNITY_MARKET_ERROR__BOOTSTRAP_INCOMPLETE
+  | typeof OPPORTUNITY_MARKET_ERROR__CALLBACK_ALREADY_APPLIED
+  | typeof OPPORTUNITY_MARKET_ERROR__COMPUTATION_OFFSET_IN_USE
+  | typeof OPPORTUNITY_MARKET_ERROR__CONFIG_CHANGE_NOT_READY
   | typeof OPPORTUNITY_MARKET_ERROR__CREATOR_MISMATCH
   | typeof OPPORTUNITY_MARKET_ERROR__DEPOSIT_BELOW_MINIMUM
+  | typeof OPPORTUNITY_MARKET_ERROR__GATE_TOKEN_REQUIRED
   | typeof OPPORTUNITY_MARKET_ERROR__INSUFFICIENT_BALANCE
   | typeof OPPORTUNITY_MARKET_ERROR__INSUFFICIENT_REWARD_FUNDING
   | typeof OPPORTUNITY_MARKET_ERROR__INVALID_ACCOUNT_STATE
@@ -102,21 +140,31 @@ export type OpportunityMarketError =
   | typeof OPPORTUNITY_MARKET_ERROR__MARKET_ALREADY_OPEN
   | typeof OPPORTUNITY_MARKET_ERROR__MARKET_NOT_OPEN
   | typeof OPPORTUNITY_MARKET_ERROR__MARKET_NOT_RESOLVED
+  | typeof OPPORTUNITY_MARKET_ERROR__MARKET_VOID
   | typeof OPPORTUNITY_MARKET_ERROR__NO_FEES_TO_CLAIM
+  | typeof OPPORTUNITY_MARKET_ERROR__NO_PENDING_UPDATE_AUTHORITY
   | typeof OPPORTUNITY_MARKET_ERROR__NO_REWARD_TO_CLAIM
   | typeof OPPORTUNITY_MARKET_ERROR__NO_STAKE
   | typeof OPPORTUNITY_MARKET_ERROR__NOT_REVEALED
   | typeof OPPORTUNITY_MARKET_ERROR__OPTION_STILL_NEEDED
   | typeof OPPORTUNITY_MARKET_ERROR__OVERFLOW
+  | typeof OPPORTUNITY_MARKET_ERROR__PARTICIPANT_PASS_REQUIRED
+  | typeof OPPORTUNITY_MARKET_ERROR__PLATFORM_PAUSED
   | typeof OPPORTUNITY_MARKET_ERROR__REVEAL_PERIOD_ENDED
   | typeof OPPORTUNITY_MARKET_ERROR__REVEAL_PERIOD_NOT_OVER
   | typeof OPPORTUNITY_MARKET_ERROR__REWARD_ALREADY_CLAIMED
+  | typeof OPPORTUNITY_MARKET_ERROR__REWARD_NOT_VESTED
+  | typeof OPPORTUNITY_MARKET_ERROR__SCHEMA_VERSION_MISMATCH
   | typeof OPPORTUNITY_MARKET_ERROR__SELECT_OPTIONS_DEADLINE_PASSED
   | typeof OPPORTUNITY_MARKET_ERROR__STAKE_BELOW_MINIMUM
+  | typeof OPPORTUNITY_MARKET_ERROR__STAKE_MIGRATED
   | typeof OPPORTUNITY_MARKET_ERROR__STAKE_NOT_STUCK
   | typeof OPPORTUNITY_MARKET_ERROR__TALLY_ALREADY_INCREMENTED
   | typeof OPPORTUNITY_MARKET_ERROR__TIME_WINDOW_MISMATCH
+  | typeof OPPORTUNITY_MARKET_ERROR__TRANSFER_HOOK_NOT_ALLOWED
   | typeof OPPORTUNITY_MARKET_ERROR__UNAUTHORIZED
+  | typeof OPPORTUNITY_MARKET_ERROR__UNEXPECTED_COMPUTATION_OFFSET
+  | typeof OPPORTUNITY_MARKET_ERROR__USER_PORTFOLIO_REQUIRED
   | typeof OPPORTUNITY_MARKET_ERROR__WINNER_ALREADY_SELECTED;
 
 let opportunityMarketErrorMessages:
@@ -129,8 +177,14 @@ if (process.env.NODE_ENV !== 'production') {
     [OPPORTUNITY_MARKET_ERROR__ALREADY_REVEALED]: `Stake already revealed`,
     [OPPORTUNITY_MARKET_ERROR__ALREADY_STAKED]: `Already staked for this stake account`,
     [OPPORTUNITY_MARKET_ERROR__ALREADY_UNSTAKED]: `Already unstaked`,
+    [OPPORTUNITY_MARKET_ERROR__AUDIT_LOG_REQUIRED]: `Audit log required`,
+    [OPPORTUNITY_MARKET_ERROR__BOOTSTRAP_INCOMPLETE]: `Computation definitions not initialized`,
+    [OPPORTUNITY_MARKET_ERROR__CALLBACK_ALREADY_APPLIED]: `Callback already applied`,
+    [OPPORTUNITY_MARKET_ERROR__COMPUTATION_OFFSET_IN_USE]: `Computation offset already in use`,
+    [OPPORTUNITY_MARKET_ERROR__CONFIG_CHANGE_NOT_READY]: `Config change delay has not passed`,
     [OPPORTUNITY_MARKET_ERROR__CREATOR_MISMATCH]: `Creator mismatch`,
     [OPPORTUNITY_MARKET_ERROR__DEPOSIT_BELOW_MINIMUM]: `Deposit amount below minimum required for option creation`,
+    [OPPORTUNITY_MARKET_ERROR__GATE_TOKEN_REQUIRED]: `Gate token required`,
     [OPPORTUNITY_MARKET_ERROR__INSUFFICIENT_BALANCE]: `Insufficient balance`,
     [OPPORTUNITY_MARKET_ERROR__INSUFFICIENT_REWARD_FUNDING]: `Insufficient reward funding`,
     [OPPORTUNITY_MARKET_ERROR__INVALID_ACCOUNT_STATE]: `Invalid account state`,
@@ -142,21 +196,31 @@ if (process.env.NODE_ENV !== 'production') {
     [OPPORTUNITY_MARKET_ERROR__MARKET_ALREADY_OPEN]: `Market is already open`,
     [OPPORTUNITY_MARKET_ERROR__MARKET_NOT_OPEN]: `Market is not open`,
     [OPPORTUNITY_MARKET_ERROR__MARKET_NOT_RESOLVED]: `Market not yet resolved`,
+    [OPPORTUNITY_MARKET_ERROR__MARKET_VOID]: `Market is void: it missed its participation threshold or was voided by the platform`,
     [OPPORTUNITY_MARKET_ERROR__NO_FEES_TO_CLAIM]: `No fees to claim`,
+    [OPPORTUNITY_MARKET_ERROR__NO_PENDING_UPDATE_AUTHORITY]: `No pending update authority`,
     [OPPORTUNITY_MARKET_ERROR__NO_REWARD_TO_CLAIM]: `No reward to claim`,
     [OPPORTUNITY_MARKET_ERROR__NO_STAKE]: `Stake account has no recorded stake`,
     [OPPORTUNITY_MARKET_ERROR__NOT_REVEALED]: `Stake not yet revealed`,
     [OPPORTUNITY_MARKET_ERROR__OPTION_STILL_NEEDED]: `Option still needed`,
     [OPPORTUNITY_MARKET_ERROR__OVERFLOW]: `Arithmetic overflow`,
+    [OPPORTUNITY_MARKET_ERROR__PARTICIPANT_PASS_REQUIRED]: `Participant pass required`,
+    [OPPORTUNITY_MARKET_ERROR__PLATFORM_PAUSED]: `Platform is paused`,
     [OPPORTUNITY_MARKET_ERROR__REVEAL_PERIOD_ENDED]: `Reveal period has already ended`,
     [OPPORTUNITY_MARKET_ERROR__REVEAL_PERIOD_NOT_OVER]: `Reveal period not over`,
     [OPPORTUNITY_MARKET_ERROR__REWARD_ALREADY_CLAIMED]: `Reward already claimed`,
+    [OPPORTUNITY_MARKET_ERROR__REWARD_NOT_VESTED]: `Reward has not fully vested`,
+    [OPPORTUNITY_MARKET_ERROR__SCHEMA_VERSION_MISMATCH]: `Account was written by an unsupported schema version`,
     [OPPORTUNITY_MARKET_ERROR__SELECT_OPTIONS_DEADLINE_PASSED]: `Deadline for selecting winning options has passed`,
     [OPPORTUNITY_MARKET_ERROR__STAKE_BELOW_MINIMUM]: `Stake amount is below the market minimum`,
+    [OPPORTUNITY_MARKET_ERROR__STAKE_MIGRATED]: `Stake was migrated to a successor market`,
     [OPPORTUNITY_MARKET_ERROR__STAKE_NOT_STUCK]: `Stake account is not in a stuck or failed state`,
     [OPPORTUNITY_MARKET_ERROR__TALLY_ALREADY_INCREMENTED]: `Tally already incremented for this stake account`,
     [OPPORTUNITY_MARKET_ERROR__TIME_WINDOW_MISMATCH]: `Operation called outside its permitted time window`,
+    [OPPORTUNITY_MARKET_ERROR__TRANSFER_HOOK_NOT_ALLOWED]: `Transfer hook program is not allowlisted or its hook is mutable`,
     [OPPORTUNITY_MARKET_ERROR__UNAUTHORIZED]: `Unauthorized`,
+    [OPPORTUNITY_MARKET_ERROR__UNEXPECTED_COMPUTATION_OFFSET]: `Computation offset does not match the account's next derived offset`,
+    [OPPORTUNITY_MARKET_ERROR__USER_PORTFOLIO_REQUIRED]: `User portfolio required`,
     [OPPORTUNITY_MARKET_ERROR__WINNER_ALREADY_SELECTED]: `Market winner already selected`,
   };
 }
@@ -59,6 +59,9 @@ export type AddMarketOptionInstruction<
   TAccountOption extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -77,6 +80,12 @@ export type AddMarketOptionInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -120,11 +129,15 @@ export type AddMarketOptionAsyncInput<
   TAccountMarket extends string = string,
   TAccountOption extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   market: Address<TAccountMarket>;
   option?: Address<TAccountOption>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   optionId: AddMarketOptionInstructionDataArgs['optionId'];
 };
 
@@ -133,13 +146,17 @@ export async function getAddMarketOptionInstructionAsync<
   TAccountMarket extends string,
   TAccountOption extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: AddMarketOptionAsyncInput<
     TAccountSigner,
     TAccountMarket,
     TAccountOption,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -148,7 +165,9 @@ export async function getAddMarketOptionInstructionAsync<
     TAccountSigner,
     TAccountMarket,
     TAccountOption,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -161,6 +180,8 @@ export async function getAddMarketOptionInstructionAsync<
     market: { value: input.market ?? null, isWritable: true },
     option: { value: input.option ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -187,6 +208,10 @@ export async function getAddMarketOptionInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -195,6 +220,8 @@ export async function getAddMarketOptionInstructionAsync<
       getAccountMeta(accounts.market),
       getAccountMeta(accounts.option),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getAddMarketOptionInstructionDataEncoder().encode(
       args as AddMarketOptionInstructionDataArgs
@@ -205,7 +232,9 @@ export async function getAddMarketOptionInstructionAsync<
     TAccountSigner,
     TAccountMarket,
     TAccountOption,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -214,11 +243,15 @@ export type AddMarketOptionInput<
   TAccountMarket extends string = string,
   TAccountOption extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   market: Address<TAccountMarket>;
   option: Address<TAccountOption>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   optionId: AddMarketOptionInstructionDataArgs['optionId'];
 };
 
@@ -227,13 +260,17 @@ export function getAddMarketOptionInstruction<
   TAccountMarket extends string,
   TAccountOption extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: AddMarketOptionInput<
     TAccountSigner,
     TAccountMarket,
     TAccountOption,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): AddMarketOptionInstruction<
@@ -241,7 +278,9 @@ export function getAddMarketOptionInstruction<
   TAccountSigner,
   TAccountMarket,
   TAccountOption,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -253,6 +292,8 @@ export function getAddMarketOptionInstruction<
     market: { value: input.market ?? null, isWritable: true },
     option: { value: input.option ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -267,6 +308,10 @@ export function getAddMarketOptionInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -275,6 +320,8 @@ export function getAddMarketOptionInstruction<
       getAccountMeta(accounts.market),
       getAccountMeta(accounts.option),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getAddMarketOptionInstructionDataEncoder().encode(
       args as AddMarketOptionInstructionDataArgs
@@ -285,7 +332,9 @@ export function getAddMarketOptionInstruction<
     TAccountSigner,
     TAccountMarket,
     TAccountOption,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -299,6 +348,8 @@ export type ParsedAddMarketOptionInstruction<
     market: TAccountMetas[1];
     option: TAccountMetas[2];
     systemProgram: TAccountMetas[3];
+    eventAuthority: TAccountMetas[4];
+    program: TAccountMetas[5];
   };
   data: AddMarketOptionInstructionData;
 };
@@ -311,7 +362,7 @@ export function parseAddMarketOptionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedAddMarketOptionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -328,6 +379,8 @@ export function parseAddMarketOptionInstruction<
       market: getNextAccount(),
       option: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getAddMarketOptionInstructionDataDecoder().decode(instruction.data),
   };
@@ -55,6 +55,7 @@ export type AddRewardInstruction<
   TProgram extends string = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
   TAccountSponsor extends string | AccountMeta<string> = string,
   TAccountMarket extends string | AccountMeta<string> = string,
+  TAccountPlatformConfig extends string | AccountMeta<string> = string,
   TAccountSponsorAccount extends string | AccountMeta<string> = string,
   TAccountTokenMint extends string | AccountMeta<string> = string,
   TAccountSponsorTokenAccount extends string | AccountMeta<string> = string,
@@ -62,6 +63,9 @@ export type AddRewardInstruction<
   TAccountTokenProgram extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -74,6 +78,9 @@ export type AddRewardInstruction<
       TAccountMarket extends string
         ? WritableAccount<TAccountMarket>
         : TAccountMarket,
+      TAccountPlatformConfig extends string
+        ? ReadonlyAccount<TAccountPlatformConfig>
+        : TAccountPlatformConfig,
       TAccountSponsorAccount extends string
         ? WritableAccount<TAccountSponsorAccount>
         : TAccountSponsorAccount,
@@ -92,6 +99,12 @@ export type AddRewardInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -139,15 +152,19 @@ export function getAddRewardInstructionDataCodec(): FixedSizeCodec<
 export type AddRewardAsyncInput<
   TAccountSponsor extends string = string,
   TAccountMarket extends string = string,
+  TAccountPlatformConfig extends string = string,
   TAccountSponsorAccount extends string = string,
   TAccountTokenMint extends string = string,
   TAccountSponsorTokenAccount extends string = string,
   TAccountMarketTokenAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   sponsor: TransactionSigner<TAccountSponsor>;
   market: Address<TAccountMarket>;
+  platformConfig: Address<TAccountPlatformConfig>;
   sponsorAccount?: Address<TAccountSponsorAccount>;
   tokenMint: Address<TAccountTokenMint>;
   sponsorTokenAccount: Address<TAccountSponsorTokenAccount>;
@@ -155,6 +172,8 @@ export type AddRewardAsyncInput<
   marketTokenAta?: Address<TAccountMarketTokenAta>;
   tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   amount: AddRewardInstructionDataArgs['amount'];
   lock: AddRewardInstructionDataArgs['lock'];
 };
@@ -162,23 +181,29 @@ export type AddRewardAsyncInput<
 export async function getAddRewardInstructionAsync<
   TAccountSponsor extends string,
   TAccountMarket extends string,
+  TAccountPlatformConfig extends string,
   TAccountSponsorAccount extends string,
   TAccountTokenMint extends string,
   TAccountSponsorTokenAccount extends string,
   TAccountMarketTokenAta extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: AddRewardAsyncInput<
     TAccountSponsor,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountSponsorAccount,
     TAccountTokenMint,
     TAccountSponsorTokenAccount,
     TAccountMarketTokenAta,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -186,12 +211,15 @@ export async function getAddRewardInstructionAsync<
     TProgramAddress,
     TAccountSponsor,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountSponsorAccount,
     TAccountTokenMint,
     TAccountSponsorTokenAccount,
     TAccountMarketTokenAta,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -202,6 +230,7 @@ export async function getAddRewardInstructionAsync<
   const originalAccounts = {
     sponsor: { value: input.sponsor ?? null, isWritable: true },
     market: { value: input.market ?? null, isWritable: true },
+    platformConfig: { value: input.platformConfig ?? null, isWritable: false },
     sponsorAccount: { value: input.sponsorAccount ?? null, isWritable: true },
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     sponsorTokenAccount: {
@@ -211,6 +240,8 @@ export async function getAddRewardInstructionAsync<
     marketTokenAta: { value: input.marketTokenAta ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -248,18 +279,25 @@ export async function getAddRewardInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.sponsor),
       getAccountMeta(accounts.market),
+      getAccountMeta(accounts.platformConfig),
       getAccountMeta(accounts.sponsorAccount),
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.sponsorTokenAccount),
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getAddRewardInstructionDataEncoder().encode(
       args as AddRewardInstructionDataArgs
@@ -269,27 +307,34 @@ export async function getAddRewardInstructionAsync<
     TProgramAddress,
     TAccountSponsor,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountSponsorAccount,
     TAccountTokenMint,
     TAccountSponsorTokenAccount,
     TAccountMarketTokenAta,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
 export type AddRewardInput<
   TAccountSponsor extends string = string,
   TAccountMarket extends string = string,
+  TAccountPlatformConfig extends string = string,
   TAccountSponsorAccount extends string = string,
   TAccountTokenMint extends string = string,
   TAccountSponsorTokenAccount extends string = string,
   TAccountMarketTokenAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   sponsor: TransactionSigner<TAccountSponsor>;
   market: Address<TAccountMarket>;
+  platformConfig: Address<TAccountPlatformConfig>;
   sponsorAccount: Address<TAccountSponsorAccount>;
   tokenMint: Address<TAccountTokenMint>;
   sponsorTokenAccount: Address<TAccountSponsorTokenAccount>;
@@ -297,6 +342,8 @@ export type AddRewardInput<
   marketTokenAta: Address<TAccountMarketTokenAta>;
   tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   amount: AddRewardInstructionDataArgs['amount'];
   lock: AddRewardInstructionDataArgs['lock'];
 };
@@ -304,35 +351,44 @@ export type AddRewardInput<
 export function getAddRewardInstruction<
   TAccountSponsor extends string,
   TAccountMarket extends string,
+  TAccountPlatformConfig extends string,
   TAccountSponsorAccount extends string,
   TAccountTokenMint extends string,
   TAccountSponsorTokenAccount extends string,
   TAccountMarketTokenAta extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: AddRewardInput<
     TAccountSponsor,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountSponsorAccount,
     TAccountTokenMint,
     TAccountSponsorTokenAccount,
     TAccountMarketTokenAta,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): AddRewardInstruction<
   TProgramAddress,
   TAccountSponsor,
   TAccountMarket,
+  TAccountPlatformConfig,
   TAccountSponsorAccount,
   TAccountTokenMint,
   TAccountSponsorTokenAccount,
   TAccountMarketTokenAta,
   TAccountTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -342,6 +398,7 @@ export function getAddRewardInstruction<
   const originalAccounts = {
     sponsor: { value: input.sponsor ?? null, isWritable: true },
     market: { value: input.market ?? null, isWritable: true },
+    platformConfig: { value: input.platformConfig ?? null, isWritable: false },
     sponsorAccount: { value: input.sponsorAccount ?? null, isWritable: true },
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     sponsorTokenAccount: {
@@ -351,6 +408,8 @@ export function getAddRewardInstruction<
     marketTokenAta: { value: input.marketTokenAta ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -365,18 +424,25 @@ export function getAddRewardInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.sponsor),
       getAccountMeta(accounts.market),
+      getAccountMeta(accounts.platformConfig),
       getAccountMeta(accounts.sponsorAccount),
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.sponsorTokenAccount),
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getAddRewardInstructionDataEncoder().encode(
       args as AddRewardInstructionDataArgs
@@ -386,12 +452,15 @@ export function getAddRewardInstruction<
     TProgramAddress,
     TAccountSponsor,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountSponsorAccount,
     TAccountTokenMint,
     TAccountSponsorTokenAccount,
     TAccountMarketTokenAta,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -403,13 +472,16 @@ export type ParsedAddRewardInstruction<
   accounts: {
     sponsor: TAccountMetas[0];
     market: TAccountMetas[1];
-    sponsorAccount: TAccountMetas[2];
-    tokenMint: TAccountMetas[3];
-    sponsorTokenAccount: TAccountMetas[4];
+    platformConfig: TAccountMetas[2];
+    sponsorAccount: TAccountMetas[3];
+    tokenMint: TAccountMetas[4];
+    sponsorTokenAccount: TAccountMetas[5];
     /** Market-owned ATA holding all program-held tokens for this market. */
-    marketTokenAta: TAccountMetas[5];
-    tokenProgram: TAccountMetas[6];
-    systemProgram: TAccountMetas[7];
+    marketTokenAta: TAccountMetas[6];
+    tokenProgram: TAccountMetas[7];
+    systemProgram: TAccountMetas[8];
+    eventAuthority: TAccountMetas[9];
+    program: TAccountMetas[10];
   };
   data: AddRewardInstructionData;
 };
@@ -422,7 +494,7 @@ export function parseAddRewardInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedAddRewardInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -437,12 +509,15 @@ export function parseAddRewardInstruction<
     accounts: {
       sponsor: getNextAccount(),
       market: getNextAccount(),
+      platformConfig: getNextAccount(),
       sponsorAccount: getNextAccount(),
       tokenMint: getNextAccount(),
       sponsorTokenAccount: getNextAccount(),
       marketTokenAta: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getAddRewardInstructionDataDecoder().decode(instruction.data),
   };
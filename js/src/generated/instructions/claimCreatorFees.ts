@@ -57,6 +57,9 @@ export type ClaimCreatorFeesInstruction<
   TAccountMarketTokenAta extends string | AccountMeta<string> = string,
   TAccountDestinationTokenAccount extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -81,6 +84,12 @@ export type ClaimCreatorFeesInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -121,6 +130,8 @@ export type ClaimCreatorFeesAsyncInput<
   TAccountMarketTokenAta extends string = string,
   TAccountDestinationTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   market: Address<TAccountMarket>;
@@ -128,6 +139,8 @@ export type ClaimCreatorFeesAsyncInput<
   marketTokenAta?: Address<TAccountMarketTokenAta>;
   destinationTokenAccount: Address<TAccountDestinationTokenAccount>;
   tokenProgram: Address<TAccountTokenProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
 };
 
 export async function getClaimCreatorFeesInstructionAsync<
@@ -137,6 +150,8 @@ export async function getClaimCreatorFeesInstructionAsync<
   TAccountMarketTokenAta extends string,
   TAccountDestinationTokenAccount extends string,
   TAccountTokenProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: ClaimCreatorFeesAsyncInput<
@@ -145,7 +160,9 @@ export async function getClaimCreatorFeesInstructionAsync<
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountDestinationTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -156,7 +173,9 @@ export async function getClaimCreatorFeesInstructionAsync<
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountDestinationTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -174,6 +193,8 @@ export async function getClaimCreatorFeesInstructionAsync<
       isWritable: true,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -192,6 +213,10 @@ export async function getClaimCreatorFeesInstructionAsync<
       ],
     });
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -202,6 +227,8 @@ export async function getClaimCreatorFeesInstructionAsync<
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.destinationTokenAccount),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getClaimCreatorFeesInstructionDataEncoder().encode({}),
     programAddress,
@@ -212,7 +239,9 @@ export async function getClaimCreatorFeesInstructionAsync<
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountDestinationTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -223,6 +252,8 @@ export type ClaimCreatorFeesInput<
   TAccountMarketTokenAta extends string = string,
   TAccountDestinationTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   market: Address<TAccountMarket>;
@@ -230,6 +261,8 @@ export type ClaimCreatorFeesInput<
   marketTokenAta: Address<TAccountMarketTokenAta>;
   destinationTokenAccount: Address<TAccountDestinationTokenAccount>;
   tokenProgram: Address<TAccountTokenProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
 };
 
 export function getClaimCreatorFeesInstruction<
@@ -239,6 +272,8 @@ export function getClaimCreatorFeesInstruction<
   TAccountMarketTokenAta extends string,
   TAccountDestinationTokenAccount extends string,
   TAccountTokenProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: ClaimCreatorFeesInput<
@@ -247,7 +282,9 @@ export function getClaimCreatorFeesInstruction<
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountDestinationTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): ClaimCreatorFeesInstruction<
@@ -257,7 +294,9 @@ export function getClaimCreatorFeesInstruction<
   TAccountTokenMint,
   TAccountMarketTokenAta,
   TAccountDestinationTokenAccount,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -274,12 +313,20 @@ export function getClaimCreatorFeesInstruction<
       isWritable: true,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Resolve default values.
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
@@ -289,6 +336,8 @@ export function getClaimCreatorFeesInstruction<
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.destinationTokenAccount),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getClaimCreatorFeesInstructionDataEncoder().encode({}),
     programAddress,
@@ -299,7 +348,9 @@ export function getClaimCreatorFeesInstruction<
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountDestinationTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -315,6 +366,8 @@ export type ParsedClaimCreatorFeesInstruction<
     marketTokenAta: TAccountMetas[3];
     destinationTokenAccount: TAccountMetas[4];
     tokenProgram: TAccountMetas[5];
+    eventAuthority: TAccountMetas[6];
+    program: TAccountMetas[7];
   };
   data: ClaimCreatorFeesInstructionData;
 };
@@ -327,7 +380,7 @@ export function parseClaimCreatorFeesInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedClaimCreatorFeesInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -346,6 +399,8 @@ export function parseClaimCreatorFeesInstruction<
       marketTokenAta: getNextAccount(),
       destinationTokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getClaimCreatorFeesInstructionDataDecoder().decode(instruction.data),
   };
@@ -56,6 +56,9 @@ export type ClaimFeesInstruction<
   TAccountMarketTokenAta extends string | AccountMeta<string> = string,
   TAccountDestinationTokenAccount extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -83,6 +86,12 @@ export type ClaimFeesInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -122,6 +131,8 @@ export type ClaimFeesAsyncInput<
   TAccountMarketTokenAta extends string = string,
   TAccountDestinationTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   market: Address<TAccountMarket>;
@@ -130,6 +141,8 @@ export type ClaimFeesAsyncInput<
   marketTokenAta?: Address<TAccountMarketTokenAta>;
   destinationTokenAccount: Address<TAccountDestinationTokenAccount>;
   tokenProgram: Address<TAccountTokenProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
 };
 
 export async function getClaimFeesInstructionAsync<
@@ -140,6 +153,8 @@ export async function getClaimFeesInstructionAsync<
   TAccountMarketTokenAta extends string,
   TAccountDestinationTokenAccount extends string,
   TAccountTokenProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: ClaimFeesAsyncInput<
@@ -149,7 +164,9 @@ export async function getClaimFeesInstructionAsync<
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountDestinationTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -161,7 +178,9 @@ export async function getClaimFeesInstructionAsync<
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountDestinationTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -180,6 +199,8 @@ export async function getClaimFeesInstructionAsync<
       isWritable: true,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -198,6 +219,10 @@ export async function getClaimFeesInstructionAsync<
       ],
     });
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -209,6 +234,8 @@ export async function getClaimFeesInstructionAsync<
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.destinationTokenAccount),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getClaimFeesInstructionDataEncoder().encode({}),
     programAddress,
@@ -220,7 +247,9 @@ export async function getClaimFeesInstructionAsync<
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountDestinationTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -232,6 +261,8 @@ export type ClaimFeesInput<
   TAccountMarketTokenAta extends string = string,
   TAccountDestinationTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   market: Address<TAccountMarket>;
@@ -240,6 +271,8 @@ export type ClaimFeesInput<
   marketTokenAta: Address<TAccountMarketTokenAta>;
   destinationTokenAccount: Address<TAccountDestinationTokenAccount>;
   tokenProgram: Address<TAccountTokenProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
 };
 
 export function getClaimFeesInstruction<
@@ -250,6 +283,8 @@ export function getClaimFeesInstruction<
   TAccountMarketTokenAta extends string,
   TAccountDestinationTokenAccount extends string,
   TAccountTokenProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: ClaimFeesInput<
@@ -259,7 +294,9 @@ export function getClaimFeesInstruction<
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountDestinationTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): ClaimFeesInstruction<
@@ -270,7 +307,9 @@ export function getClaimFeesInstruction<
   TAccountTokenMint,
   TAccountMarketTokenAta,
   TAccountDestinationTokenAccount,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -288,12 +327,20 @@ export function getClaimFeesInstruction<
       isWritable: true,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Resolve default values.
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
@@ -304,6 +351,8 @@ export function getClaimFeesInstruction<
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.destinationTokenAccount),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getClaimFeesInstructionDataEncoder().encode({}),
     programAddress,
@@ -315,7 +364,9 @@ export function getClaimFeesInstruction<
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountDestinationTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -332,6 +383,8 @@ export type ParsedClaimFeesInstruction<
     marketTokenAta: TAccountMetas[4];
     destinationTokenAccount: TAccountMetas[5];
     tokenProgram: TAccountMetas[6];
+    eventAuthority: TAccountMetas[7];
+    program: TAccountMetas[8];
   };
   data: ClaimFeesInstructionData;
 };
@@ -344,7 +397,7 @@ export function parseClaimFeesInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedClaimFeesInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -364,6 +417,8 @@ export function parseClaimFeesInstruction<
       marketTokenAta: getNextAccount(),
       destinationTokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getClaimFeesInstructionDataDecoder().decode(instruction.data),
   };
@@ -60,6 +60,9 @@ export type CloseOptionAccountInstruction<
   TAccountOption extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -81,6 +84,12 @@ export type CloseOptionAccountInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -127,12 +136,16 @@ export type CloseOptionAccountAsyncInput<
   TAccountMarket extends string = string,
   TAccountOption extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   creator: Address<TAccountCreator>;
   market: Address<TAccountMarket>;
   option?: Address<TAccountOption>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   optionId: CloseOptionAccountInstructionDataArgs['optionId'];
 };
 
@@ -142,6 +155,8 @@ export async function getCloseOptionAccountInstructionAsync<
   TAccountMarket extends string,
   TAccountOption extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: CloseOptionAccountAsyncInput<
@@ -149,7 +164,9 @@ export async function getCloseOptionAccountInstructionAsync<
     TAccountCreator,
     TAccountMarket,
     TAccountOption,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -159,7 +176,9 @@ export async function getCloseOptionAccountInstructionAsync<
     TAccountCreator,
     TAccountMarket,
     TAccountOption,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -173,6 +192,8 @@ export async function getCloseOptionAccountInstructionAsync<
     market: { value: input.market ?? null, isWritable: true },
     option: { value: input.option ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -199,6 +220,10 @@ export async function getCloseOptionAccountInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -208,6 +233,8 @@ export async function getCloseOptionAccountInstructionAsync<
       getAccountMeta(accounts.market),
       getAccountMeta(accounts.option),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getCloseOptionAccountInstructionDataEncoder().encode(
       args as CloseOptionAccountInstructionDataArgs
@@ -219,7 +246,9 @@ export async function getCloseOptionAccountInstructionAsync<
     TAccountCreator,
     TAccountMarket,
     TAccountOption,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -229,12 +258,16 @@ export type CloseOptionAccountInput<
   TAccountMarket extends string = string,
   TAccountOption extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   creator: Address<TAccountCreator>;
   market: Address<TAccountMarket>;
   option: Address<TAccountOption>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   optionId: CloseOptionAccountInstructionDataArgs['optionId'];
 };
 
@@ -244,6 +277,8 @@ export function getCloseOptionAccountInstruction<
   TAccountMarket extends string,
   TAccountOption extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: CloseOptionAccountInput<
@@ -251,7 +286,9 @@ export function getCloseOptionAccountInstruction<
     TAccountCreator,
     TAccountMarket,
     TAccountOption,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): CloseOptionAccountInstruction<
@@ -260,7 +297,9 @@ export function getCloseOptionAccountInstruction<
   TAccountCreator,
   TAccountMarket,
   TAccountOption,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -273,6 +312,8 @@ export function getCloseOptionAccountInstruction<
     market: { value: input.market ?? null, isWritable: true },
     option: { value: input.option ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -287,6 +328,10 @@ export function getCloseOptionAccountInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -296,6 +341,8 @@ export function getCloseOptionAccountInstruction<
       getAccountMeta(accounts.market),
       getAccountMeta(accounts.option),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getCloseOptionAccountInstructionDataEncoder().encode(
       args as CloseOptionAccountInstructionDataArgs
@@ -307,7 +354,9 @@ export function getCloseOptionAccountInstruction<
     TAccountCreator,
     TAccountMarket,
     TAccountOption,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -322,6 +371,8 @@ export type ParsedCloseOptionAccountInstruction<
     market: TAccountMetas[2];
     option: TAccountMetas[3];
     systemProgram: TAccountMetas[4];
+    eventAuthority: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: CloseOptionAccountInstructionData;
 };
@@ -334,7 +385,7 @@ export function parseCloseOptionAccountInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCloseOptionAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -352,6 +403,8 @@ export function parseCloseOptionAccountInstruction<
       market: getNextAccount(),
       option: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getCloseOptionAccountInstructionDataDecoder().decode(
       instruction.data
@@ -11,8 +11,12 @@ import {
   fixDecoderSize,
   fixEncoderSize,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -20,16 +24,20 @@ import {
   getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -58,7 +66,10 @@ export type CloseStakeAccountInstruction<
   TProgram extends string = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountMarket extends string | AccountMeta<string> = string,
+  TAccountPlatformConfig extends string | AccountMeta<string> = string,
   TAccountStakeAccount extends string | AccountMeta<string> = string,
+  TAccountDepositReceipt extends string | AccountMeta<string> = string,
+  TAccountUserPortfolio extends string | AccountMeta<string> = string,
   TAccountOption extends string | AccountMeta<string> = string,
   TAccountTokenMint extends string | AccountMeta<string> = string,
   TAccountMarketTokenAta extends string | AccountMeta<string> = string,
@@ -66,6 +77,10 @@ export type CloseStakeAccountInstruction<
   TAccountTokenProgram extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
+  TAccountAuditLog extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -78,9 +93,18 @@ export type CloseStakeAccountInstruction<
       TAccountMarket extends string
         ? WritableAccount<TAccountMarket>
         : TAccountMarket,
+      TAccountPlatformConfig extends string
+        ? ReadonlyAccount<TAccountPlatformConfig>
+        : TAccountPlatformConfig,
       TAccountStakeAccount extends string
         ? WritableAccount<TAccountStakeAccount>
         : TAccountStakeAccount,
+      TAccountDepositReceipt extends string
+        ? WritableAccount<TAccountDepositReceipt>
+        : TAccountDepositReceipt,
+      TAccountUserPortfolio extends string
+        ? WritableAccount<TAccountUserPortfolio>
+        : TAccountUserPortfolio,
       TAccountOption extends string
         ? WritableAccount<TAccountOption>
         : TAccountOption,
@@ -99,6 +123,15 @@ export type CloseStakeAccountInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountAuditLog extends string
+        ? WritableAccount<TAccountAuditLog>
+        : TAccountAuditLog,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -107,33 +140,47 @@ export type CloseStakeAccountInstructionData = {
   discriminator: ReadonlyUint8Array;
   optionId: bigint;
   stakeAccountId: number;
+  clientId: Option<Array<number>>;
+  sequence: Option<bigint>;
 };
 
 export type CloseStakeAccountInstructionDataArgs = {
   optionId: number | bigint;
   stakeAccountId: number;
+  clientId: OptionOrNullable<Array<number>>;
+  sequence: OptionOrNullable<number | bigint>;
 };
 
-export function getCloseStakeAccountInstructionDataEncoder(): FixedSizeEncoder<CloseStakeAccountInstructionDataArgs> {
+export function getCloseStakeAccountInstructionDataEncoder(): Encoder<CloseStakeAccountInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['optionId', getU64Encoder()],
       ['stakeAccountId', getU32Encoder()],
+      [
+        'clientId',
+        getOptionEncoder(getArrayEncoder(getU8Encoder(), { size: 32 })),
+      ],
+      ['sequence', getOptionEncoder(getU64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: CLOSE_STAKE_ACCOUNT_DISCRIMINATOR })
   );
 }
 
-export function getCloseStakeAccountInstructionDataDecoder(): FixedSizeDecoder<CloseStakeAccountInstructionData> {
+export function getCloseStakeAccountInstructionDataDecoder(): Decoder<CloseStakeAccountInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['optionId', getU64Decoder()],
     ['stakeAccountId', getU32Decoder()],
+    [
+      'clientId',
+      getOptionDecoder(getArrayDecoder(getU8Decoder(), { size: 32 })),
+    ],
+    ['sequence', getOptionDecoder(getU64Decoder())],
   ]);
 }
 
-export function getCloseStakeAccountInstructionDataCodec(): FixedSizeCodec<
+export function getCloseStakeAccountInstructionDataCodec(): Codec<
   CloseStakeAccountInstructionDataArgs,
   CloseStakeAccountInstructionData
 > {
@@ -146,17 +193,28 @@ export function getCloseStakeAccountInstructionDataCodec(): FixedSizeCodec<
 export type CloseStakeAccountAsyncInput<
   TAccountOwner extends string = string,
   TAccountMarket extends string = string,
+  TAccountPlatformConfig extends string = string,
   TAccountStakeAccount extends string = string,
+  TAccountDepositReceipt extends string = string,
+  TAccountUserPortfolio extends string = string,
   TAccountOption extends string = string,
   TAccountTokenMint extends string = string,
   TAccountMarketTokenAta extends string = string,
   TAccountOwnerTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountAuditLog extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   owner: TransactionSigner<TAccountOwner>;
   market: Address<TAccountMarket>;
+  platformConfig: Address<TAccountPlatformConfig>;
   stakeAccount?: Address<TAccountStakeAccount>;
+  /** Optional receipt for integrators reconciling against their own ledger; needs client_id. */
+  depositReceipt?: Address<TAccountDepositReceipt>;
+  /** Required if the position was counted on the owner's portfolio. */
+  userPortfolio?: Address<TAccountUserPortfolio>;
   option?: Address<TAccountOption>;
   tokenMint: Address<TAccountTokenMint>;
   /** Market-owned ATA holding all program-held tokens for this market */
@@ -165,32 +223,50 @@ export type CloseStakeAccountAsyncInput<
   ownerTokenAccount: Address<TAccountOwnerTokenAccount>;
   tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address<TAccountAuditLog>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   optionId: CloseStakeAccountInstructionDataArgs['optionId'];
   stakeAccountId: CloseStakeAccountInstructionDataArgs['stakeAccountId'];
+  clientId: CloseStakeAccountInstructionDataArgs['clientId'];
+  sequence: CloseStakeAccountInstructionDataArgs['sequence'];
 };
 
 export async function getCloseStakeAccountInstructionAsync<
   TAccountOwner extends string,
   TAccountMarket extends string,
+  TAccountPlatformConfig extends string,
   TAccountStakeAccount extends string,
+  TAccountDepositReceipt extends string,
+  TAccountUserPortfolio extends string,
   TAccountOption extends string,
   TAccountTokenMint extends string,
   TAccountMarketTokenAta extends string,
   TAccountOwnerTokenAccount extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountAuditLog extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: CloseStakeAccountAsyncInput<
     TAccountOwner,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountDepositReceipt,
+    TAccountUserPortfolio,
     TAccountOption,
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountOwnerTokenAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -198,13 +274,19 @@ export async function getCloseStakeAccountInstructionAsync<
     TProgramAddress,
     TAccountOwner,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountDepositReceipt,
+    TAccountUserPortfolio,
     TAccountOption,
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountOwnerTokenAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -215,7 +297,10 @@ export async function getCloseStakeAccountInstructionAsync<
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
     market: { value: input.market ?? null, isWritable: true },
+    platformConfig: { value: input.platformConfig ?? null, isWritable: false },
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
+    depositReceipt: { value: input.depositReceipt ?? null, isWritable: true },
+    userPortfolio: { value: input.userPortfolio ?? null, isWritable: true },
     option: { value: input.option ?? null, isWritable: true },
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     marketTokenAta: { value: input.marketTokenAta ?? null, isWritable: true },
@@ -225,6 +310,9 @@ export async function getCloseStakeAccountInstructionAsync<
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -250,6 +338,36 @@ export async function getCloseStakeAccountInstructionAsync<
       ],
     });
   }
+  if (!accounts.depositReceipt.value) {
+    accounts.depositReceipt.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            100, 101, 112, 111, 115, 105, 116, 95, 114, 101, 99, 101, 105, 112,
+            116,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.owner.value)),
+        getOptionEncoder(getArrayEncoder(getU8Encoder(), { size: 32 })).encode(
+          expectSome(args.clientId)
+        ),
+      ],
+    });
+  }
+  if (!accounts.userPortfolio.value) {
+    accounts.userPortfolio.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            117, 115, 101, 114, 95, 112, 111, 114, 116, 102, 111, 108, 105, 111,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.owner.value)),
+      ],
+    });
+  }
   if (!accounts.option.value) {
     accounts.option.value = await getProgramDerivedAddress({
       programAddress,
@@ -277,19 +395,29 @@ export async function getCloseStakeAccountInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.market),
+      getAccountMeta(accounts.platformConfig),
       getAccountMeta(accounts.stakeAccount),
+      getAccountMeta(accounts.depositReceipt),
+      getAccountMeta(accounts.userPortfolio),
       getAccountMeta(accounts.option),
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.ownerTokenAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.auditLog),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getCloseStakeAccountInstructionDataEncoder().encode(
       args as CloseStakeAccountInstructionDataArgs
@@ -299,30 +427,47 @@ export async function getCloseStakeAccountInstructionAsync<
     TProgramAddress,
     TAccountOwner,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountDepositReceipt,
+    TAccountUserPortfolio,
     TAccountOption,
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountOwnerTokenAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
 export type CloseStakeAccountInput<
   TAccountOwner extends string = string,
   TAccountMarket extends string = string,
+  TAccountPlatformConfig extends string = string,
   TAccountStakeAccount extends string = string,
+  TAccountDepositReceipt extends string = string,
+  TAccountUserPortfolio extends string = string,
   TAccountOption extends string = string,
   TAccountTokenMint extends string = string,
   TAccountMarketTokenAta extends string = string,
   TAccountOwnerTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountAuditLog extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   owner: TransactionSigner<TAccountOwner>;
   market: Address<TAccountMarket>;
+  platformConfig: Address<TAccountPlatformConfig>;
   stakeAccount: Address<TAccountStakeAccount>;
+  /** Optional receipt for integrators reconciling against their own ledger; needs client_id. */
+  depositReceipt?: Address<TAccountDepositReceipt>;
+  /** Required if the position was counted on the owner's portfolio. */
+  userPortfolio?: Address<TAccountUserPortfolio>;
   option: Address<TAccountOption>;
   tokenMint: Address<TAccountTokenMint>;
   /** Market-owned ATA holding all program-held tokens for this market */
@@ -331,45 +476,69 @@ export type CloseStakeAccountInput<
   ownerTokenAccount: Address<TAccountOwnerTokenAccount>;
   tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address<TAccountAuditLog>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   optionId: CloseStakeAccountInstructionDataArgs['optionId'];
   stakeAccountId: CloseStakeAccountInstructionDataArgs['stakeAccountId'];
+  clientId: CloseStakeAccountInstructionDataArgs['clientId'];
+  sequence: CloseStakeAccountInstructionDataArgs['sequence'];
 };
 
 export function getCloseStakeAccountInstruction<
   TAccountOwner extends string,
   TAccountMarket extends string,
+  TAccountPlatformConfig extends string,
   TAccountStakeAccount extends string,
+  TAccountDepositReceipt extends string,
+  TAccountUserPortfolio extends string,
   TAccountOption extends string,
   TAccountTokenMint extends string,
   TAccountMarketTokenAta extends string,
   TAccountOwnerTokenAccount extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountAuditLog extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: CloseStakeAccountInput<
     TAccountOwner,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountDepositReceipt,
+    TAccountUserPortfolio,
     TAccountOption,
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountOwnerTokenAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): CloseStakeAccountInstruction<
   TProgramAddress,
   TAccountOwner,
   TAccountMarket,
+  TAccountPlatformConfig,
   TAccountStakeAccount,
+  TAccountDepositReceipt,
+  TAccountUserPortfolio,
   TAccountOption,
   TAccountTokenMint,
   TAccountMarketTokenAta,
   TAccountOwnerTokenAccount,
   TAccountTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountAuditLog,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -379,7 +548,10 @@ export function getCloseStakeAccountInstruction<
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
     market: { value: input.market ?? null, isWritable: true },
+    platformConfig: { value: input.platformConfig ?? null, isWritable: false },
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
+    depositReceipt: { value: input.depositReceipt ?? null, isWritable: true },
+    userPortfolio: { value: input.userPortfolio ?? null, isWritable: true },
     option: { value: input.option ?? null, isWritable: true },
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     marketTokenAta: { value: input.marketTokenAta ?? null, isWritable: true },
@@ -389,6 +561,9 @@ export function getCloseStakeAccountInstruction<
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -403,19 +578,29 @@ export function getCloseStakeAccountInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.market),
+      getAccountMeta(accounts.platformConfig),
       getAccountMeta(accounts.stakeAccount),
+      getAccountMeta(accounts.depositReceipt),
+      getAccountMeta(accounts.userPortfolio),
       getAccountMeta(accounts.option),
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.ownerTokenAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.auditLog),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getCloseStakeAccountInstructionDataEncoder().encode(
       args as CloseStakeAccountInstructionDataArgs
@@ -425,13 +610,19 @@ export function getCloseStakeAccountInstruction<
     TProgramAddress,
     TAccountOwner,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountDepositReceipt,
+    TAccountUserPortfolio,
     TAccountOption,
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountOwnerTokenAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -443,15 +634,24 @@ export type ParsedCloseStakeAccountInstruction<
   accounts: {
     owner: TAccountMetas[0];
     market: TAccountMetas[1];
-    stakeAccount: TAccountMetas[2];
-    option: TAccountMetas[3];
-    tokenMint: TAccountMetas[4];
+    platformConfig: TAccountMetas[2];
+    stakeAccount: TAccountMetas[3];
+    /** Optional receipt for integrators reconciling against their own ledger; needs client_id. */
+    depositReceipt?: TAccountMetas[4] | undefined;
+    /** Required if the position was counted on the owner's portfolio. */
+    userPortfolio?: TAccountMetas[5] | undefined;
+    option: TAccountMetas[6];
+    tokenMint: TAccountMetas[7];
     /** Market-owned ATA holding all program-held tokens for this market */
-    marketTokenAta: TAccountMetas[5];
+    marketTokenAta: TAccountMetas[8];
     /** Owner's token account to receive rewards */
-    ownerTokenAccount: TAccountMetas[6];
-    tokenProgram: TAccountMetas[7];
-    systemProgram: TAccountMetas[8];
+    ownerTokenAccount: TAccountMetas[9];
+    tokenProgram: TAccountMetas[10];
+    systemProgram: TAccountMetas[11];
+    /** Required when the market keeps an audit log. */
+    auditLog?: TAccountMetas[12] | undefined;
+    eventAuthority: TAccountMetas[13];
+    program: TAccountMetas[14];
   };
   data: CloseStakeAccountInstructionData;
 };
@@ -464,7 +664,7 @@ export function parseCloseStakeAccountInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCloseStakeAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 15) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -474,18 +674,30 @@ export function parseCloseStakeAccountInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === OPPORTUNITY_MARKET_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       owner: getNextAccount(),
       market: getNextAccount(),
+      platformConfig: getNextAccount(),
       stakeAccount: getNextAccount(),
+      depositReceipt: getNextOptionalAccount(),
+      userPortfolio: getNextOptionalAccount(),
       option: getNextAccount(),
       tokenMint: getNextAccount(),
       marketTokenAta: getNextAccount(),
       ownerTokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      auditLog: getNextOptionalAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getCloseStakeAccountInstructionDataDecoder().decode(instruction.data),
   };
@@ -57,12 +57,16 @@ export type CloseStuckStakeAccountInstruction<
   TAccountSigner extends string | AccountMeta<string> = string,
   TAccountMarket extends string | AccountMeta<string> = string,
   TAccountStakeAccount extends string | AccountMeta<string> = string,
+  TAccountUserPortfolio extends string | AccountMeta<string> = string,
   TAccountTokenMint extends string | AccountMeta<string> = string,
   TAccountSignerTokenAccount extends string | AccountMeta<string> = string,
   TAccountMarketTokenAta extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -73,11 +77,14 @@ export type CloseStuckStakeAccountInstruction<
             AccountSignerMeta<TAccountSigner>
         : TAccountSigner,
       TAccountMarket extends string
-        ? ReadonlyAccount<TAccountMarket>
+        ? WritableAccount<TAccountMarket>
         : TAccountMarket,
       TAccountStakeAccount extends string
         ? WritableAccount<TAccountStakeAccount>
         : TAccountStakeAccount,
+      TAccountUserPortfolio extends string
+        ? WritableAccount<TAccountUserPortfolio>
+        : TAccountUserPortfolio,
       TAccountTokenMint extends string
         ? ReadonlyAccount<TAccountTokenMint>
         : TAccountTokenMint,
@@ -93,6 +100,12 @@ export type CloseStuckStakeAccountInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -140,21 +153,28 @@ export type CloseStuckStakeAccountAsyncInput<
   TAccountSigner extends string = string,
   TAccountMarket extends string = string,
   TAccountStakeAccount extends string = string,
+  TAccountUserPortfolio extends string = string,
   TAccountTokenMint extends string = string,
   TAccountSignerTokenAccount extends string = string,
   TAccountMarketTokenAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   market: Address<TAccountMarket>;
   stakeAccount?: Address<TAccountStakeAccount>;
+  /** Required if the position was counted on the owner's portfolio. */
+  userPortfolio?: Address<TAccountUserPortfolio>;
   tokenMint: Address<TAccountTokenMint>;
   /** Signer's token account to receive refund */
   signerTokenAccount: Address<TAccountSignerTokenAccount>;
   marketTokenAta?: Address<TAccountMarketTokenAta>;
   tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   stakeAccountId: CloseStuckStakeAccountInstructionDataArgs['stakeAccountId'];
 };
 
@@ -162,22 +182,28 @@ export async function getCloseStuckStakeAccountInstructionAsync<
   TAccountSigner extends string,
   TAccountMarket extends string,
   TAccountStakeAccount extends string,
+  TAccountUserPortfolio extends string,
   TAccountTokenMint extends string,
   TAccountSignerTokenAccount extends string,
   TAccountMarketTokenAta extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: CloseStuckStakeAccountAsyncInput<
     TAccountSigner,
     TAccountMarket,
     TAccountStakeAccount,
+    TAccountUserPortfolio,
     TAccountTokenMint,
     TAccountSignerTokenAccount,
     TAccountMarketTokenAta,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -186,11 +212,14 @@ export async function getCloseStuckStakeAccountInstructionAsync<
     TAccountSigner,
     TAccountMarket,
     TAccountStakeAccount,
+    TAccountUserPortfolio,
     TAccountTokenMint,
     TAccountSignerTokenAccount,
     TAccountMarketTokenAta,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -200,8 +229,9 @@ export async function getCloseStuckStakeAccountInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
-    market: { value: input.market ?? null, isWritable: false },
+    market: { value: input.market ?? null, isWritable: true },
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
+    userPortfolio: { value: input.userPortfolio ?? null, isWritable: true },
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     signerTokenAccount: {
       value: input.signerTokenAccount ?? null,
@@ -210,6 +240,8 @@ export async function getCloseStuckStakeAccountInstructionAsync<
     marketTokenAta: { value: input.marketTokenAta ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -235,6 +267,19 @@ export async function getCloseStuckStakeAccountInstructionAsync<
       ],
     });
   }
+  if (!accounts.userPortfolio.value) {
+    accounts.userPortfolio.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            117, 115, 101, 114, 95, 112, 111, 114, 116, 102, 111, 108, 105, 111,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.signer.value)),
+      ],
+    });
+  }
   if (!accounts.marketTokenAta.value) {
     accounts.marketTokenAta.value = await getProgramDerivedAddress({
       programAddress:
@@ -250,6 +295,10 @@ export async function getCloseStuckStakeAccountInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -257,11 +306,14 @@ export async function getCloseStuckStakeAccountInstructionAsync<
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.market),
       getAccountMeta(accounts.stakeAccount),
+      getAccountMeta(accounts.userPortfolio),
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.signerTokenAccount),
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getCloseStuckStakeAccountInstructionDataEncoder().encode(
       args as CloseStuckStakeAccountInstructionDataArgs
@@ -272,11 +324,14 @@ export async function getCloseStuckStakeAccountInstructionAsync<
     TAccountSigner,
     TAccountMarket,
     TAccountStakeAccount,
+    TAccountUserPortfolio,
     TAccountTokenMint,
     TAccountSignerTokenAccount,
     TAccountMarketTokenAta,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -284,21 +339,28 @@ export type CloseStuckStakeAccountInput<
   TAccountSigner extends string = string,
   TAccountMarket extends string = string,
   TAccountStakeAccount extends string = string,
+  TAccountUserPortfolio extends string = string,
   TAccountTokenMint extends string = string,
   TAccountSignerTokenAccount extends string = string,
   TAccountMarketTokenAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   market: Address<TAccountMarket>;
   stakeAccount: Address<TAccountStakeAccount>;
+  /** Required if the position was counted on the owner's portfolio. */
+  userPortfolio?: Address<TAccountUserPortfolio>;
   tokenMint: Address<TAccountTokenMint>;
   /** Signer's token account to receive refund */
   signerTokenAccount: Address<TAccountSignerTokenAccount>;
   marketTokenAta: Address<TAccountMarketTokenAta>;
   tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   stakeAccountId: CloseStuckStakeAccountInstructionDataArgs['stakeAccountId'];
 };
 
@@ -306,22 +368,28 @@ export function getCloseStuckStakeAccountInstruction<
   TAccountSigner extends string,
   TAccountMarket extends string,
   TAccountStakeAccount extends string,
+  TAccountUserPortfolio extends string,
   TAccountTokenMint extends string,
   TAccountSignerTokenAccount extends string,
   TAccountMarketTokenAta extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: CloseStuckStakeAccountInput<
     TAccountSigner,
     TAccountMarket,
     TAccountStakeAccount,
+    TAccountUserPortfolio,
     TAccountTokenMint,
     TAccountSignerTokenAccount,
     TAccountMarketTokenAta,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): CloseStuckStakeAccountInstruction<
@@ -329,11 +397,14 @@ export function getCloseStuckStakeAccountInstruction<
   TAccountSigner,
   TAccountMarket,
   TAccountStakeAccount,
+  TAccountUserPortfolio,
   TAccountTokenMint,
   TAccountSignerTokenAccount,
   TAccountMarketTokenAta,
   TAccountTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -342,8 +413,9 @@ export function getCloseStuckStakeAccountInstruction<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
-    market: { value: input.market ?? null, isWritable: false },
+    market: { value: input.market ?? null, isWritable: true },
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
+    userPortfolio: { value: input.userPortfolio ?? null, isWritable: true },
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     signerTokenAccount: {
       value: input.signerTokenAccount ?? null,
@@ -352,6 +424,8 @@ export function getCloseStuckStakeAccountInstruction<
     marketTokenAta: { value: input.marketTokenAta ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -366,6 +440,10 @@ export function getCloseStuckStakeAccountInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -373,11 +451,14 @@ export function getCloseStuckStakeAccountInstruction<
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.market),
       getAccountMeta(accounts.stakeAccount),
+      getAccountMeta(accounts.userPortfolio),
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.signerTokenAccount),
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getCloseStuckStakeAccountInstructionDataEncoder().encode(
       args as CloseStuckStakeAccountInstructionDataArgs
@@ -388,11 +469,14 @@ export function getCloseStuckStakeAccountInstruction<
     TAccountSigner,
     TAccountMarket,
     TAccountStakeAccount,
+    TAccountUserPortfolio,
     TAccountTokenMint,
     TAccountSignerTokenAccount,
     TAccountMarketTokenAta,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -405,12 +489,16 @@ export type ParsedCloseStuckStakeAccountInstruction<
     signer: TAccountMetas[0];
     market: TAccountMetas[1];
     stakeAccount: TAccountMetas[2];
-    tokenMint: TAccountMetas[3];
+    /** Required if the position was counted on the owner's portfolio. */
+    userPortfolio?: TAccountMetas[3] | undefined;
+    tokenMint: TAccountMetas[4];
     /** Signer's token account to receive refund */
-    signerTokenAccount: TAccountMetas[4];
-    marketTokenAta: TAccountMetas[5];
-    tokenProgram: TAccountMetas[6];
-    systemProgram: TAccountMetas[7];
+    signerTokenAccount: TAccountMetas[5];
+    marketTokenAta: TAccountMetas[6];
+    tokenProgram: TAccountMetas[7];
+    systemProgram: TAccountMetas[8];
+    eventAuthority: TAccountMetas[9];
+    program: TAccountMetas[10];
   };
   data: CloseStuckStakeAccountInstructionData;
 };
@@ -423,7 +511,7 @@ export function parseCloseStuckStakeAccountInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCloseStuckStakeAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -433,17 +521,26 @@ export function parseCloseStuckStakeAccountInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === OPPORTUNITY_MARKET_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       signer: getNextAccount(),
       market: getNextAccount(),
       stakeAccount: getNextAccount(),
+      userPortfolio: getNextOptionalAccount(),
       tokenMint: getNextAccount(),
       signerTokenAccount: getNextAccount(),
       marketTokenAta: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getCloseStuckStakeAccountInstructionDataDecoder().decode(
       instruction.data
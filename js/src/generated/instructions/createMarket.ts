@@ -69,11 +69,15 @@ export type CreateMarketInstruction<
   TAccountMarket extends string | AccountMeta<string> = string,
   TAccountMarketTokenAta extends string | AccountMeta<string> = string,
   TAccountAllowedMint extends string | AccountMeta<string> = string,
+  TAccountBootstrapState extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> = string,
   TAccountAssociatedTokenProgram extends string | AccountMeta<string> =
     'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -98,6 +102,9 @@ export type CreateMarketInstruction<
       TAccountAllowedMint extends string
         ? ReadonlyAccount<TAccountAllowedMint>
         : TAccountAllowedMint,
+      TAccountBootstrapState extends string
+        ? ReadonlyAccount<TAccountBootstrapState>
+        : TAccountBootstrapState,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
@@ -107,6 +114,12 @@ export type CreateMarketInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -182,9 +195,12 @@ export type CreateMarketAsyncInput<
   TAccountMarket extends string = string,
   TAccountMarketTokenAta extends string = string,
   TAccountAllowedMint extends string = string,
+  TAccountBootstrapState extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   creator: TransactionSigner<TAccountCreator>;
   platformConfig: Address<TAccountPlatformConfig>;
@@ -193,9 +209,13 @@ export type CreateMarketAsyncInput<
   /** This ATA holds all of the market's program-held tokens (stakes, rewards, fees). */
   marketTokenAta?: Address<TAccountMarketTokenAta>;
   allowedMint?: Address<TAccountAllowedMint>;
+  /** Markets can't be staked on until every computation definition exists. */
+  bootstrapState?: Address<TAccountBootstrapState>;
   tokenProgram: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   marketIndex: CreateMarketInstructionDataArgs['marketIndex'];
   marketAuthority: CreateMarketInstructionDataArgs['marketAuthority'];
   allowUnstakingEarly: CreateMarketInstructionDataArgs['allowUnstakingEarly'];
@@ -213,9 +233,12 @@ export async function getCreateMarketInstructionAsync<
   TAccountMarket extends string,
   TAccountMarketTokenAta extends string,
   TAccountAllowedMint extends string,
+  TAccountBootstrapState extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: CreateMarketAsyncInput<
@@ -225,9 +248,12 @@ export async function getCreateMarketInstructionAsync<
     TAccountMarket,
     TAccountMarketTokenAta,
     TAccountAllowedMint,
+    TAccountBootstrapState,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -239,9 +265,12 @@ export async function getCreateMarketInstructionAsync<
     TAccountMarket,
     TAccountMarketTokenAta,
     TAccountAllowedMint,
+    TAccountBootstrapState,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -256,12 +285,15 @@ export async function getCreateMarketInstructionAsync<
     market: { value: input.market ?? null, isWritable: true },
     marketTokenAta: { value: input.marketTokenAta ?? null, isWritable: true },
     allowedMint: { value: input.allowedMint ?? null, isWritable: false },
+    bootstrapState: { value: input.bootstrapState ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -317,6 +349,19 @@ export async function getCreateMarketInstructionAsync<
       ],
     });
   }
+  if (!accounts.bootstrapState.value) {
+    accounts.bootstrapState.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            98, 111, 111, 116, 115, 116, 114, 97, 112, 95, 115, 116, 97, 116,
+            101,
+          ])
+        ),
+      ],
+    });
+  }
   if (!accounts.associatedTokenProgram.value) {
     accounts.associatedTokenProgram.value =
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
@@ -325,6 +370,10 @@ export async function getCreateMarketInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -335,9 +384,12 @@ export async function getCreateMarketInstructionAsync<
       getAccountMeta(accounts.market),
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.allowedMint),
+      getAccountMeta(accounts.bootstrapState),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getCreateMarketInstructionDataEncoder().encode(
       args as CreateMarketInstructionDataArgs
@@ -351,9 +403,12 @@ export async function getCreateMarketInstructionAsync<
     TAccountMarket,
     TAccountMarketTokenAta,
     TAccountAllowedMint,
+    TAccountBootstrapState,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -364,9 +419,12 @@ export type CreateMarketInput<
   TAccountMarket extends string = string,
   TAccountMarketTokenAta extends string = string,
   TAccountAllowedMint extends string = string,
+  TAccountBootstrapState extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   creator: TransactionSigner<TAccountCreator>;
   platformConfig: Address<TAccountPlatformConfig>;
@@ -375,9 +433,13 @@ export type CreateMarketInput<
   /** This ATA holds all of the market's program-held tokens (stakes, rewards, fees). */
   marketTokenAta: Address<TAccountMarketTokenAta>;
   allowedMint: Address<TAccountAllowedMint>;
+  /** Markets can't be staked on until every computation definition exists. */
+  bootstrapState: Address<TAccountBootstrapState>;
   tokenProgram: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   marketIndex: CreateMarketInstructionDataArgs['marketIndex'];
   marketAuthority: CreateMarketInstructionDataArgs['marketAuthority'];
   allowUnstakingEarly: CreateMarketInstructionDataArgs['allowUnstakingEarly'];
@@ -395,9 +457,12 @@ export function getCreateMarketInstruction<
   TAccountMarket extends string,
   TAccountMarketTokenAta extends string,
   TAccountAllowedMint extends string,
+  TAccountBootstrapState extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: CreateMarketInput<
@@ -407,9 +472,12 @@ export function getCreateMarketInstruction<
     TAccountMarket,
     TAccountMarketTokenAta,
     TAccountAllowedMint,
+    TAccountBootstrapState,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): CreateMarketInstruction<
@@ -420,9 +488,12 @@ export function getCreateMarketInstruction<
   TAccountMarket,
   TAccountMarketTokenAta,
   TAccountAllowedMint,
+  TAccountBootstrapState,
   TAccountTokenProgram,
   TAccountAssociatedTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -436,12 +507,15 @@ export function getCreateMarketInstruction<
     market: { value: input.market ?? null, isWritable: true },
     marketTokenAta: { value: input.marketTokenAta ?? null, isWritable: true },
     allowedMint: { value: input.allowedMint ?? null, isWritable: false },
+    bootstrapState: { value: input.bootstrapState ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -460,6 +534,10 @@ export function getCreateMarketInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -470,9 +548,12 @@ export function getCreateMarketInstruction<
       getAccountMeta(accounts.market),
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.allowedMint),
+      getAccountMeta(accounts.bootstrapState),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getCreateMarketInstructionDataEncoder().encode(
       args as CreateMarketInstructionDataArgs
@@ -486,9 +567,12 @@ export function getCreateMarketInstruction<
     TAccountMarket,
     TAccountMarketTokenAta,
     TAccountAllowedMint,
+    TAccountBootstrapState,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -505,9 +589,13 @@ export type ParsedCreateMarketInstruction<
     /** This ATA holds all of the market's program-held tokens (stakes, rewards, fees). */
     marketTokenAta: TAccountMetas[4];
     allowedMint: TAccountMetas[5];
-    tokenProgram: TAccountMetas[6];
-    associatedTokenProgram: TAccountMetas[7];
-    systemProgram: TAccountMetas[8];
+    /** Markets can't be staked on until every computation definition exists. */
+    bootstrapState: TAccountMetas[6];
+    tokenProgram: TAccountMetas[7];
+    associatedTokenProgram: TAccountMetas[8];
+    systemProgram: TAccountMetas[9];
+    eventAuthority: TAccountMetas[10];
+    program: TAccountMetas[11];
   };
   data: CreateMarketInstructionData;
 };
@@ -520,7 +608,7 @@ export function parseCreateMarketInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateMarketInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -539,9 +627,12 @@ export function parseCreateMarketInstruction<
       market: getNextAccount(),
       marketTokenAta: getNextAccount(),
       allowedMint: getNextAccount(),
+      bootstrapState: getNextAccount(),
       tokenProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getCreateMarketInstructionDataDecoder().decode(instruction.data),
   };
@@ -10,8 +10,10 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   transformEncoder,
@@ -31,7 +33,11 @@ import {
   type WritableAccount,
 } from '@solana/kit';
 import { OPPORTUNITY_MARKET_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const END_REVEAL_PERIOD_DISCRIMINATOR = new Uint8Array([
   144, 81, 42, 65, 127, 60, 134, 92,
@@ -48,6 +54,11 @@ export type EndRevealPeriodInstruction<
   TAccountSigner extends string | AccountMeta<string> = string,
   TAccountMarket extends string | AccountMeta<string> = string,
   TAccountPlatformConfig extends string | AccountMeta<string> = string,
+  TAccountMarketResult extends string | AccountMeta<string> = string,
+  TAccountAuditLog extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -63,6 +74,18 @@ export type EndRevealPeriodInstruction<
       TAccountPlatformConfig extends string
         ? ReadonlyAccount<TAccountPlatformConfig>
         : TAccountPlatformConfig,
+      TAccountMarketResult extends string
+        ? WritableAccount<TAccountMarketResult>
+        : TAccountMarketResult,
+      TAccountAuditLog extends string
+        ? WritableAccount<TAccountAuditLog>
+        : TAccountAuditLog,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -96,33 +119,168 @@ export function getEndRevealPeriodInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type EndRevealPeriodAsyncInput<
+  TAccountSigner extends string = string,
+  TAccountMarket extends string = string,
+  TAccountPlatformConfig extends string = string,
+  TAccountMarketResult extends string = string,
+  TAccountAuditLog extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
+> = {
+  signer: TransactionSigner<TAccountSigner>;
+  market: Address<TAccountMarket>;
+  platformConfig: Address<TAccountPlatformConfig>;
+  marketResult?: Address<TAccountMarketResult>;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address<TAccountAuditLog>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
+};
+
+export async function getEndRevealPeriodInstructionAsync<
+  TAccountSigner extends string,
+  TAccountMarket extends string,
+  TAccountPlatformConfig extends string,
+  TAccountMarketResult extends string,
+  TAccountAuditLog extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
+  TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
+>(
+  input: EndRevealPeriodAsyncInput<
+    TAccountSigner,
+    TAccountMarket,
+    TAccountPlatformConfig,
+    TAccountMarketResult,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  EndRevealPeriodInstruction<
+    TProgramAddress,
+    TAccountSigner,
+    TAccountMarket,
+    TAccountPlatformConfig,
+    TAccountMarketResult,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
+  >
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? OPPORTUNITY_MARKET_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    signer: { value: input.signer ?? null, isWritable: false },
+    market: { value: input.market ?? null, isWritable: true },
+    platformConfig: { value: input.platformConfig ?? null, isWritable: false },
+    marketResult: { value: input.marketResult ?? null, isWritable: true },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.marketResult.value) {
+    accounts.marketResult.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            109, 97, 114, 107, 101, 116, 95, 114, 101, 115, 117, 108, 116,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
+      ],
+    });
+  }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.market),
+      getAccountMeta(accounts.platformConfig),
+      getAccountMeta(accounts.marketResult),
+      getAccountMeta(accounts.auditLog),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
+    ],
+    data: getEndRevealPeriodInstructionDataEncoder().encode({}),
+    programAddress,
+  } as EndRevealPeriodInstruction<
+    TProgramAddress,
+    TAccountSigner,
+    TAccountMarket,
+    TAccountPlatformConfig,
+    TAccountMarketResult,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
+  >);
+}
+
 export type EndRevealPeriodInput<
   TAccountSigner extends string = string,
   TAccountMarket extends string = string,
   TAccountPlatformConfig extends string = string,
+  TAccountMarketResult extends string = string,
+  TAccountAuditLog extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   market: Address<TAccountMarket>;
   platformConfig: Address<TAccountPlatformConfig>;
+  marketResult: Address<TAccountMarketResult>;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address<TAccountAuditLog>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
 };
 
 export function getEndRevealPeriodInstruction<
   TAccountSigner extends string,
   TAccountMarket extends string,
   TAccountPlatformConfig extends string,
+  TAccountMarketResult extends string,
+  TAccountAuditLog extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: EndRevealPeriodInput<
     TAccountSigner,
     TAccountMarket,
-    TAccountPlatformConfig
+    TAccountPlatformConfig,
+    TAccountMarketResult,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): EndRevealPeriodInstruction<
   TProgramAddress,
   TAccountSigner,
   TAccountMarket,
-  TAccountPlatformConfig
+  TAccountPlatformConfig,
+  TAccountMarketResult,
+  TAccountAuditLog,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -133,18 +291,32 @@ export function getEndRevealPeriodInstruction<
     signer: { value: input.signer ?? null, isWritable: false },
     market: { value: input.market ?? null, isWritable: true },
     platformConfig: { value: input.platformConfig ?? null, isWritable: false },
+    marketResult: { value: input.marketResult ?? null, isWritable: true },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Resolve default values.
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.market),
       getAccountMeta(accounts.platformConfig),
+      getAccountMeta(accounts.marketResult),
+      getAccountMeta(accounts.auditLog),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getEndRevealPeriodInstructionDataEncoder().encode({}),
     programAddress,
@@ -152,7 +324,11 @@ export function getEndRevealPeriodInstruction<
     TProgramAddress,
     TAccountSigner,
     TAccountMarket,
-    TAccountPlatformConfig
+    TAccountPlatformConfig,
+    TAccountMarketResult,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -165,6 +341,11 @@ export type ParsedEndRevealPeriodInstruction<
     signer: TAccountMetas[0];
     market: TAccountMetas[1];
     platformConfig: TAccountMetas[2];
+    marketResult: TAccountMetas[3];
+    /** Required when the market keeps an audit log. */
+    auditLog?: TAccountMetas[4] | undefined;
+    eventAuthority: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: EndRevealPeriodInstructionData;
 };
@@ -177,7 +358,7 @@ export function parseEndRevealPeriodInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedEndRevealPeriodInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -187,12 +368,22 @@ export function parseEndRevealPeriodInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === OPPORTUNITY_MARKET_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       signer: getNextAccount(),
       market: getNextAccount(),
       platformConfig: getNextAccount(),
+      marketResult: getNextAccount(),
+      auditLog: getNextOptionalAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getEndRevealPeriodInstructionDataDecoder().decode(instruction.data),
   };
@@ -63,6 +63,9 @@ export type FinalizeRevealStakeInstruction<
   TAccountOption extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -87,6 +90,12 @@ export type FinalizeRevealStakeInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -141,6 +150,8 @@ export type FinalizeRevealStakeAsyncInput<
   TAccountStakeAccount extends string = string,
   TAccountOption extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   owner: Address<TAccountOwner>;
@@ -148,6 +159,8 @@ export type FinalizeRevealStakeAsyncInput<
   stakeAccount?: Address<TAccountStakeAccount>;
   option?: Address<TAccountOption>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   optionId: FinalizeRevealStakeInstructionDataArgs['optionId'];
   stakeAccountId: FinalizeRevealStakeInstructionDataArgs['stakeAccountId'];
 };
@@ -159,6 +172,8 @@ export async function getFinalizeRevealStakeInstructionAsync<
   TAccountStakeAccount extends string,
   TAccountOption extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: FinalizeRevealStakeAsyncInput<
@@ -167,7 +182,9 @@ export async function getFinalizeRevealStakeInstructionAsync<
     TAccountMarket,
     TAccountStakeAccount,
     TAccountOption,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -178,7 +195,9 @@ export async function getFinalizeRevealStakeInstructionAsync<
     TAccountMarket,
     TAccountStakeAccount,
     TAccountOption,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -193,6 +212,8 @@ export async function getFinalizeRevealStakeInstructionAsync<
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
     option: { value: input.option ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -234,6 +255,10 @@ export async function getFinalizeRevealStakeInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -244,6 +269,8 @@ export async function getFinalizeRevealStakeInstructionAsync<
       getAccountMeta(accounts.stakeAccount),
       getAccountMeta(accounts.option),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getFinalizeRevealStakeInstructionDataEncoder().encode(
       args as FinalizeRevealStakeInstructionDataArgs
@@ -256,7 +283,9 @@ export async function getFinalizeRevealStakeInstructionAsync<
     TAccountMarket,
     TAccountStakeAccount,
     TAccountOption,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -267,6 +296,8 @@ export type FinalizeRevealStakeInput<
   TAccountStakeAccount extends string = string,
   TAccountOption extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   owner: Address<TAccountOwner>;
@@ -274,6 +305,8 @@ export type FinalizeRevealStakeInput<
   stakeAccount: Address<TAccountStakeAccount>;
   option: Address<TAccountOption>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   optionId: FinalizeRevealStakeInstructionDataArgs['optionId'];
   stakeAccountId: FinalizeRevealStakeInstructionDataArgs['stakeAccountId'];
 };
@@ -285,6 +318,8 @@ export function getFinalizeRevealStakeInstruction<
   TAccountStakeAccount extends string,
   TAccountOption extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: FinalizeRevealStakeInput<
@@ -293,7 +328,9 @@ export function getFinalizeRevealStakeInstruction<
     TAccountMarket,
     TAccountStakeAccount,
     TAccountOption,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): FinalizeRevealStakeInstruction<
@@ -303,7 +340,9 @@ export function getFinalizeRevealStakeInstruction<
   TAccountMarket,
   TAccountStakeAccount,
   TAccountOption,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -317,6 +356,8 @@ export function getFinalizeRevealStakeInstruction<
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
     option: { value: input.option ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -331,6 +372,10 @@ export function getFinalizeRevealStakeInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -341,6 +386,8 @@ export function getFinalizeRevealStakeInstruction<
       getAccountMeta(accounts.stakeAccount),
       getAccountMeta(accounts.option),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getFinalizeRevealStakeInstructionDataEncoder().encode(
       args as FinalizeRevealStakeInstructionDataArgs
@@ -353,7 +400,9 @@ export function getFinalizeRevealStakeInstruction<
     TAccountMarket,
     TAccountStakeAccount,
     TAccountOption,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -369,6 +418,8 @@ export type ParsedFinalizeRevealStakeInstruction<
     stakeAccount: TAccountMetas[3];
     option: TAccountMetas[4];
     systemProgram: TAccountMetas[5];
+    eventAuthority: TAccountMetas[6];
+    program: TAccountMetas[7];
   };
   data: FinalizeRevealStakeInstructionData;
 };
@@ -381,7 +432,7 @@ export function parseFinalizeRevealStakeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedFinalizeRevealStakeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -400,6 +451,8 @@ export function parseFinalizeRevealStakeInstruction<
       stakeAccount: getNextAccount(),
       option: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getFinalizeRevealStakeInstructionDataDecoder().decode(
       instruction.data
@@ -6,30 +6,65 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptUpdateAuthority';
 export * from './addMarketOption';
 export * from './addReward';
+export * from './cancelConfigChange';
 export * from './claimCreatorFees';
 export * from './claimFees';
+export * from './claimRewardTranche';
 export * from './closeOptionAccount';
 export * from './closeStakeAccount';
 export * from './closeStuckStakeAccount';
+export * from './createCategory';
 export * from './createMarket';
+export * from './createMarketWithOptions';
+export * from './emergencyResolve';
 export * from './endRevealPeriod';
+export * from './executeConfigChange';
 export * from './finalizeRevealStake';
+export * from './finalizeRevealStakes';
+export * from './fundCrankBudget';
+export * from './getClaimable';
+export * from './getMarketPhase';
 export * from './initAllowedMint';
+export * from './initAuditLog';
+export * from './initializeCompDefs';
 export * from './initPlatformConfig';
 export * from './initStakeAccount';
+export * from './initUserPortfolio';
+export * from './issueParticipantPass';
+export * from './migrateStake';
 export * from './openMarket';
+export * from './removeMarketOption';
 export * from './resolveMarket';
 export * from './revealStake';
+export * from './revealStakeBatch';
+export * from './revealStakeBatchCallback';
+export * from './revealStakeBatchCompDef';
 export * from './revealStakeCallback';
 export * from './revealStakeCompDef';
+export * from './revokeParticipantPass';
 export * from './setFeeClaimAuthority';
+export * from './setGateMint';
+export * from './setLoserRebate';
+export * from './setMarketCategory';
+export * from './setParticipantsRestricted';
+export * from './setParticipationThreshold';
+export * from './setPlatformPaused';
+export * from './setRewardVesting';
+export * from './setSuccessorMarket';
+export * from './setTransferHookPrograms';
 export * from './setUpdateAuthority';
 export * from './setWinningOption';
 export * from './stake';
 export * from './stakeCallback';
 export * from './stakeCompDef';
 export * from './unstake';
+export * from './updateMarketTiming';
 export * from './updatePlatformConfig';
+export * from './verifyStakeDisclosure';
+export * from './verifyStakeDisclosureCallback';
+export * from './verifyStakeDisclosureCompDef';
+export * from './withdrawCrankBudget';
 export * from './withdrawReward';
@@ -57,6 +57,9 @@ export type InitAllowedMintInstruction<
   TAccountAllowedMint extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -78,6 +81,12 @@ export type InitAllowedMintInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -117,12 +126,16 @@ export type InitAllowedMintAsyncInput<
   TAccountTokenMint extends string = string,
   TAccountAllowedMint extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   updateAuthority: TransactionSigner<TAccountUpdateAuthority>;
   platformConfig: Address<TAccountPlatformConfig>;
   tokenMint: Address<TAccountTokenMint>;
   allowedMint?: Address<TAccountAllowedMint>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
 };
 
 export async function getInitAllowedMintInstructionAsync<
@@ -131,6 +144,8 @@ export async function getInitAllowedMintInstructionAsync<
   TAccountTokenMint extends string,
   TAccountAllowedMint extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: InitAllowedMintAsyncInput<
@@ -138,7 +153,9 @@ export async function getInitAllowedMintInstructionAsync<
     TAccountPlatformConfig,
     TAccountTokenMint,
     TAccountAllowedMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -148,7 +165,9 @@ export async function getInitAllowedMintInstructionAsync<
     TAccountPlatformConfig,
     TAccountTokenMint,
     TAccountAllowedMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -162,6 +181,8 @@ export async function getInitAllowedMintInstructionAsync<
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     allowedMint: { value: input.allowedMint ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -189,6 +210,10 @@ export async function getInitAllowedMintInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -198,6 +223,8 @@ export async function getInitAllowedMintInstructionAsync<
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.allowedMint),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getInitAllowedMintInstructionDataEncoder().encode({}),
     programAddress,
@@ -207,7 +234,9 @@ export async function getInitAllowedMintInstructionAsync<
     TAccountPlatformConfig,
     TAccountTokenMint,
     TAccountAllowedMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -217,12 +246,16 @@ export type InitAllowedMintInput<
   TAccountTokenMint extends string = string,
   TAccountAllowedMint extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   updateAuthority: TransactionSigner<TAccountUpdateAuthority>;
   platformConfig: Address<TAccountPlatformConfig>;
   tokenMint: Address<TAccountTokenMint>;
   allowedMint: Address<TAccountAllowedMint>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
 };
 
 export function getInitAllowedMintInstruction<
@@ -231,6 +264,8 @@ export function getInitAllowedMintInstruction<
   TAccountTokenMint extends string,
   TAccountAllowedMint extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: InitAllowedMintInput<
@@ -238,7 +273,9 @@ export function getInitAllowedMintInstruction<
     TAccountPlatformConfig,
     TAccountTokenMint,
     TAccountAllowedMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): InitAllowedMintInstruction<
@@ -247,7 +284,9 @@ export function getInitAllowedMintInstruction<
   TAccountPlatformConfig,
   TAccountTokenMint,
   TAccountAllowedMint,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -260,6 +299,8 @@ export function getInitAllowedMintInstruction<
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     allowedMint: { value: input.allowedMint ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -271,6 +312,10 @@ export function getInitAllowedMintInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -280,6 +325,8 @@ export function getInitAllowedMintInstruction<
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.allowedMint),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getInitAllowedMintInstructionDataEncoder().encode({}),
     programAddress,
@@ -289,7 +336,9 @@ export function getInitAllowedMintInstruction<
     TAccountPlatformConfig,
     TAccountTokenMint,
     TAccountAllowedMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -304,6 +353,8 @@ export type ParsedInitAllowedMintInstruction<
     tokenMint: TAccountMetas[2];
     allowedMint: TAccountMetas[3];
     systemProgram: TAccountMetas[4];
+    eventAuthority: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: InitAllowedMintInstructionData;
 };
@@ -316,7 +367,7 @@ export function parseInitAllowedMintInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedInitAllowedMintInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -334,6 +385,8 @@ export function parseInitAllowedMintInstruction<
       tokenMint: getNextAccount(),
       allowedMint: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getInitAllowedMintInstructionDataDecoder().decode(instruction.data),
   };
@@ -60,6 +60,9 @@ export type InitStakeAccountInstruction<
   TAccountStakeAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -81,6 +84,12 @@ export type InitStakeAccountInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -125,6 +134,8 @@ export type InitStakeAccountAsyncInput<
   TAccountMarket extends string = string,
   TAccountStakeAccount extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
   /** No signature required: this instruction is permissionless. */
@@ -132,6 +143,8 @@ export type InitStakeAccountAsyncInput<
   market: Address<TAccountMarket>;
   stakeAccount?: Address<TAccountStakeAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   stakeAccountId: InitStakeAccountInstructionDataArgs['stakeAccountId'];
 };
 
@@ -141,6 +154,8 @@ export async function getInitStakeAccountInstructionAsync<
   TAccountMarket extends string,
   TAccountStakeAccount extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: InitStakeAccountAsyncInput<
@@ -148,7 +163,9 @@ export async function getInitStakeAccountInstructionAsync<
     TAccountOwner,
     TAccountMarket,
     TAccountStakeAccount,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -158,7 +175,9 @@ export async function getInitStakeAccountInstructionAsync<
     TAccountOwner,
     TAccountMarket,
     TAccountStakeAccount,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -172,6 +191,8 @@ export async function getInitStakeAccountInstructionAsync<
     market: { value: input.market ?? null, isWritable: false },
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -201,6 +222,10 @@ export async function getInitStakeAccountInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -210,6 +235,8 @@ export async function getInitStakeAccountInstructionAsync<
       getAccountMeta(accounts.market),
       getAccountMeta(accounts.stakeAccount),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getInitStakeAccountInstructionDataEncoder().encode(
       args as InitStakeAccountInstructionDataArgs
@@ -221,7 +248,9 @@ export async function getInitStakeAccountInstructionAsync<
     TAccountOwner,
     TAccountMarket,
     TAccountStakeAccount,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -231,6 +260,8 @@ export type InitStakeAccountInput<
   TAccountMarket extends string = string,
   TAccountStakeAccount extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
   /** No signature required: this instruction is permissionless. */
@@ -238,6 +269,8 @@ export type InitStakeAccountInput<
   market: Address<TAccountMarket>;
   stakeAccount: Address<TAccountStakeAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   stakeAccountId: InitStakeAccountInstructionDataArgs['stakeAccountId'];
 };
 
@@ -247,6 +280,8 @@ export function getInitStakeAccountInstruction<
   TAccountMarket extends string,
   TAccountStakeAccount extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: InitStakeAccountInput<
@@ -254,7 +289,9 @@ export function getInitStakeAccountInstruction<
     TAccountOwner,
     TAccountMarket,
     TAccountStakeAccount,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): InitStakeAccountInstruction<
@@ -263,7 +300,9 @@ export function getInitStakeAccountInstruction<
   TAccountOwner,
   TAccountMarket,
   TAccountStakeAccount,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -276,6 +315,8 @@ export function getInitStakeAccountInstruction<
     market: { value: input.market ?? null, isWritable: false },
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -290,6 +331,10 @@ export function getInitStakeAccountInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -299,6 +344,8 @@ export function getInitStakeAccountInstruction<
       getAccountMeta(accounts.market),
       getAccountMeta(accounts.stakeAccount),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getInitStakeAccountInstructionDataEncoder().encode(
       args as InitStakeAccountInstructionDataArgs
@@ -310,7 +357,9 @@ export function getInitStakeAccountInstruction<
     TAccountOwner,
     TAccountMarket,
     TAccountStakeAccount,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -326,6 +375,8 @@ export type ParsedInitStakeAccountInstruction<
     market: TAccountMetas[2];
     stakeAccount: TAccountMetas[3];
     systemProgram: TAccountMetas[4];
+    eventAuthority: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: InitStakeAccountInstructionData;
 };
@@ -338,7 +389,7 @@ export function parseInitStakeAccountInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedInitStakeAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -356,6 +407,8 @@ export function parseInitStakeAccountInstruction<
       market: getNextAccount(),
       stakeAccount: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getInitStakeAccountInstructionDataDecoder().decode(instruction.data),
   };
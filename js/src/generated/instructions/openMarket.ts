@@ -48,6 +48,10 @@ export type OpenMarketInstruction<
   TAccountMarketAuthority extends string | AccountMeta<string> = string,
   TAccountMarket extends string | AccountMeta<string> = string,
   TAccountPlatformConfig extends string | AccountMeta<string> = string,
+  TAccountAuditLog extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -63,6 +67,15 @@ export type OpenMarketInstruction<
       TAccountPlatformConfig extends string
         ? ReadonlyAccount<TAccountPlatformConfig>
         : TAccountPlatformConfig,
+      TAccountAuditLog extends string
+        ? WritableAccount<TAccountAuditLog>
+        : TAccountAuditLog,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -105,10 +118,17 @@ export type OpenMarketInput<
   TAccountMarketAuthority extends string = string,
   TAccountMarket extends string = string,
   TAccountPlatformConfig extends string = string,
+  TAccountAuditLog extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   marketAuthority: TransactionSigner<TAccountMarketAuthority>;
   market: Address<TAccountMarket>;
   platformConfig: Address<TAccountPlatformConfig>;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address<TAccountAuditLog>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   timeToStake: OpenMarketInstructionDataArgs['timeToStake'];
 };
 
@@ -116,19 +136,28 @@ export function getOpenMarketInstruction<
   TAccountMarketAuthority extends string,
   TAccountMarket extends string,
   TAccountPlatformConfig extends string,
+  TAccountAuditLog extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: OpenMarketInput<
     TAccountMarketAuthority,
     TAccountMarket,
-    TAccountPlatformConfig
+    TAccountPlatformConfig,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): OpenMarketInstruction<
   TProgramAddress,
   TAccountMarketAuthority,
   TAccountMarket,
-  TAccountPlatformConfig
+  TAccountPlatformConfig,
+  TAccountAuditLog,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -142,6 +171,9 @@ export function getOpenMarketInstruction<
     },
     market: { value: input.market ?? null, isWritable: true },
     platformConfig: { value: input.platformConfig ?? null, isWritable: false },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -151,12 +183,21 @@ export function getOpenMarketInstruction<
   // Original args.
   const args = { ...input };
 
+  // Resolve default values.
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.marketAuthority),
       getAccountMeta(accounts.market),
       getAccountMeta(accounts.platformConfig),
+      getAccountMeta(accounts.auditLog),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getOpenMarketInstructionDataEncoder().encode(
       args as OpenMarketInstructionDataArgs
@@ -166,7 +207,10 @@ export function getOpenMarketInstruction<
     TProgramAddress,
     TAccountMarketAuthority,
     TAccountMarket,
-    TAccountPlatformConfig
+    TAccountPlatformConfig,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -179,6 +223,10 @@ export type ParsedOpenMarketInstruction<
     marketAuthority: TAccountMetas[0];
     market: TAccountMetas[1];
     platformConfig: TAccountMetas[2];
+    /** Required when the market keeps an audit log. */
+    auditLog?: TAccountMetas[3] | undefined;
+    eventAuthority: TAccountMetas[4];
+    program: TAccountMetas[5];
   };
   data: OpenMarketInstructionData;
 };
@@ -191,7 +239,7 @@ export function parseOpenMarketInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedOpenMarketInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -201,12 +249,21 @@ export function parseOpenMarketInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === OPPORTUNITY_MARKET_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       marketAuthority: getNextAccount(),
       market: getNextAccount(),
       platformConfig: getNextAccount(),
+      auditLog: getNextOptionalAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getOpenMarketInstructionDataDecoder().decode(instruction.data),
   };
@@ -10,8 +10,10 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   transformEncoder,
@@ -24,13 +26,18 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
-  type ReadonlySignerAccount,
+  type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/kit';
 import { OPPORTUNITY_MARKET_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const RESOLVE_MARKET_DISCRIMINATOR = new Uint8Array([
   155, 23, 80, 173, 46, 74, 23, 239,
@@ -46,18 +53,40 @@ export type ResolveMarketInstruction<
   TProgram extends string = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
   TAccountMarketAuthority extends string | AccountMeta<string> = string,
   TAccountMarket extends string | AccountMeta<string> = string,
+  TAccountMarketResult extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends string | AccountMeta<string> =
+    '11111111111111111111111111111111',
+  TAccountAuditLog extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
   InstructionWithAccounts<
     [
       TAccountMarketAuthority extends string
-        ? ReadonlySignerAccount<TAccountMarketAuthority> &
+        ? WritableSignerAccount<TAccountMarketAuthority> &
             AccountSignerMeta<TAccountMarketAuthority>
         : TAccountMarketAuthority,
       TAccountMarket extends string
         ? WritableAccount<TAccountMarket>
         : TAccountMarket,
+      TAccountMarketResult extends string
+        ? WritableAccount<TAccountMarketResult>
+        : TAccountMarketResult,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountAuditLog extends string
+        ? WritableAccount<TAccountAuditLog>
+        : TAccountAuditLog,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -91,25 +120,172 @@ export function getResolveMarketInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type ResolveMarketAsyncInput<
+  TAccountMarketAuthority extends string = string,
+  TAccountMarket extends string = string,
+  TAccountMarketResult extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountAuditLog extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
+> = {
+  marketAuthority: TransactionSigner<TAccountMarketAuthority>;
+  market: Address<TAccountMarket>;
+  marketResult?: Address<TAccountMarketResult>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address<TAccountAuditLog>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
+};
+
+export async function getResolveMarketInstructionAsync<
+  TAccountMarketAuthority extends string,
+  TAccountMarket extends string,
+  TAccountMarketResult extends string,
+  TAccountSystemProgram extends string,
+  TAccountAuditLog extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
+  TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
+>(
+  input: ResolveMarketAsyncInput<
+    TAccountMarketAuthority,
+    TAccountMarket,
+    TAccountMarketResult,
+    TAccountSystemProgram,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  ResolveMarketInstruction<
+    TProgramAddress,
+    TAccountMarketAuthority,
+    TAccountMarket,
+    TAccountMarketResult,
+    TAccountSystemProgram,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
+  >
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? OPPORTUNITY_MARKET_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    marketAuthority: { value: input.marketAuthority ?? null, isWritable: true },
+    market: { value: input.market ?? null, isWritable: true },
+    marketResult: { value: input.marketResult ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.marketResult.value) {
+    accounts.marketResult.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            109, 97, 114, 107, 101, 116, 95, 114, 101, 115, 117, 108, 116,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
+      ],
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.marketAuthority),
+      getAccountMeta(accounts.market),
+      getAccountMeta(accounts.marketResult),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.auditLog),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
+    ],
+    data: getResolveMarketInstructionDataEncoder().encode({}),
+    programAddress,
+  } as ResolveMarketInstruction<
+    TProgramAddress,
+    TAccountMarketAuthority,
+    TAccountMarket,
+    TAccountMarketResult,
+    TAccountSystemProgram,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
+  >);
+}
+
 export type ResolveMarketInput<
   TAccountMarketAuthority extends string = string,
   TAccountMarket extends string = string,
+  TAccountMarketResult extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountAuditLog extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   marketAuthority: TransactionSigner<TAccountMarketAuthority>;
   market: Address<TAccountMarket>;
+  marketResult: Address<TAccountMarketResult>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address<TAccountAuditLog>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
 };
 
 export function getResolveMarketInstruction<
   TAccountMarketAuthority extends string,
   TAccountMarket extends string,
+  TAccountMarketResult extends string,
+  TAccountSystemProgram extends string,
+  TAccountAuditLog extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
-  input: ResolveMarketInput<TAccountMarketAuthority, TAccountMarket>,
+  input: ResolveMarketInput<
+    TAccountMarketAuthority,
+    TAccountMarket,
+    TAccountMarketResult,
+    TAccountSystemProgram,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
+  >,
   config?: { programAddress?: TProgramAddress }
 ): ResolveMarketInstruction<
   TProgramAddress,
   TAccountMarketAuthority,
-  TAccountMarket
+  TAccountMarket,
+  TAccountMarketResult,
+  TAccountSystemProgram,
+  TAccountAuditLog,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -117,29 +293,51 @@ export function getResolveMarketInstruction<
 
   // Original accounts.
   const originalAccounts = {
-    marketAuthority: {
-      value: input.marketAuthority ?? null,
-      isWritable: false,
-    },
+    marketAuthority: { value: input.marketAuthority ?? null, isWritable: true },
     market: { value: input.market ?? null, isWritable: true },
+    marketResult: { value: input.marketResult ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.marketAuthority),
       getAccountMeta(accounts.market),
+      getAccountMeta(accounts.marketResult),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.auditLog),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getResolveMarketInstructionDataEncoder().encode({}),
     programAddress,
   } as ResolveMarketInstruction<
     TProgramAddress,
     TAccountMarketAuthority,
-    TAccountMarket
+    TAccountMarket,
+    TAccountMarketResult,
+    TAccountSystemProgram,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -151,6 +349,12 @@ export type ParsedResolveMarketInstruction<
   accounts: {
     marketAuthority: TAccountMetas[0];
     market: TAccountMetas[1];
+    marketResult: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
+    /** Required when the market keeps an audit log. */
+    auditLog?: TAccountMetas[4] | undefined;
+    eventAuthority: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: ResolveMarketInstructionData;
 };
@@ -163,7 +367,7 @@ export function parseResolveMarketInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedResolveMarketInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -173,9 +377,23 @@ export function parseResolveMarketInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === OPPORTUNITY_MARKET_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
-    accounts: { marketAuthority: getNextAccount(), market: getNextAccount() },
+    accounts: {
+      marketAuthority: getNextAccount(),
+      market: getNextAccount(),
+      marketResult: getNextAccount(),
+      systemProgram: getNextAccount(),
+      auditLog: getNextOptionalAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
+    },
     data: getResolveMarketInstructionDataDecoder().decode(instruction.data),
   };
 }
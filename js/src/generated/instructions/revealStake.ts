@@ -13,6 +13,8 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -24,12 +26,14 @@ import {
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -59,7 +63,10 @@ export type RevealStakeInstruction<
   TAccountSigner extends string | AccountMeta<string> = string,
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountMarket extends string | AccountMeta<string> = string,
+  TAccountPlatformConfig extends string | AccountMeta<string> = string,
   TAccountStakeAccount extends string | AccountMeta<string> = string,
+  TAccountAuditLog extends string | AccountMeta<string> = string,
+  TAccountProgramMetrics extends string | AccountMeta<string> = string,
   TAccountSignPdaAccount extends string | AccountMeta<string> = string,
   TAccountMxeAccount extends string | AccountMeta<string> = string,
   TAccountMempoolAccount extends string | AccountMeta<string> = string,
@@ -88,11 +95,20 @@ export type RevealStakeInstruction<
         ? ReadonlyAccount<TAccountOwner>
         : TAccountOwner,
       TAccountMarket extends string
-        ? ReadonlyAccount<TAccountMarket>
+        ? WritableAccount<TAccountMarket>
         : TAccountMarket,
+      TAccountPlatformConfig extends string
+        ? ReadonlyAccount<TAccountPlatformConfig>
+        : TAccountPlatformConfig,
       TAccountStakeAccount extends string
         ? WritableAccount<TAccountStakeAccount>
         : TAccountStakeAccount,
+      TAccountAuditLog extends string
+        ? WritableAccount<TAccountAuditLog>
+        : TAccountAuditLog,
+      TAccountProgramMetrics extends string
+        ? WritableAccount<TAccountProgramMetrics>
+        : TAccountProgramMetrics,
       TAccountSignPdaAccount extends string
         ? WritableAccount<TAccountSignPdaAccount>
         : TAccountSignPdaAccount,
@@ -134,33 +150,37 @@ export type RevealStakeInstructionData = {
   discriminator: ReadonlyUint8Array;
   computationOffset: bigint;
   stakeAccountId: number;
+  cuPriceMicro: Option<bigint>;
 };
 
 export type RevealStakeInstructionDataArgs = {
   computationOffset: number | bigint;
   stakeAccountId: number;
+  cuPriceMicro: OptionOrNullable<number | bigint>;
 };
 
-export function getRevealStakeInstructionDataEncoder(): FixedSizeEncoder<RevealStakeInstructionDataArgs> {
+export function getRevealStakeInstructionDataEncoder(): Encoder<RevealStakeInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['computationOffset', getU64Encoder()],
       ['stakeAccountId', getU32Encoder()],
+      ['cuPriceMicro', getOptionEncoder(getU64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: REVEAL_STAKE_DISCRIMINATOR })
   );
 }
 
-export function getRevealStakeInstructionDataDecoder(): FixedSizeDecoder<RevealStakeInstructionData> {
+export function getRevealStakeInstructionDataDecoder(): Decoder<RevealStakeInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['computationOffset', getU64Decoder()],
     ['stakeAccountId', getU32Decoder()],
+    ['cuPriceMicro', getOptionDecoder(getU64Decoder())],
   ]);
 }
 
-export function getRevealStakeInstructionDataCodec(): FixedSizeCodec<
+export function getRevealStakeInstructionDataCodec(): Codec<
   RevealStakeInstructionDataArgs,
   RevealStakeInstructionData
 > {
@@ -174,7 +194,10 @@ export type RevealStakeAsyncInput<
   TAccountSigner extends string = string,
   TAccountOwner extends string = string,
   TAccountMarket extends string = string,
+  TAccountPlatformConfig extends string = string,
   TAccountStakeAccount extends string = string,
+  TAccountAuditLog extends string = string,
+  TAccountProgramMetrics extends string = string,
   TAccountSignPdaAccount extends string = string,
   TAccountMxeAccount extends string = string,
   TAccountMempoolAccount extends string = string,
@@ -190,7 +213,11 @@ export type RevealStakeAsyncInput<
   signer: TransactionSigner<TAccountSigner>;
   owner: Address<TAccountOwner>;
   market: Address<TAccountMarket>;
+  platformConfig: Address<TAccountPlatformConfig>;
   stakeAccount?: Address<TAccountStakeAccount>;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address<TAccountAuditLog>;
+  programMetrics?: Address<TAccountProgramMetrics>;
   signPdaAccount?: Address<TAccountSignPdaAccount>;
   mxeAccount: Address<TAccountMxeAccount>;
   mempoolAccount: Address<TAccountMempoolAccount>;
@@ -204,13 +231,17 @@ export type RevealStakeAsyncInput<
   arciumProgram?: Address<TAccountArciumProgram>;
   computationOffset: RevealStakeInstructionDataArgs['computationOffset'];
   stakeAccountId: RevealStakeInstructionDataArgs['stakeAccountId'];
+  cuPriceMicro: RevealStakeInstructionDataArgs['cuPriceMicro'];
 };
 
 export async function getRevealStakeInstructionAsync<
   TAccountSigner extends string,
   TAccountOwner extends string,
   TAccountMarket extends string,
+  TAccountPlatformConfig extends string,
   TAccountStakeAccount extends string,
+  TAccountAuditLog extends string,
+  TAccountProgramMetrics extends string,
   TAccountSignPdaAccount extends string,
   TAccountMxeAccount extends string,
   TAccountMempoolAccount extends string,
@@ -228,7 +259,10 @@ export async function getRevealStakeInstructionAsync<
     TAccountSigner,
     TAccountOwner,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountAuditLog,
+    TAccountProgramMetrics,
     TAccountSignPdaAccount,
     TAccountMxeAccount,
     TAccountMempoolAccount,
@@ -248,7 +282,10 @@ export async function getRevealStakeInstructionAsync<
     TAccountSigner,
     TAccountOwner,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountAuditLog,
+    TAccountProgramMetrics,
     TAccountSignPdaAccount,
     TAccountMxeAccount,
     TAccountMempoolAccount,
@@ -270,8 +307,11 @@ export async function getRevealStakeInstructionAsync<
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: false },
-    market: { value: input.market ?? null, isWritable: false },
+    market: { value: input.market ?? null, isWritable: true },
+    platformConfig: { value: input.platformConfig ?? null, isWritable: false },
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
+    programMetrics: { value: input.programMetrics ?? null, isWritable: true },
     signPdaAccount: { value: input.signPdaAccount ?? null, isWritable: true },
     mxeAccount: { value: input.mxeAccount ?? null, isWritable: false },
     mempoolAccount: { value: input.mempoolAccount ?? null, isWritable: true },
@@ -311,6 +351,19 @@ export async function getRevealStakeInstructionAsync<
       ],
     });
   }
+  if (!accounts.programMetrics.value) {
+    accounts.programMetrics.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 114, 111, 103, 114, 97, 109, 95, 109, 101, 116, 114, 105, 99,
+            115,
+          ])
+        ),
+      ],
+    });
+  }
   if (!accounts.signPdaAccount.value) {
     accounts.signPdaAccount.value = await getProgramDerivedAddress({
       programAddress,
@@ -347,7 +400,10 @@ export async function getRevealStakeInstructionAsync<
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.market),
+      getAccountMeta(accounts.platformConfig),
       getAccountMeta(accounts.stakeAccount),
+      getAccountMeta(accounts.auditLog),
+      getAccountMeta(accounts.programMetrics),
       getAccountMeta(accounts.signPdaAccount),
       getAccountMeta(accounts.mxeAccount),
       getAccountMeta(accounts.mempoolAccount),
@@ -369,7 +425,10 @@ export async function getRevealStakeInstructionAsync<
     TAccountSigner,
     TAccountOwner,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountAuditLog,
+    TAccountProgramMetrics,
     TAccountSignPdaAccount,
     TAccountMxeAccount,
     TAccountMempoolAccount,
@@ -388,7 +447,10 @@ export type RevealStakeInput<
   TAccountSigner extends string = string,
   TAccountOwner extends string = string,
   TAccountMarket extends string = string,
+  TAccountPlatformConfig extends string = string,
   TAccountStakeAccount extends string = string,
+  TAccountAuditLog extends string = string,
+  TAccountProgramMetrics extends string = string,
   TAccountSignPdaAccount extends string = string,
   TAccountMxeAccount extends string = string,
   TAccountMempoolAccount extends string = string,
@@ -404,7 +466,11 @@ export type RevealStakeInput<
   signer: TransactionSigner<TAccountSigner>;
   owner: Address<TAccountOwner>;
   market: Address<TAccountMarket>;
+  platformConfig: Address<TAccountPlatformConfig>;
   stakeAccount: Address<TAccountStakeAccount>;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address<TAccountAuditLog>;
+  programMetrics: Address<TAccountProgramMetrics>;
   signPdaAccount: Address<TAccountSignPdaAccount>;
   mxeAccount: Address<TAccountMxeAccount>;
   mempoolAccount: Address<TAccountMempoolAccount>;
@@ -418,13 +484,17 @@ export type RevealStakeInput<
   arciumProgram?: Address<TAccountArciumProgram>;
   computationOffset: RevealStakeInstructionDataArgs['computationOffset'];
   stakeAccountId: RevealStakeInstructionDataArgs['stakeAccountId'];
+  cuPriceMicro: RevealStakeInstructionDataArgs['cuPriceMicro'];
 };
 
 export function getRevealStakeInstruction<
   TAccountSigner extends string,
   TAccountOwner extends string,
   TAccountMarket extends string,
+  TAccountPlatformConfig extends string,
   TAccountStakeAccount extends string,
+  TAccountAuditLog extends string,
+  TAccountProgramMetrics extends string,
   TAccountSignPdaAccount extends string,
   TAccountMxeAccount extends string,
   TAccountMempoolAccount extends string,
@@ -442,7 +512,10 @@ export function getRevealStakeInstruction<
     TAccountSigner,
     TAccountOwner,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountAuditLog,
+    TAccountProgramMetrics,
     TAccountSignPdaAccount,
     TAccountMxeAccount,
     TAccountMempoolAccount,
@@ -461,7 +534,10 @@ export function getRevealStakeInstruction<
   TAccountSigner,
   TAccountOwner,
   TAccountMarket,
+  TAccountPlatformConfig,
   TAccountStakeAccount,
+  TAccountAuditLog,
+  TAccountProgramMetrics,
   TAccountSignPdaAccount,
   TAccountMxeAccount,
   TAccountMempoolAccount,
@@ -482,8 +558,11 @@ export function getRevealStakeInstruction<
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: false },
-    market: { value: input.market ?? null, isWritable: false },
+    market: { value: input.market ?? null, isWritable: true },
+    platformConfig: { value: input.platformConfig ?? null, isWritable: false },
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
+    programMetrics: { value: input.programMetrics ?? null, isWritable: true },
     signPdaAccount: { value: input.signPdaAccount ?? null, isWritable: true },
     mxeAccount: { value: input.mxeAccount ?? null, isWritable: false },
     mempoolAccount: { value: input.mempoolAccount ?? null, isWritable: true },
@@ -531,7 +610,10 @@ export function getRevealStakeInstruction<
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.market),
+      getAccountMeta(accounts.platformConfig),
       getAccountMeta(accounts.stakeAccount),
+      getAccountMeta(accounts.auditLog),
+      getAccountMeta(accounts.programMetrics),
       getAccountMeta(accounts.signPdaAccount),
       getAccountMeta(accounts.mxeAccount),
       getAccountMeta(accounts.mempoolAccount),
@@ -553,7 +635,10 @@ export function getRevealStakeInstruction<
     TAccountSigner,
     TAccountOwner,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountAuditLog,
+    TAccountProgramMetrics,
     TAccountSignPdaAccount,
     TAccountMxeAccount,
     TAccountMempoolAccount,
@@ -577,18 +662,22 @@ export type ParsedRevealStakeInstruction<
     signer: TAccountMetas[0];
     owner: TAccountMetas[1];
     market: TAccountMetas[2];
-    stakeAccount: TAccountMetas[3];
-    signPdaAccount: TAccountMetas[4];
-    mxeAccount: TAccountMetas[5];
-    mempoolAccount: TAccountMetas[6];
-    executingPool: TAccountMetas[7];
-    computationAccount: TAccountMetas[8];
-    compDefAccount: TAccountMetas[9];
-    clusterAccount: TAccountMetas[10];
-    poolAccount: TAccountMetas[11];
-    clockAccount: TAccountMetas[12];
-    systemProgram: TAccountMetas[13];
-    arciumProgram: TAccountMetas[14];
+    platformConfig: TAccountMetas[3];
+    stakeAccount: TAccountMetas[4];
+    /** Required when the market keeps an audit log. */
+    auditLog?: TAccountMetas[5] | undefined;
+    programMetrics: TAccountMetas[6];
+    signPdaAccount: TAccountMetas[7];
+    mxeAccount: TAccountMetas[8];
+    mempoolAccount: TAccountMetas[9];
+    executingPool: TAccountMetas[10];
+    computationAccount: TAccountMetas[11];
+    compDefAccount: TAccountMetas[12];
+    clusterAccount: TAccountMetas[13];
+    poolAccount: TAccountMetas[14];
+    clockAccount: TAccountMetas[15];
+    systemProgram: TAccountMetas[16];
+    arciumProgram: TAccountMetas[17];
   };
   data: RevealStakeInstructionData;
 };
@@ -601,7 +690,7 @@ export function parseRevealStakeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedRevealStakeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 15) {
+  if (instruction.accounts.length < 18) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -611,13 +700,22 @@ export function parseRevealStakeInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === OPPORTUNITY_MARKET_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       signer: getNextAccount(),
       owner: getNextAccount(),
       market: getNextAccount(),
+      platformConfig: getNextAccount(),
       stakeAccount: getNextAccount(),
+      auditLog: getNextOptionalAccount(),
+      programMetrics: getNextAccount(),
       signPdaAccount: getNextAccount(),
       mxeAccount: getNextAccount(),
       mempoolAccount: getNextAccount(),
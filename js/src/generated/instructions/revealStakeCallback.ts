@@ -16,6 +16,7 @@ import {
   getBytesEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getTupleDecoder,
@@ -67,6 +68,10 @@ export type RevealStakeCallbackInstruction<
   TAccountInstructionsSysvar extends string | AccountMeta<string> =
     'Sysvar1nstructions1111111111111111111111111',
   TAccountStakeAccount extends string | AccountMeta<string> = string,
+  TAccountProgramMetrics extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -93,6 +98,15 @@ export type RevealStakeCallbackInstruction<
       TAccountStakeAccount extends string
         ? WritableAccount<TAccountStakeAccount>
         : TAccountStakeAccount,
+      TAccountProgramMetrics extends string
+        ? WritableAccount<TAccountProgramMetrics>
+        : TAccountProgramMetrics,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -197,6 +211,163 @@ export function getRevealStakeCallbackInstructionDataCodec(): Codec<
   );
 }
 
+export type RevealStakeCallbackAsyncInput<
+  TAccountArciumProgram extends string = string,
+  TAccountCompDefAccount extends string = string,
+  TAccountMxeAccount extends string = string,
+  TAccountComputationAccount extends string = string,
+  TAccountClusterAccount extends string = string,
+  TAccountInstructionsSysvar extends string = string,
+  TAccountStakeAccount extends string = string,
+  TAccountProgramMetrics extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
+> = {
+  arciumProgram?: Address<TAccountArciumProgram>;
+  compDefAccount: Address<TAccountCompDefAccount>;
+  mxeAccount: Address<TAccountMxeAccount>;
+  computationAccount: Address<TAccountComputationAccount>;
+  clusterAccount: Address<TAccountClusterAccount>;
+  instructionsSysvar?: Address<TAccountInstructionsSysvar>;
+  stakeAccount: Address<TAccountStakeAccount>;
+  programMetrics?: Address<TAccountProgramMetrics>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
+  output: RevealStakeCallbackInstructionDataArgs['output'];
+};
+
+export async function getRevealStakeCallbackInstructionAsync<
+  TAccountArciumProgram extends string,
+  TAccountCompDefAccount extends string,
+  TAccountMxeAccount extends string,
+  TAccountComputationAccount extends string,
+  TAccountClusterAccount extends string,
+  TAccountInstructionsSysvar extends string,
+  TAccountStakeAccount extends string,
+  TAccountProgramMetrics extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
+  TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
+>(
+  input: RevealStakeCallbackAsyncInput<
+    TAccountArciumProgram,
+    TAccountCompDefAccount,
+    TAccountMxeAccount,
+    TAccountComputationAccount,
+    TAccountClusterAccount,
+    TAccountInstructionsSysvar,
+    TAccountStakeAccount,
+    TAccountProgramMetrics,
+    TAccountEventAuthority,
+    TAccountProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  RevealStakeCallbackInstruction<
+    TProgramAddress,
+    TAccountArciumProgram,
+    TAccountCompDefAccount,
+    TAccountMxeAccount,
+    TAccountComputationAccount,
+    TAccountClusterAccount,
+    TAccountInstructionsSysvar,
+    TAccountStakeAccount,
+    TAccountProgramMetrics,
+    TAccountEventAuthority,
+    TAccountProgram
+  >
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? OPPORTUNITY_MARKET_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    arciumProgram: { value: input.arciumProgram ?? null, isWritable: false },
+    compDefAccount: { value: input.compDefAccount ?? null, isWritable: false },
+    mxeAccount: { value: input.mxeAccount ?? null, isWritable: false },
+    computationAccount: {
+      value: input.computationAccount ?? null,
+      isWritable: false,
+    },
+    clusterAccount: { value: input.clusterAccount ?? null, isWritable: false },
+    instructionsSysvar: {
+      value: input.instructionsSysvar ?? null,
+      isWritable: false,
+    },
+    stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
+    programMetrics: { value: input.programMetrics ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.arciumProgram.value) {
+    accounts.arciumProgram.value =
+      'Arcj82pX7HxYKLR92qvgZUAd7vGS1k4hQvAFcPATFdEQ' as Address<'Arcj82pX7HxYKLR92qvgZUAd7vGS1k4hQvAFcPATFdEQ'>;
+  }
+  if (!accounts.instructionsSysvar.value) {
+    accounts.instructionsSysvar.value =
+      'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
+  }
+  if (!accounts.programMetrics.value) {
+    accounts.programMetrics.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 114, 111, 103, 114, 97, 109, 95, 109, 101, 116, 114, 105, 99,
+            115,
+          ])
+        ),
+      ],
+    });
+  }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.arciumProgram),
+      getAccountMeta(accounts.compDefAccount),
+      getAccountMeta(accounts.mxeAccount),
+      getAccountMeta(accounts.computationAccount),
+      getAccountMeta(accounts.clusterAccount),
+      getAccountMeta(accounts.instructionsSysvar),
+      getAccountMeta(accounts.stakeAccount),
+      getAccountMeta(accounts.programMetrics),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
+    ],
+    data: getRevealStakeCallbackInstructionDataEncoder().encode(
+      args as RevealStakeCallbackInstructionDataArgs
+    ),
+    programAddress,
+  } as RevealStakeCallbackInstruction<
+    TProgramAddress,
+    TAccountArciumProgram,
+    TAccountCompDefAccount,
+    TAccountMxeAccount,
+    TAccountComputationAccount,
+    TAccountClusterAccount,
+    TAccountInstructionsSysvar,
+    TAccountStakeAccount,
+    TAccountProgramMetrics,
+    TAccountEventAuthority,
+    TAccountProgram
+  >);
+}
+
 export type RevealStakeCallbackInput<
   TAccountArciumProgram extends string = string,
   TAccountCompDefAccount extends string = string,
@@ -205,6 +376,9 @@ export type RevealStakeCallbackInput<
   TAccountClusterAccount extends string = string,
   TAccountInstructionsSysvar extends string = string,
   TAccountStakeAccount extends string = string,
+  TAccountProgramMetrics extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   arciumProgram?: Address<TAccountArciumProgram>;
   compDefAccount: Address<TAccountCompDefAccount>;
@@ -213,6 +387,9 @@ export type RevealStakeCallbackInput<
   clusterAccount: Address<TAccountClusterAccount>;
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
   stakeAccount: Address<TAccountStakeAccount>;
+  programMetrics: Address<TAccountProgramMetrics>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   output: RevealStakeCallbackInstructionDataArgs['output'];
 };
 
@@ -224,6 +401,9 @@ export function getRevealStakeCallbackInstruction<
   TAccountClusterAccount extends string,
   TAccountInstructionsSysvar extends string,
   TAccountStakeAccount extends string,
+  TAccountProgramMetrics extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: RevealStakeCallbackInput<
@@ -233,7 +413,10 @@ export function getRevealStakeCallbackInstruction<
     TAccountComputationAccount,
     TAccountClusterAccount,
     TAccountInstructionsSysvar,
-    TAccountStakeAccount
+    TAccountStakeAccount,
+    TAccountProgramMetrics,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): RevealStakeCallbackInstruction<
@@ -244,7 +427,10 @@ export function getRevealStakeCallbackInstruction<
   TAccountComputationAccount,
   TAccountClusterAccount,
   TAccountInstructionsSysvar,
-  TAccountStakeAccount
+  TAccountStakeAccount,
+  TAccountProgramMetrics,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -265,6 +451,9 @@ export function getRevealStakeCallbackInstruction<
       isWritable: false,
     },
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
+    programMetrics: { value: input.programMetrics ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -283,6 +472,10 @@ export function getRevealStakeCallbackInstruction<
     accounts.instructionsSysvar.value =
       'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -294,6 +487,9 @@ export function getRevealStakeCallbackInstruction<
       getAccountMeta(accounts.clusterAccount),
       getAccountMeta(accounts.instructionsSysvar),
       getAccountMeta(accounts.stakeAccount),
+      getAccountMeta(accounts.programMetrics),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getRevealStakeCallbackInstructionDataEncoder().encode(
       args as RevealStakeCallbackInstructionDataArgs
@@ -307,7 +503,10 @@ export function getRevealStakeCallbackInstruction<
     TAccountComputationAccount,
     TAccountClusterAccount,
     TAccountInstructionsSysvar,
-    TAccountStakeAccount
+    TAccountStakeAccount,
+    TAccountProgramMetrics,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -324,6 +523,9 @@ export type ParsedRevealStakeCallbackInstruction<
     clusterAccount: TAccountMetas[4];
     instructionsSysvar: TAccountMetas[5];
     stakeAccount: TAccountMetas[6];
+    programMetrics: TAccountMetas[7];
+    eventAuthority: TAccountMetas[8];
+    program: TAccountMetas[9];
   };
   data: RevealStakeCallbackInstructionData;
 };
@@ -336,7 +538,7 @@ export function parseRevealStakeCallbackInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedRevealStakeCallbackInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -356,6 +558,9 @@ export function parseRevealStakeCallbackInstruction<
       clusterAccount: getNextAccount(),
       instructionsSysvar: getNextAccount(),
       stakeAccount: getNextAccount(),
+      programMetrics: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getRevealStakeCallbackInstructionDataDecoder().decode(
       instruction.data
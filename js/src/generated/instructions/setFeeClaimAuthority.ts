@@ -48,6 +48,9 @@ export type SetFeeClaimAuthorityInstruction<
   TAccountUpdateAuthority extends string | AccountMeta<string> = string,
   TAccountPlatformConfig extends string | AccountMeta<string> = string,
   TAccountNewFeeClaimAuthority extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -63,6 +66,12 @@ export type SetFeeClaimAuthorityInstruction<
       TAccountNewFeeClaimAuthority extends string
         ? ReadonlyAccount<TAccountNewFeeClaimAuthority>
         : TAccountNewFeeClaimAuthority,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -103,29 +112,39 @@ export type SetFeeClaimAuthorityInput<
   TAccountUpdateAuthority extends string = string,
   TAccountPlatformConfig extends string = string,
   TAccountNewFeeClaimAuthority extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   updateAuthority: TransactionSigner<TAccountUpdateAuthority>;
   platformConfig: Address<TAccountPlatformConfig>;
   newFeeClaimAuthority: Address<TAccountNewFeeClaimAuthority>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
 };
 
 export function getSetFeeClaimAuthorityInstruction<
   TAccountUpdateAuthority extends string,
   TAccountPlatformConfig extends string,
   TAccountNewFeeClaimAuthority extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: SetFeeClaimAuthorityInput<
     TAccountUpdateAuthority,
     TAccountPlatformConfig,
-    TAccountNewFeeClaimAuthority
+    TAccountNewFeeClaimAuthority,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): SetFeeClaimAuthorityInstruction<
   TProgramAddress,
   TAccountUpdateAuthority,
   TAccountPlatformConfig,
-  TAccountNewFeeClaimAuthority
+  TAccountNewFeeClaimAuthority,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -142,18 +161,28 @@ export function getSetFeeClaimAuthorityInstruction<
       value: input.newFeeClaimAuthority ?? null,
       isWritable: false,
     },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Resolve default values.
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.updateAuthority),
       getAccountMeta(accounts.platformConfig),
       getAccountMeta(accounts.newFeeClaimAuthority),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getSetFeeClaimAuthorityInstructionDataEncoder().encode({}),
     programAddress,
@@ -161,7 +190,9 @@ export function getSetFeeClaimAuthorityInstruction<
     TProgramAddress,
     TAccountUpdateAuthority,
     TAccountPlatformConfig,
-    TAccountNewFeeClaimAuthority
+    TAccountNewFeeClaimAuthority,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -174,6 +205,8 @@ export type ParsedSetFeeClaimAuthorityInstruction<
     updateAuthority: TAccountMetas[0];
     platformConfig: TAccountMetas[1];
     newFeeClaimAuthority: TAccountMetas[2];
+    eventAuthority: TAccountMetas[3];
+    program: TAccountMetas[4];
   };
   data: SetFeeClaimAuthorityInstructionData;
 };
@@ -186,7 +219,7 @@ export function parseSetFeeClaimAuthorityInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetFeeClaimAuthorityInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 5) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -202,6 +235,8 @@ export function parseSetFeeClaimAuthorityInstruction<
       updateAuthority: getNextAccount(),
       platformConfig: getNextAccount(),
       newFeeClaimAuthority: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getSetFeeClaimAuthorityInstructionDataDecoder().decode(
       instruction.data
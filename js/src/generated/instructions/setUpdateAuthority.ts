@@ -48,6 +48,9 @@ export type SetUpdateAuthorityInstruction<
   TAccountUpdateAuthority extends string | AccountMeta<string> = string,
   TAccountPlatformConfig extends string | AccountMeta<string> = string,
   TAccountNewAuthority extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -63,6 +66,12 @@ export type SetUpdateAuthorityInstruction<
       TAccountNewAuthority extends string
         ? ReadonlyAccount<TAccountNewAuthority>
         : TAccountNewAuthority,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -100,29 +109,39 @@ export type SetUpdateAuthorityInput<
   TAccountUpdateAuthority extends string = string,
   TAccountPlatformConfig extends string = string,
   TAccountNewAuthority extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   updateAuthority: TransactionSigner<TAccountUpdateAuthority>;
   platformConfig: Address<TAccountPlatformConfig>;
   newAuthority: Address<TAccountNewAuthority>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
 };
 
 export function getSetUpdateAuthorityInstruction<
   TAccountUpdateAuthority extends string,
   TAccountPlatformConfig extends string,
   TAccountNewAuthority extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: SetUpdateAuthorityInput<
     TAccountUpdateAuthority,
     TAccountPlatformConfig,
-    TAccountNewAuthority
+    TAccountNewAuthority,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): SetUpdateAuthorityInstruction<
   TProgramAddress,
   TAccountUpdateAuthority,
   TAccountPlatformConfig,
-  TAccountNewAuthority
+  TAccountNewAuthority,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -136,18 +155,28 @@ export function getSetUpdateAuthorityInstruction<
     },
     platformConfig: { value: input.platformConfig ?? null, isWritable: true },
     newAuthority: { value: input.newAuthority ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Resolve default values.
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.updateAuthority),
       getAccountMeta(accounts.platformConfig),
       getAccountMeta(accounts.newAuthority),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getSetUpdateAuthorityInstructionDataEncoder().encode({}),
     programAddress,
@@ -155,7 +184,9 @@ export function getSetUpdateAuthorityInstruction<
     TProgramAddress,
     TAccountUpdateAuthority,
     TAccountPlatformConfig,
-    TAccountNewAuthority
+    TAccountNewAuthority,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -168,6 +199,8 @@ export type ParsedSetUpdateAuthorityInstruction<
     updateAuthority: TAccountMetas[0];
     platformConfig: TAccountMetas[1];
     newAuthority: TAccountMetas[2];
+    eventAuthority: TAccountMetas[3];
+    program: TAccountMetas[4];
   };
   data: SetUpdateAuthorityInstructionData;
 };
@@ -180,7 +213,7 @@ export function parseSetUpdateAuthorityInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetUpdateAuthorityInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 5) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -196,6 +229,8 @@ export function parseSetUpdateAuthorityInstruction<
       updateAuthority: getNextAccount(),
       platformConfig: getNextAccount(),
       newAuthority: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getSetUpdateAuthorityInstructionDataDecoder().decode(
       instruction.data
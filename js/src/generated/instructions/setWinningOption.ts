@@ -30,6 +30,7 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -58,6 +59,9 @@ export type SetWinningOptionInstruction<
   TAccountMarketAuthority extends string | AccountMeta<string> = string,
   TAccountMarket extends string | AccountMeta<string> = string,
   TAccountOption extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -73,6 +77,12 @@ export type SetWinningOptionInstruction<
       TAccountOption extends string
         ? WritableAccount<TAccountOption>
         : TAccountOption,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -121,10 +131,14 @@ export type SetWinningOptionAsyncInput<
   TAccountMarketAuthority extends string = string,
   TAccountMarket extends string = string,
   TAccountOption extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   marketAuthority: TransactionSigner<TAccountMarketAuthority>;
   market: Address<TAccountMarket>;
   option?: Address<TAccountOption>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   optionId: SetWinningOptionInstructionDataArgs['optionId'];
   rewardBp: SetWinningOptionInstructionDataArgs['rewardBp'];
 };
@@ -133,12 +147,16 @@ export async function getSetWinningOptionInstructionAsync<
   TAccountMarketAuthority extends string,
   TAccountMarket extends string,
   TAccountOption extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: SetWinningOptionAsyncInput<
     TAccountMarketAuthority,
     TAccountMarket,
-    TAccountOption
+    TAccountOption,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -146,7 +164,9 @@ export async function getSetWinningOptionInstructionAsync<
     TProgramAddress,
     TAccountMarketAuthority,
     TAccountMarket,
-    TAccountOption
+    TAccountOption,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -161,6 +181,8 @@ export async function getSetWinningOptionInstructionAsync<
     },
     market: { value: input.market ?? null, isWritable: true },
     option: { value: input.option ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -183,6 +205,10 @@ export async function getSetWinningOptionInstructionAsync<
       ],
     });
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -190,6 +216,8 @@ export async function getSetWinningOptionInstructionAsync<
       getAccountMeta(accounts.marketAuthority),
       getAccountMeta(accounts.market),
       getAccountMeta(accounts.option),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getSetWinningOptionInstructionDataEncoder().encode(
       args as SetWinningOptionInstructionDataArgs
@@ -199,7 +227,9 @@ export async function getSetWinningOptionInstructionAsync<
     TProgramAddress,
     TAccountMarketAuthority,
     TAccountMarket,
-    TAccountOption
+    TAccountOption,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -207,10 +237,14 @@ export type SetWinningOptionInput<
   TAccountMarketAuthority extends string = string,
   TAccountMarket extends string = string,
   TAccountOption extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   marketAuthority: TransactionSigner<TAccountMarketAuthority>;
   market: Address<TAccountMarket>;
   option: Address<TAccountOption>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   optionId: SetWinningOptionInstructionDataArgs['optionId'];
   rewardBp: SetWinningOptionInstructionDataArgs['rewardBp'];
 };
@@ -219,19 +253,25 @@ export function getSetWinningOptionInstruction<
   TAccountMarketAuthority extends string,
   TAccountMarket extends string,
   TAccountOption extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: SetWinningOptionInput<
     TAccountMarketAuthority,
     TAccountMarket,
-    TAccountOption
+    TAccountOption,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): SetWinningOptionInstruction<
   TProgramAddress,
   TAccountMarketAuthority,
   TAccountMarket,
-  TAccountOption
+  TAccountOption,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -245,6 +285,8 @@ export function getSetWinningOptionInstruction<
     },
     market: { value: input.market ?? null, isWritable: true },
     option: { value: input.option ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -254,12 +296,20 @@ export function getSetWinningOptionInstruction<
   // Original args.
   const args = { ...input };
 
+  // Resolve default values.
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.marketAuthority),
       getAccountMeta(accounts.market),
       getAccountMeta(accounts.option),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getSetWinningOptionInstructionDataEncoder().encode(
       args as SetWinningOptionInstructionDataArgs
@@ -269,7 +319,9 @@ export function getSetWinningOptionInstruction<
     TProgramAddress,
     TAccountMarketAuthority,
     TAccountMarket,
-    TAccountOption
+    TAccountOption,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -282,6 +334,8 @@ export type ParsedSetWinningOptionInstruction<
     marketAuthority: TAccountMetas[0];
     market: TAccountMetas[1];
     option: TAccountMetas[2];
+    eventAuthority: TAccountMetas[3];
+    program: TAccountMetas[4];
   };
   data: SetWinningOptionInstructionData;
 };
@@ -294,7 +348,7 @@ export function parseSetWinningOptionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetWinningOptionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 5) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -310,6 +364,8 @@ export function parseSetWinningOptionInstruction<
       marketAuthority: getNextAccount(),
       market: getNextAccount(),
       option: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getSetWinningOptionInstructionDataDecoder().decode(instruction.data),
   };
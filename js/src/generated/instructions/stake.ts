@@ -15,6 +15,8 @@ import {
   getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -30,12 +32,14 @@ import {
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
@@ -46,6 +50,7 @@ import {
 import { OPPORTUNITY_MARKET_PROGRAM_ADDRESS } from '../programs';
 import {
   expectAddress,
+  expectSome,
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
@@ -63,11 +68,21 @@ export type StakeInstruction<
   TAccountSigner extends string | AccountMeta<string> = string,
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountMarket extends string | AccountMeta<string> = string,
+  TAccountPlatformConfig extends string | AccountMeta<string> = string,
   TAccountStakeAccount extends string | AccountMeta<string> = string,
+  TAccountDepositReceipt extends string | AccountMeta<string> = string,
+  TAccountUserPortfolio extends string | AccountMeta<string> = string,
+  TAccountParticipantPass extends string | AccountMeta<string> = string,
+  TAccountGateTokenAccount extends string | AccountMeta<string> = string,
   TAccountTokenMint extends string | AccountMeta<string> = string,
   TAccountSignerTokenAccount extends string | AccountMeta<string> = string,
   TAccountMarketTokenAta extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
+  TAccountAuditLog extends string | AccountMeta<string> = string,
+  TAccountProgramMetrics extends string | AccountMeta<string> = string,
   TAccountSignPdaAccount extends string | AccountMeta<string> = string,
   TAccountMxeAccount extends string | AccountMeta<string> = string,
   TAccountMempoolAccount extends string | AccountMeta<string> = string,
@@ -99,9 +114,24 @@ export type StakeInstruction<
       TAccountMarket extends string
         ? WritableAccount<TAccountMarket>
         : TAccountMarket,
+      TAccountPlatformConfig extends string
+        ? ReadonlyAccount<TAccountPlatformConfig>
+        : TAccountPlatformConfig,
       TAccountStakeAccount extends string
         ? WritableAccount<TAccountStakeAccount>
         : TAccountStakeAccount,
+      TAccountDepositReceipt extends string
+        ? WritableAccount<TAccountDepositReceipt>
+        : TAccountDepositReceipt,
+      TAccountUserPortfolio extends string
+        ? WritableAccount<TAccountUserPortfolio>
+        : TAccountUserPortfolio,
+      TAccountParticipantPass extends string
+        ? ReadonlyAccount<TAccountParticipantPass>
+        : TAccountParticipantPass,
+      TAccountGateTokenAccount extends string
+        ? ReadonlyAccount<TAccountGateTokenAccount>
+        : TAccountGateTokenAccount,
       TAccountTokenMint extends string
         ? ReadonlyAccount<TAccountTokenMint>
         : TAccountTokenMint,
@@ -114,6 +144,18 @@ export type StakeInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
+      TAccountAuditLog extends string
+        ? WritableAccount<TAccountAuditLog>
+        : TAccountAuditLog,
+      TAccountProgramMetrics extends string
+        ? WritableAccount<TAccountProgramMetrics>
+        : TAccountProgramMetrics,
       TAccountSignPdaAccount extends string
         ? WritableAccount<TAccountSignPdaAccount>
         : TAccountSignPdaAccount,
@@ -161,6 +203,9 @@ export type StakeInstructionData = {
   authorizedReaderNonce: bigint;
   userPubkey: Array<number>;
   stateNonce: bigint;
+  clientId: Array<number>;
+  sequence: bigint;
+  cuPriceMicro: Option<bigint>;
 };
 
 export type StakeInstructionDataArgs = {
@@ -172,9 +217,12 @@ export type StakeInstructionDataArgs = {
   authorizedReaderNonce: number | bigint;
   userPubkey: Array<number>;
   stateNonce: number | bigint;
+  clientId: Array<number>;
+  sequence: number | bigint;
+  cuPriceMicro: OptionOrNullable<number | bigint>;
 };
 
-export function getStakeInstructionDataEncoder(): FixedSizeEncoder<StakeInstructionDataArgs> {
+export function getStakeInstructionDataEncoder(): Encoder<StakeInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
@@ -189,12 +237,15 @@ export function getStakeInstructionDataEncoder(): FixedSizeEncoder<StakeInstruct
       ['authorizedReaderNonce', getU128Encoder()],
       ['userPubkey', getArrayEncoder(getU8Encoder(), { size: 32 })],
       ['stateNonce', getU128Encoder()],
+      ['clientId', getArrayEncoder(getU8Encoder(), { size: 32 })],
+      ['sequence', getU64Encoder()],
+      ['cuPriceMicro', getOptionEncoder(getU64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: STAKE_DISCRIMINATOR })
   );
 }
 
-export function getStakeInstructionDataDecoder(): FixedSizeDecoder<StakeInstructionData> {
+export function getStakeInstructionDataDecoder(): Decoder<StakeInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['computationOffset', getU64Decoder()],
@@ -205,10 +256,13 @@ export function getStakeInstructionDataDecoder(): FixedSizeDecoder<StakeInstruct
     ['authorizedReaderNonce', getU128Decoder()],
     ['userPubkey', getArrayDecoder(getU8Decoder(), { size: 32 })],
     ['stateNonce', getU128Decoder()],
+    ['clientId', getArrayDecoder(getU8Decoder(), { size: 32 })],
+    ['sequence', getU64Decoder()],
+    ['cuPriceMicro', getOptionDecoder(getU64Decoder())],
   ]);
 }
 
-export function getStakeInstructionDataCodec(): FixedSizeCodec<
+export function getStakeInstructionDataCodec(): Codec<
   StakeInstructionDataArgs,
   StakeInstructionData
 > {
@@ -222,11 +276,20 @@ export type StakeAsyncInput<
   TAccountSigner extends string = string,
   TAccountPayer extends string = string,
   TAccountMarket extends string = string,
+  TAccountPlatformConfig extends string = string,
   TAccountStakeAccount extends string = string,
+  TAccountDepositReceipt extends string = string,
+  TAccountUserPortfolio extends string = string,
+  TAccountParticipantPass extends string = string,
+  TAccountGateTokenAccount extends string = string,
   TAccountTokenMint extends string = string,
   TAccountSignerTokenAccount extends string = string,
   TAccountMarketTokenAta extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
+  TAccountAuditLog extends string = string,
+  TAccountProgramMetrics extends string = string,
   TAccountSignPdaAccount extends string = string,
   TAccountMxeAccount extends string = string,
   TAccountMempoolAccount extends string = string,
@@ -242,12 +305,26 @@ export type StakeAsyncInput<
   signer: TransactionSigner<TAccountSigner>;
   payer: TransactionSigner<TAccountPayer>;
   market: Address<TAccountMarket>;
+  platformConfig: Address<TAccountPlatformConfig>;
   stakeAccount: Address<TAccountStakeAccount>;
+  /** Optional receipt for integrators reconciling against their own ledger. */
+  depositReceipt?: Address<TAccountDepositReceipt>;
+  /** Optional position summary for the owner's wallet. */
+  userPortfolio?: Address<TAccountUserPortfolio>;
+  /** Required when the market restricts participants. */
+  participantPass?: Address<TAccountParticipantPass>;
+  /** Required when the market is gated by a mint. */
+  gateTokenAccount?: Address<TAccountGateTokenAccount>;
   tokenMint: Address<TAccountTokenMint>;
   /** Funds the stake. */
   signerTokenAccount: Address<TAccountSignerTokenAccount>;
   marketTokenAta?: Address<TAccountMarketTokenAta>;
   tokenProgram: Address<TAccountTokenProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address<TAccountAuditLog>;
+  programMetrics?: Address<TAccountProgramMetrics>;
   signPdaAccount?: Address<TAccountSignPdaAccount>;
   mxeAccount: Address<TAccountMxeAccount>;
   mempoolAccount: Address<TAccountMempoolAccount>;
@@ -267,17 +344,29 @@ export type StakeAsyncInput<
   authorizedReaderNonce: StakeInstructionDataArgs['authorizedReaderNonce'];
   userPubkey: StakeInstructionDataArgs['userPubkey'];
   stateNonce: StakeInstructionDataArgs['stateNonce'];
+  clientId: StakeInstructionDataArgs['clientId'];
+  sequence: StakeInstructionDataArgs['sequence'];
+  cuPriceMicro: StakeInstructionDataArgs['cuPriceMicro'];
 };
 
 export async function getStakeInstructionAsync<
   TAccountSigner extends string,
   TAccountPayer extends string,
   TAccountMarket extends string,
+  TAccountPlatformConfig extends string,
   TAccountStakeAccount extends string,
+  TAccountDepositReceipt extends string,
+  TAccountUserPortfolio extends string,
+  TAccountParticipantPass extends string,
+  TAccountGateTokenAccount extends string,
   TAccountTokenMint extends string,
   TAccountSignerTokenAccount extends string,
   TAccountMarketTokenAta extends string,
   TAccountTokenProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
+  TAccountAuditLog extends string,
+  TAccountProgramMetrics extends string,
   TAccountSignPdaAccount extends string,
   TAccountMxeAccount extends string,
   TAccountMempoolAccount extends string,
@@ -295,11 +384,20 @@ export async function getStakeInstructionAsync<
     TAccountSigner,
     TAccountPayer,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountDepositReceipt,
+    TAccountUserPortfolio,
+    TAccountParticipantPass,
+    TAccountGateTokenAccount,
     TAccountTokenMint,
     TAccountSignerTokenAccount,
     TAccountMarketTokenAta,
     TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram,
+    TAccountAuditLog,
+    TAccountProgramMetrics,
     TAccountSignPdaAccount,
     TAccountMxeAccount,
     TAccountMempoolAccount,
@@ -319,11 +417,20 @@ export async function getStakeInstructionAsync<
     TAccountSigner,
     TAccountPayer,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountDepositReceipt,
+    TAccountUserPortfolio,
+    TAccountParticipantPass,
+    TAccountGateTokenAccount,
     TAccountTokenMint,
     TAccountSignerTokenAccount,
     TAccountMarketTokenAta,
     TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram,
+    TAccountAuditLog,
+    TAccountProgramMetrics,
     TAccountSignPdaAccount,
     TAccountMxeAccount,
     TAccountMempoolAccount,
@@ -346,7 +453,18 @@ export async function getStakeInstructionAsync<
     signer: { value: input.signer ?? null, isWritable: false },
     payer: { value: input.payer ?? null, isWritable: true },
     market: { value: input.market ?? null, isWritable: true },
+    platformConfig: { value: input.platformConfig ?? null, isWritable: false },
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
+    depositReceipt: { value: input.depositReceipt ?? null, isWritable: true },
+    userPortfolio: { value: input.userPortfolio ?? null, isWritable: true },
+    participantPass: {
+      value: input.participantPass ?? null,
+      isWritable: false,
+    },
+    gateTokenAccount: {
+      value: input.gateTokenAccount ?? null,
+      isWritable: false,
+    },
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     signerTokenAccount: {
       value: input.signerTokenAccount ?? null,
@@ -354,6 +472,10 @@ export async function getStakeInstructionAsync<
     },
     marketTokenAta: { value: input.marketTokenAta ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
+    programMetrics: { value: input.programMetrics ?? null, isWritable: true },
     signPdaAccount: { value: input.signPdaAccount ?? null, isWritable: true },
     mxeAccount: { value: input.mxeAccount ?? null, isWritable: false },
     mempoolAccount: { value: input.mempoolAccount ?? null, isWritable: true },
@@ -378,6 +500,51 @@ export async function getStakeInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.depositReceipt.value) {
+    accounts.depositReceipt.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            100, 101, 112, 111, 115, 105, 116, 95, 114, 101, 99, 101, 105, 112,
+            116,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.signer.value)),
+        getArrayEncoder(getU8Encoder(), { size: 32 }).encode(
+          expectSome(args.clientId)
+        ),
+      ],
+    });
+  }
+  if (!accounts.userPortfolio.value) {
+    accounts.userPortfolio.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            117, 115, 101, 114, 95, 112, 111, 114, 116, 102, 111, 108, 105, 111,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.signer.value)),
+      ],
+    });
+  }
+  if (!accounts.participantPass.value) {
+    accounts.participantPass.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 97, 114, 116, 105, 99, 105, 112, 97, 110, 116, 95, 112, 97,
+            115, 115,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.market.value)),
+        getAddressEncoder().encode(expectAddress(accounts.signer.value)),
+      ],
+    });
+  }
   if (!accounts.marketTokenAta.value) {
     accounts.marketTokenAta.value = await getProgramDerivedAddress({
       programAddress:
@@ -389,6 +556,23 @@ export async function getStakeInstructionAsync<
       ],
     });
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+  if (!accounts.programMetrics.value) {
+    accounts.programMetrics.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 114, 111, 103, 114, 97, 109, 95, 109, 101, 116, 114, 105, 99,
+            115,
+          ])
+        ),
+      ],
+    });
+  }
   if (!accounts.signPdaAccount.value) {
     accounts.signPdaAccount.value = await getProgramDerivedAddress({
       programAddress,
@@ -425,11 +609,20 @@ export async function getStakeInstructionAsync<
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.market),
+      getAccountMeta(accounts.platformConfig),
       getAccountMeta(accounts.stakeAccount),
+      getAccountMeta(accounts.depositReceipt),
+      getAccountMeta(accounts.userPortfolio),
+      getAccountMeta(accounts.participantPass),
+      getAccountMeta(accounts.gateTokenAccount),
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.signerTokenAccount),
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.auditLog),
+      getAccountMeta(accounts.programMetrics),
       getAccountMeta(accounts.signPdaAccount),
       getAccountMeta(accounts.mxeAccount),
       getAccountMeta(accounts.mempoolAccount),
@@ -451,11 +644,20 @@ export async function getStakeInstructionAsync<
     TAccountSigner,
     TAccountPayer,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountDepositReceipt,
+    TAccountUserPortfolio,
+    TAccountParticipantPass,
+    TAccountGateTokenAccount,
     TAccountTokenMint,
     TAccountSignerTokenAccount,
     TAccountMarketTokenAta,
     TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram,
+    TAccountAuditLog,
+    TAccountProgramMetrics,
     TAccountSignPdaAccount,
     TAccountMxeAccount,
     TAccountMempoolAccount,
@@ -474,11 +676,20 @@ export type StakeInput<
   TAccountSigner extends string = string,
   TAccountPayer extends string = string,
   TAccountMarket extends string = string,
+  TAccountPlatformConfig extends string = string,
   TAccountStakeAccount extends string = string,
+  TAccountDepositReceipt extends string = string,
+  TAccountUserPortfolio extends string = string,
+  TAccountParticipantPass extends string = string,
+  TAccountGateTokenAccount extends string = string,
   TAccountTokenMint extends string = string,
   TAccountSignerTokenAccount extends string = string,
   TAccountMarketTokenAta extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
+  TAccountAuditLog extends string = string,
+  TAccountProgramMetrics extends string = string,
   TAccountSignPdaAccount extends string = string,
   TAccountMxeAccount extends string = string,
   TAccountMempoolAccount extends string = string,
@@ -494,12 +705,26 @@ export type StakeInput<
   signer: TransactionSigner<TAccountSigner>;
   payer: TransactionSigner<TAccountPayer>;
   market: Address<TAccountMarket>;
+  platformConfig: Address<TAccountPlatformConfig>;
   stakeAccount: Address<TAccountStakeAccount>;
+  /** Optional receipt for integrators reconciling against their own ledger. */
+  depositReceipt?: Address<TAccountDepositReceipt>;
+  /** Optional position summary for the owner's wallet. */
+  userPortfolio?: Address<TAccountUserPortfolio>;
+  /** Required when the market restricts participants. */
+  participantPass?: Address<TAccountParticipantPass>;
+  /** Required when the market is gated by a mint. */
+  gateTokenAccount?: Address<TAccountGateTokenAccount>;
   tokenMint: Address<TAccountTokenMint>;
   /** Funds the stake. */
   signerTokenAccount: Address<TAccountSignerTokenAccount>;
   marketTokenAta: Address<TAccountMarketTokenAta>;
   tokenProgram: Address<TAccountTokenProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address<TAccountAuditLog>;
+  programMetrics: Address<TAccountProgramMetrics>;
   signPdaAccount: Address<TAccountSignPdaAccount>;
   mxeAccount: Address<TAccountMxeAccount>;
   mempoolAccount: Address<TAccountMempoolAccount>;
@@ -519,17 +744,29 @@ export type StakeInput<
   authorizedReaderNonce: StakeInstructionDataArgs['authorizedReaderNonce'];
   userPubkey: StakeInstructionDataArgs['userPubkey'];
   stateNonce: StakeInstructionDataArgs['stateNonce'];
+  clientId: StakeInstructionDataArgs['clientId'];
+  sequence: StakeInstructionDataArgs['sequence'];
+  cuPriceMicro: StakeInstructionDataArgs['cuPriceMicro'];
 };
 
 export function getStakeInstruction<
   TAccountSigner extends string,
   TAccountPayer extends string,
   TAccountMarket extends string,
+  TAccountPlatformConfig extends string,
   TAccountStakeAccount extends string,
+  TAccountDepositReceipt extends string,
+  TAccountUserPortfolio extends string,
+  TAccountParticipantPass extends string,
+  TAccountGateTokenAccount extends string,
   TAccountTokenMint extends string,
   TAccountSignerTokenAccount extends string,
   TAccountMarketTokenAta extends string,
   TAccountTokenProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
+  TAccountAuditLog extends string,
+  TAccountProgramMetrics extends string,
   TAccountSignPdaAccount extends string,
   TAccountMxeAccount extends string,
   TAccountMempoolAccount extends string,
@@ -547,11 +784,20 @@ export function getStakeInstruction<
     TAccountSigner,
     TAccountPayer,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountDepositReceipt,
+    TAccountUserPortfolio,
+    TAccountParticipantPass,
+    TAccountGateTokenAccount,
     TAccountTokenMint,
     TAccountSignerTokenAccount,
     TAccountMarketTokenAta,
     TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram,
+    TAccountAuditLog,
+    TAccountProgramMetrics,
     TAccountSignPdaAccount,
     TAccountMxeAccount,
     TAccountMempoolAccount,
@@ -570,11 +816,20 @@ export function getStakeInstruction<
   TAccountSigner,
   TAccountPayer,
   TAccountMarket,
+  TAccountPlatformConfig,
   TAccountStakeAccount,
+  TAccountDepositReceipt,
+  TAccountUserPortfolio,
+  TAccountParticipantPass,
+  TAccountGateTokenAccount,
   TAccountTokenMint,
   TAccountSignerTokenAccount,
   TAccountMarketTokenAta,
   TAccountTokenProgram,
+  TAccountEventAuthority,
+  TAccountProgram,
+  TAccountAuditLog,
+  TAccountProgramMetrics,
   TAccountSignPdaAccount,
   TAccountMxeAccount,
   TAccountMempoolAccount,
@@ -596,7 +851,18 @@ export function getStakeInstruction<
     signer: { value: input.signer ?? null, isWritable: false },
     payer: { value: input.payer ?? null, isWritable: true },
     market: { value: input.market ?? null, isWritable: true },
+    platformConfig: { value: input.platformConfig ?? null, isWritable: false },
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
+    depositReceipt: { value: input.depositReceipt ?? null, isWritable: true },
+    userPortfolio: { value: input.userPortfolio ?? null, isWritable: true },
+    participantPass: {
+      value: input.participantPass ?? null,
+      isWritable: false,
+    },
+    gateTokenAccount: {
+      value: input.gateTokenAccount ?? null,
+      isWritable: false,
+    },
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     signerTokenAccount: {
       value: input.signerTokenAccount ?? null,
@@ -604,6 +870,10 @@ export function getStakeInstruction<
     },
     marketTokenAta: { value: input.marketTokenAta ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
+    programMetrics: { value: input.programMetrics ?? null, isWritable: true },
     signPdaAccount: { value: input.signPdaAccount ?? null, isWritable: true },
     mxeAccount: { value: input.mxeAccount ?? null, isWritable: false },
     mempoolAccount: { value: input.mempoolAccount ?? null, isWritable: true },
@@ -628,6 +898,10 @@ export function getStakeInstruction<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
   if (!accounts.poolAccount.value) {
     accounts.poolAccount.value =
       'G2sRWJvi3xoyh5k2gY49eG9L8YhAEWQPtNb1zb1GXTtC' as Address<'G2sRWJvi3xoyh5k2gY49eG9L8YhAEWQPtNb1zb1GXTtC'>;
@@ -651,11 +925,20 @@ export function getStakeInstruction<
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.market),
+      getAccountMeta(accounts.platformConfig),
       getAccountMeta(accounts.stakeAccount),
+      getAccountMeta(accounts.depositReceipt),
+      getAccountMeta(accounts.userPortfolio),
+      getAccountMeta(accounts.participantPass),
+      getAccountMeta(accounts.gateTokenAccount),
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.signerTokenAccount),
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
+      getAccountMeta(accounts.auditLog),
+      getAccountMeta(accounts.programMetrics),
       getAccountMeta(accounts.signPdaAccount),
       getAccountMeta(accounts.mxeAccount),
       getAccountMeta(accounts.mempoolAccount),
@@ -677,11 +960,20 @@ export function getStakeInstruction<
     TAccountSigner,
     TAccountPayer,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountDepositReceipt,
+    TAccountUserPortfolio,
+    TAccountParticipantPass,
+    TAccountGateTokenAccount,
     TAccountTokenMint,
     TAccountSignerTokenAccount,
     TAccountMarketTokenAta,
     TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram,
+    TAccountAuditLog,
+    TAccountProgramMetrics,
     TAccountSignPdaAccount,
     TAccountMxeAccount,
     TAccountMempoolAccount,
@@ -705,23 +997,37 @@ export type ParsedStakeInstruction<
     signer: TAccountMetas[0];
     payer: TAccountMetas[1];
     market: TAccountMetas[2];
-    stakeAccount: TAccountMetas[3];
-    tokenMint: TAccountMetas[4];
+    platformConfig: TAccountMetas[3];
+    stakeAccount: TAccountMetas[4];
+    /** Optional receipt for integrators reconciling against their own ledger. */
+    depositReceipt?: TAccountMetas[5] | undefined;
+    /** Optional position summary for the owner's wallet. */
+    userPortfolio?: TAccountMetas[6] | undefined;
+    /** Required when the market restricts participants. */
+    participantPass?: TAccountMetas[7] | undefined;
+    /** Required when the market is gated by a mint. */
+    gateTokenAccount?: TAccountMetas[8] | undefined;
+    tokenMint: TAccountMetas[9];
     /** Funds the stake. */
-    signerTokenAccount: TAccountMetas[5];
-    marketTokenAta: TAccountMetas[6];
-    tokenProgram: TAccountMetas[7];
-    signPdaAccount: TAccountMetas[8];
-    mxeAccount: TAccountMetas[9];
-    mempoolAccount: TAccountMetas[10];
-    executingPool: TAccountMetas[11];
-    computationAccount: TAccountMetas[12];
-    compDefAccount: TAccountMetas[13];
-    clusterAccount: TAccountMetas[14];
-    poolAccount: TAccountMetas[15];
-    clockAccount: TAccountMetas[16];
-    systemProgram: TAccountMetas[17];
-    arciumProgram: TAccountMetas[18];
+    signerTokenAccount: TAccountMetas[10];
+    marketTokenAta: TAccountMetas[11];
+    tokenProgram: TAccountMetas[12];
+    eventAuthority: TAccountMetas[13];
+    program: TAccountMetas[14];
+    /** Required when the market keeps an audit log. */
+    auditLog?: TAccountMetas[15] | undefined;
+    programMetrics: TAccountMetas[16];
+    signPdaAccount: TAccountMetas[17];
+    mxeAccount: TAccountMetas[18];
+    mempoolAccount: TAccountMetas[19];
+    executingPool: TAccountMetas[20];
+    computationAccount: TAccountMetas[21];
+    compDefAccount: TAccountMetas[22];
+    clusterAccount: TAccountMetas[23];
+    poolAccount: TAccountMetas[24];
+    clockAccount: TAccountMetas[25];
+    systemProgram: TAccountMetas[26];
+    arciumProgram: TAccountMetas[27];
   };
   data: StakeInstructionData;
 };
@@ -734,7 +1040,7 @@ export function parseStakeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedStakeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 19) {
+  if (instruction.accounts.length < 28) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -744,17 +1050,32 @@ export function parseStakeInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === OPPORTUNITY_MARKET_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       signer: getNextAccount(),
       payer: getNextAccount(),
       market: getNextAccount(),
+      platformConfig: getNextAccount(),
       stakeAccount: getNextAccount(),
+      depositReceipt: getNextOptionalAccount(),
+      userPortfolio: getNextOptionalAccount(),
+      participantPass: getNextOptionalAccount(),
+      gateTokenAccount: getNextOptionalAccount(),
       tokenMint: getNextAccount(),
       signerTokenAccount: getNextAccount(),
       marketTokenAta: getNextAccount(),
       tokenProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
+      auditLog: getNextOptionalAccount(),
+      programMetrics: getNextAccount(),
       signPdaAccount: getNextAccount(),
       mxeAccount: getNextAccount(),
       mempoolAccount: getNextAccount(),
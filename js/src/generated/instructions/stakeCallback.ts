@@ -16,6 +16,7 @@ import {
   getBytesEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getTupleDecoder,
@@ -68,6 +69,10 @@ export type StakeCallbackInstruction<
     'Sysvar1nstructions1111111111111111111111111',
   TAccountStakeAccount extends string | AccountMeta<string> = string,
   TAccountMarket extends string | AccountMeta<string> = string,
+  TAccountProgramMetrics extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -97,6 +102,15 @@ export type StakeCallbackInstruction<
       TAccountMarket extends string
         ? WritableAccount<TAccountMarket>
         : TAccountMarket,
+      TAccountProgramMetrics extends string
+        ? WritableAccount<TAccountProgramMetrics>
+        : TAccountProgramMetrics,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -195,6 +209,171 @@ export function getStakeCallbackInstructionDataCodec(): Codec<
   );
 }
 
+export type StakeCallbackAsyncInput<
+  TAccountArciumProgram extends string = string,
+  TAccountCompDefAccount extends string = string,
+  TAccountMxeAccount extends string = string,
+  TAccountComputationAccount extends string = string,
+  TAccountClusterAccount extends string = string,
+  TAccountInstructionsSysvar extends string = string,
+  TAccountStakeAccount extends string = string,
+  TAccountMarket extends string = string,
+  TAccountProgramMetrics extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
+> = {
+  arciumProgram?: Address<TAccountArciumProgram>;
+  compDefAccount: Address<TAccountCompDefAccount>;
+  mxeAccount: Address<TAccountMxeAccount>;
+  computationAccount: Address<TAccountComputationAccount>;
+  clusterAccount: Address<TAccountClusterAccount>;
+  instructionsSysvar?: Address<TAccountInstructionsSysvar>;
+  stakeAccount: Address<TAccountStakeAccount>;
+  market: Address<TAccountMarket>;
+  programMetrics?: Address<TAccountProgramMetrics>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
+  output: StakeCallbackInstructionDataArgs['output'];
+};
+
+export async function getStakeCallbackInstructionAsync<
+  TAccountArciumProgram extends string,
+  TAccountCompDefAccount extends string,
+  TAccountMxeAccount extends string,
+  TAccountComputationAccount extends string,
+  TAccountClusterAccount extends string,
+  TAccountInstructionsSysvar extends string,
+  TAccountStakeAccount extends string,
+  TAccountMarket extends string,
+  TAccountProgramMetrics extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
+  TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
+>(
+  input: StakeCallbackAsyncInput<
+    TAccountArciumProgram,
+    TAccountCompDefAccount,
+    TAccountMxeAccount,
+    TAccountComputationAccount,
+    TAccountClusterAccount,
+    TAccountInstructionsSysvar,
+    TAccountStakeAccount,
+    TAccountMarket,
+    TAccountProgramMetrics,
+    TAccountEventAuthority,
+    TAccountProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  StakeCallbackInstruction<
+    TProgramAddress,
+    TAccountArciumProgram,
+    TAccountCompDefAccount,
+    TAccountMxeAccount,
+    TAccountComputationAccount,
+    TAccountClusterAccount,
+    TAccountInstructionsSysvar,
+    TAccountStakeAccount,
+    TAccountMarket,
+    TAccountProgramMetrics,
+    TAccountEventAuthority,
+    TAccountProgram
+  >
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? OPPORTUNITY_MARKET_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    arciumProgram: { value: input.arciumProgram ?? null, isWritable: false },
+    compDefAccount: { value: input.compDefAccount ?? null, isWritable: false },
+    mxeAccount: { value: input.mxeAccount ?? null, isWritable: false },
+    computationAccount: {
+      value: input.computationAccount ?? null,
+      isWritable: false,
+    },
+    clusterAccount: { value: input.clusterAccount ?? null, isWritable: false },
+    instructionsSysvar: {
+      value: input.instructionsSysvar ?? null,
+      isWritable: false,
+    },
+    stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
+    market: { value: input.market ?? null, isWritable: true },
+    programMetrics: { value: input.programMetrics ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.arciumProgram.value) {
+    accounts.arciumProgram.value =
+      'Arcj82pX7HxYKLR92qvgZUAd7vGS1k4hQvAFcPATFdEQ' as Address<'Arcj82pX7HxYKLR92qvgZUAd7vGS1k4hQvAFcPATFdEQ'>;
+  }
+  if (!accounts.instructionsSysvar.value) {
+    accounts.instructionsSysvar.value =
+      'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
+  }
+  if (!accounts.programMetrics.value) {
+    accounts.programMetrics.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 114, 111, 103, 114, 97, 109, 95, 109, 101, 116, 114, 105, 99,
+            115,
+          ])
+        ),
+      ],
+    });
+  }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.arciumProgram),
+      getAccountMeta(accounts.compDefAccount),
+      getAccountMeta(accounts.mxeAccount),
+      getAccountMeta(accounts.computationAccount),
+      getAccountMeta(accounts.clusterAccount),
+      getAccountMeta(accounts.instructionsSysvar),
+      getAccountMeta(accounts.stakeAccount),
+      getAccountMeta(accounts.market),
+      getAccountMeta(accounts.programMetrics),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
+    ],
+    data: getStakeCallbackInstructionDataEncoder().encode(
+      args as StakeCallbackInstructionDataArgs
+    ),
+    programAddress,
+  } as StakeCallbackInstruction<
+    TProgramAddress,
+    TAccountArciumProgram,
+    TAccountCompDefAccount,
+    TAccountMxeAccount,
+    TAccountComputationAccount,
+    TAccountClusterAccount,
+    TAccountInstructionsSysvar,
+    TAccountStakeAccount,
+    TAccountMarket,
+    TAccountProgramMetrics,
+    TAccountEventAuthority,
+    TAccountProgram
+  >);
+}
+
 export type StakeCallbackInput<
   TAccountArciumProgram extends string = string,
   TAccountCompDefAccount extends string = string,
@@ -204,6 +383,9 @@ export type StakeCallbackInput<
   TAccountInstructionsSysvar extends string = string,
   TAccountStakeAccount extends string = string,
   TAccountMarket extends string = string,
+  TAccountProgramMetrics extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   arciumProgram?: Address<TAccountArciumProgram>;
   compDefAccount: Address<TAccountCompDefAccount>;
@@ -213,6 +395,9 @@ export type StakeCallbackInput<
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
   stakeAccount: Address<TAccountStakeAccount>;
   market: Address<TAccountMarket>;
+  programMetrics: Address<TAccountProgramMetrics>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   output: StakeCallbackInstructionDataArgs['output'];
 };
 
@@ -225,6 +410,9 @@ export function getStakeCallbackInstruction<
   TAccountInstructionsSysvar extends string,
   TAccountStakeAccount extends string,
   TAccountMarket extends string,
+  TAccountProgramMetrics extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: StakeCallbackInput<
@@ -235,7 +423,10 @@ export function getStakeCallbackInstruction<
     TAccountClusterAccount,
     TAccountInstructionsSysvar,
     TAccountStakeAccount,
-    TAccountMarket
+    TAccountMarket,
+    TAccountProgramMetrics,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): StakeCallbackInstruction<
@@ -247,7 +438,10 @@ export function getStakeCallbackInstruction<
   TAccountClusterAccount,
   TAccountInstructionsSysvar,
   TAccountStakeAccount,
-  TAccountMarket
+  TAccountMarket,
+  TAccountProgramMetrics,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -269,6 +463,9 @@ export function getStakeCallbackInstruction<
     },
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
     market: { value: input.market ?? null, isWritable: true },
+    programMetrics: { value: input.programMetrics ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -287,6 +484,10 @@ export function getStakeCallbackInstruction<
     accounts.instructionsSysvar.value =
       'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -299,6 +500,9 @@ export function getStakeCallbackInstruction<
       getAccountMeta(accounts.instructionsSysvar),
       getAccountMeta(accounts.stakeAccount),
       getAccountMeta(accounts.market),
+      getAccountMeta(accounts.programMetrics),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getStakeCallbackInstructionDataEncoder().encode(
       args as StakeCallbackInstructionDataArgs
@@ -313,7 +517,10 @@ export function getStakeCallbackInstruction<
     TAccountClusterAccount,
     TAccountInstructionsSysvar,
     TAccountStakeAccount,
-    TAccountMarket
+    TAccountMarket,
+    TAccountProgramMetrics,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -331,6 +538,9 @@ export type ParsedStakeCallbackInstruction<
     instructionsSysvar: TAccountMetas[5];
     stakeAccount: TAccountMetas[6];
     market: TAccountMetas[7];
+    programMetrics: TAccountMetas[8];
+    eventAuthority: TAccountMetas[9];
+    program: TAccountMetas[10];
   };
   data: StakeCallbackInstructionData;
 };
@@ -343,7 +553,7 @@ export function parseStakeCallbackInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedStakeCallbackInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -364,6 +574,9 @@ export function parseStakeCallbackInstruction<
       instructionsSysvar: getNextAccount(),
       stakeAccount: getNextAccount(),
       market: getNextAccount(),
+      programMetrics: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getStakeCallbackInstructionDataDecoder().decode(instruction.data),
   };
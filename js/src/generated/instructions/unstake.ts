@@ -55,13 +55,19 @@ export type UnstakeInstruction<
   TAccountSigner extends string | AccountMeta<string> = string,
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountMarket extends string | AccountMeta<string> = string,
+  TAccountPlatformConfig extends string | AccountMeta<string> = string,
   TAccountStakeAccount extends string | AccountMeta<string> = string,
+  TAccountUserPortfolio extends string | AccountMeta<string> = string,
   TAccountTokenMint extends string | AccountMeta<string> = string,
   TAccountMarketTokenAta extends string | AccountMeta<string> = string,
   TAccountOwnerTokenAccount extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     '11111111111111111111111111111111',
+  TAccountAuditLog extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -75,11 +81,17 @@ export type UnstakeInstruction<
         ? ReadonlyAccount<TAccountOwner>
         : TAccountOwner,
       TAccountMarket extends string
-        ? ReadonlyAccount<TAccountMarket>
+        ? WritableAccount<TAccountMarket>
         : TAccountMarket,
+      TAccountPlatformConfig extends string
+        ? ReadonlyAccount<TAccountPlatformConfig>
+        : TAccountPlatformConfig,
       TAccountStakeAccount extends string
         ? WritableAccount<TAccountStakeAccount>
         : TAccountStakeAccount,
+      TAccountUserPortfolio extends string
+        ? WritableAccount<TAccountUserPortfolio>
+        : TAccountUserPortfolio,
       TAccountTokenMint extends string
         ? ReadonlyAccount<TAccountTokenMint>
         : TAccountTokenMint,
@@ -95,6 +107,15 @@ export type UnstakeInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountAuditLog extends string
+        ? WritableAccount<TAccountAuditLog>
+        : TAccountAuditLog,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -137,23 +158,35 @@ export type UnstakeAsyncInput<
   TAccountSigner extends string = string,
   TAccountOwner extends string = string,
   TAccountMarket extends string = string,
+  TAccountPlatformConfig extends string = string,
   TAccountStakeAccount extends string = string,
+  TAccountUserPortfolio extends string = string,
   TAccountTokenMint extends string = string,
   TAccountMarketTokenAta extends string = string,
   TAccountOwnerTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountAuditLog extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   owner: Address<TAccountOwner>;
   market: Address<TAccountMarket>;
+  platformConfig: Address<TAccountPlatformConfig>;
   stakeAccount?: Address<TAccountStakeAccount>;
+  /** Optional position summary for the owner's wallet. */
+  userPortfolio?: Address<TAccountUserPortfolio>;
   tokenMint: Address<TAccountTokenMint>;
   marketTokenAta?: Address<TAccountMarketTokenAta>;
   /** Owner's token account to receive staked tokens */
   ownerTokenAccount: Address<TAccountOwnerTokenAccount>;
   tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address<TAccountAuditLog>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   stakeAccountId: UnstakeInstructionDataArgs['stakeAccountId'];
 };
 
@@ -161,24 +194,34 @@ export async function getUnstakeInstructionAsync<
   TAccountSigner extends string,
   TAccountOwner extends string,
   TAccountMarket extends string,
+  TAccountPlatformConfig extends string,
   TAccountStakeAccount extends string,
+  TAccountUserPortfolio extends string,
   TAccountTokenMint extends string,
   TAccountMarketTokenAta extends string,
   TAccountOwnerTokenAccount extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountAuditLog extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: UnstakeAsyncInput<
     TAccountSigner,
     TAccountOwner,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountUserPortfolio,
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountOwnerTokenAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -187,12 +230,17 @@ export async function getUnstakeInstructionAsync<
     TAccountSigner,
     TAccountOwner,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountUserPortfolio,
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountOwnerTokenAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -203,8 +251,10 @@ export async function getUnstakeInstructionAsync<
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: false },
-    market: { value: input.market ?? null, isWritable: false },
+    market: { value: input.market ?? null, isWritable: true },
+    platformConfig: { value: input.platformConfig ?? null, isWritable: false },
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
+    userPortfolio: { value: input.userPortfolio ?? null, isWritable: true },
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     marketTokenAta: { value: input.marketTokenAta ?? null, isWritable: true },
     ownerTokenAccount: {
@@ -213,6 +263,9 @@ export async function getUnstakeInstructionAsync<
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -238,6 +291,19 @@ export async function getUnstakeInstructionAsync<
       ],
     });
   }
+  if (!accounts.userPortfolio.value) {
+    accounts.userPortfolio.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            117, 115, 101, 114, 95, 112, 111, 114, 116, 102, 111, 108, 105, 111,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.owner.value)),
+      ],
+    });
+  }
   if (!accounts.marketTokenAta.value) {
     accounts.marketTokenAta.value = await getProgramDerivedAddress({
       programAddress:
@@ -253,6 +319,10 @@ export async function getUnstakeInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -260,12 +330,17 @@ export async function getUnstakeInstructionAsync<
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.market),
+      getAccountMeta(accounts.platformConfig),
       getAccountMeta(accounts.stakeAccount),
+      getAccountMeta(accounts.userPortfolio),
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.ownerTokenAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.auditLog),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getUnstakeInstructionDataEncoder().encode(
       args as UnstakeInstructionDataArgs
@@ -276,12 +351,17 @@ export async function getUnstakeInstructionAsync<
     TAccountSigner,
     TAccountOwner,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountUserPortfolio,
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountOwnerTokenAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -289,23 +369,35 @@ export type UnstakeInput<
   TAccountSigner extends string = string,
   TAccountOwner extends string = string,
   TAccountMarket extends string = string,
+  TAccountPlatformConfig extends string = string,
   TAccountStakeAccount extends string = string,
+  TAccountUserPortfolio extends string = string,
   TAccountTokenMint extends string = string,
   TAccountMarketTokenAta extends string = string,
   TAccountOwnerTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountAuditLog extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   signer: TransactionSigner<TAccountSigner>;
   owner: Address<TAccountOwner>;
   market: Address<TAccountMarket>;
+  platformConfig: Address<TAccountPlatformConfig>;
   stakeAccount: Address<TAccountStakeAccount>;
+  /** Optional position summary for the owner's wallet. */
+  userPortfolio?: Address<TAccountUserPortfolio>;
   tokenMint: Address<TAccountTokenMint>;
   marketTokenAta: Address<TAccountMarketTokenAta>;
   /** Owner's token account to receive staked tokens */
   ownerTokenAccount: Address<TAccountOwnerTokenAccount>;
   tokenProgram: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address<TAccountAuditLog>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   stakeAccountId: UnstakeInstructionDataArgs['stakeAccountId'];
 };
 
@@ -313,24 +405,34 @@ export function getUnstakeInstruction<
   TAccountSigner extends string,
   TAccountOwner extends string,
   TAccountMarket extends string,
+  TAccountPlatformConfig extends string,
   TAccountStakeAccount extends string,
+  TAccountUserPortfolio extends string,
   TAccountTokenMint extends string,
   TAccountMarketTokenAta extends string,
   TAccountOwnerTokenAccount extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountAuditLog extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: UnstakeInput<
     TAccountSigner,
     TAccountOwner,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountUserPortfolio,
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountOwnerTokenAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): UnstakeInstruction<
@@ -338,12 +440,17 @@ export function getUnstakeInstruction<
   TAccountSigner,
   TAccountOwner,
   TAccountMarket,
+  TAccountPlatformConfig,
   TAccountStakeAccount,
+  TAccountUserPortfolio,
   TAccountTokenMint,
   TAccountMarketTokenAta,
   TAccountOwnerTokenAccount,
   TAccountTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountAuditLog,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -353,8 +460,10 @@ export function getUnstakeInstruction<
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: false },
-    market: { value: input.market ?? null, isWritable: false },
+    market: { value: input.market ?? null, isWritable: true },
+    platformConfig: { value: input.platformConfig ?? null, isWritable: false },
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
+    userPortfolio: { value: input.userPortfolio ?? null, isWritable: true },
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     marketTokenAta: { value: input.marketTokenAta ?? null, isWritable: true },
     ownerTokenAccount: {
@@ -363,6 +472,9 @@ export function getUnstakeInstruction<
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -377,6 +489,10 @@ export function getUnstakeInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
@@ -384,12 +500,17 @@ export function getUnstakeInstruction<
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.market),
+      getAccountMeta(accounts.platformConfig),
       getAccountMeta(accounts.stakeAccount),
+      getAccountMeta(accounts.userPortfolio),
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.ownerTokenAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.auditLog),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getUnstakeInstructionDataEncoder().encode(
       args as UnstakeInstructionDataArgs
@@ -400,12 +521,17 @@ export function getUnstakeInstruction<
     TAccountSigner,
     TAccountOwner,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountStakeAccount,
+    TAccountUserPortfolio,
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountOwnerTokenAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountAuditLog,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -418,13 +544,20 @@ export type ParsedUnstakeInstruction<
     signer: TAccountMetas[0];
     owner: TAccountMetas[1];
     market: TAccountMetas[2];
-    stakeAccount: TAccountMetas[3];
-    tokenMint: TAccountMetas[4];
-    marketTokenAta: TAccountMetas[5];
+    platformConfig: TAccountMetas[3];
+    stakeAccount: TAccountMetas[4];
+    /** Optional position summary for the owner's wallet. */
+    userPortfolio?: TAccountMetas[5] | undefined;
+    tokenMint: TAccountMetas[6];
+    marketTokenAta: TAccountMetas[7];
     /** Owner's token account to receive staked tokens */
-    ownerTokenAccount: TAccountMetas[6];
-    tokenProgram: TAccountMetas[7];
-    systemProgram: TAccountMetas[8];
+    ownerTokenAccount: TAccountMetas[8];
+    tokenProgram: TAccountMetas[9];
+    systemProgram: TAccountMetas[10];
+    /** Required when the market keeps an audit log. */
+    auditLog?: TAccountMetas[11] | undefined;
+    eventAuthority: TAccountMetas[12];
+    program: TAccountMetas[13];
   };
   data: UnstakeInstructionData;
 };
@@ -437,7 +570,7 @@ export function parseUnstakeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUnstakeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 14) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -447,18 +580,29 @@ export function parseUnstakeInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === OPPORTUNITY_MARKET_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       signer: getNextAccount(),
       owner: getNextAccount(),
       market: getNextAccount(),
+      platformConfig: getNextAccount(),
       stakeAccount: getNextAccount(),
+      userPortfolio: getNextOptionalAccount(),
       tokenMint: getNextAccount(),
       marketTokenAta: getNextAccount(),
       ownerTokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      auditLog: getNextOptionalAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getUnstakeInstructionDataDecoder().decode(instruction.data),
   };
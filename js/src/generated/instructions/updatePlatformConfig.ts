@@ -14,6 +14,7 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
@@ -30,13 +31,18 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
-  type ReadonlySignerAccount,
+  type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/kit';
 import { OPPORTUNITY_MARKET_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const UPDATE_PLATFORM_CONFIG_DISCRIMINATOR = new Uint8Array([
   195, 60, 76, 129, 146, 45, 67, 143,
@@ -52,18 +58,36 @@ export type UpdatePlatformConfigInstruction<
   TProgram extends string = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
   TAccountUpdateAuthority extends string | AccountMeta<string> = string,
   TAccountPlatformConfig extends string | AccountMeta<string> = string,
+  TAccountPendingConfigChange extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends string | AccountMeta<string> =
+    '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
   InstructionWithAccounts<
     [
       TAccountUpdateAuthority extends string
-        ? ReadonlySignerAccount<TAccountUpdateAuthority> &
+        ? WritableSignerAccount<TAccountUpdateAuthority> &
             AccountSignerMeta<TAccountUpdateAuthority>
         : TAccountUpdateAuthority,
       TAccountPlatformConfig extends string
-        ? WritableAccount<TAccountPlatformConfig>
+        ? ReadonlyAccount<TAccountPlatformConfig>
         : TAccountPlatformConfig,
+      TAccountPendingConfigChange extends string
+        ? WritableAccount<TAccountPendingConfigChange>
+        : TAccountPendingConfigChange,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -77,6 +101,8 @@ export type UpdatePlatformConfigInstructionData = {
   minTimeToStakeSeconds: bigint;
   revealPeriodSeconds: bigint;
   marketResolutionDeadlineSeconds: bigint;
+  defaultCuPriceMicro: bigint;
+  configChangeDelaySeconds: bigint;
 };
 
 export type UpdatePlatformConfigInstructionDataArgs = {
@@ -87,6 +113,8 @@ export type UpdatePlatformConfigInstructionDataArgs = {
   minTimeToStakeSeconds: number | bigint;
   revealPeriodSeconds: number | bigint;
   marketResolutionDeadlineSeconds: number | bigint;
+  defaultCuPriceMicro: number | bigint;
+  configChangeDelaySeconds: number | bigint;
 };
 
 export function getUpdatePlatformConfigInstructionDataEncoder(): FixedSizeEncoder<UpdatePlatformConfigInstructionDataArgs> {
@@ -100,6 +128,8 @@ export function getUpdatePlatformConfigInstructionDataEncoder(): FixedSizeEncode
       ['minTimeToStakeSeconds', getU64Encoder()],
       ['revealPeriodSeconds', getU64Encoder()],
       ['marketResolutionDeadlineSeconds', getU64Encoder()],
+      ['defaultCuPriceMicro', getU64Encoder()],
+      ['configChangeDelaySeconds', getU64Encoder()],
     ]),
     (value) => ({
       ...value,
@@ -118,6 +148,8 @@ export function getUpdatePlatformConfigInstructionDataDecoder(): FixedSizeDecode
     ['minTimeToStakeSeconds', getU64Decoder()],
     ['revealPeriodSeconds', getU64Decoder()],
     ['marketResolutionDeadlineSeconds', getU64Decoder()],
+    ['defaultCuPriceMicro', getU64Decoder()],
+    ['configChangeDelaySeconds', getU64Decoder()],
   ]);
 }
 
@@ -131,12 +163,149 @@ export function getUpdatePlatformConfigInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type UpdatePlatformConfigAsyncInput<
+  TAccountUpdateAuthority extends string = string,
+  TAccountPlatformConfig extends string = string,
+  TAccountPendingConfigChange extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
+> = {
+  updateAuthority: TransactionSigner<TAccountUpdateAuthority>;
+  platformConfig: Address<TAccountPlatformConfig>;
+  pendingConfigChange?: Address<TAccountPendingConfigChange>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
+  platformFeeBp: UpdatePlatformConfigInstructionDataArgs['platformFeeBp'];
+  rewardPoolFeeBp: UpdatePlatformConfigInstructionDataArgs['rewardPoolFeeBp'];
+  creatorFeeBp: UpdatePlatformConfigInstructionDataArgs['creatorFeeBp'];
+  revealAuthority: UpdatePlatformConfigInstructionDataArgs['revealAuthority'];
+  minTimeToStakeSeconds: UpdatePlatformConfigInstructionDataArgs['minTimeToStakeSeconds'];
+  revealPeriodSeconds: UpdatePlatformConfigInstructionDataArgs['revealPeriodSeconds'];
+  marketResolutionDeadlineSeconds: UpdatePlatformConfigInstructionDataArgs['marketResolutionDeadlineSeconds'];
+  defaultCuPriceMicro: UpdatePlatformConfigInstructionDataArgs['defaultCuPriceMicro'];
+  configChangeDelaySeconds: UpdatePlatformConfigInstructionDataArgs['configChangeDelaySeconds'];
+};
+
+export async function getUpdatePlatformConfigInstructionAsync<
+  TAccountUpdateAuthority extends string,
+  TAccountPlatformConfig extends string,
+  TAccountPendingConfigChange extends string,
+  TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
+  TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
+>(
+  input: UpdatePlatformConfigAsyncInput<
+    TAccountUpdateAuthority,
+    TAccountPlatformConfig,
+    TAccountPendingConfigChange,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  UpdatePlatformConfigInstruction<
+    TProgramAddress,
+    TAccountUpdateAuthority,
+    TAccountPlatformConfig,
+    TAccountPendingConfigChange,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
+  >
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? OPPORTUNITY_MARKET_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    updateAuthority: { value: input.updateAuthority ?? null, isWritable: true },
+    platformConfig: { value: input.platformConfig ?? null, isWritable: false },
+    pendingConfigChange: {
+      value: input.pendingConfigChange ?? null,
+      isWritable: true,
+    },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.pendingConfigChange.value) {
+    accounts.pendingConfigChange.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 101, 110, 100, 105, 110, 103, 95, 99, 111, 110, 102, 105, 103,
+            95, 99, 104, 97, 110, 103, 101,
+          ])
+        ),
+        getAddressEncoder().encode(
+          expectAddress(accounts.platformConfig.value)
+        ),
+      ],
+    });
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.updateAuthority),
+      getAccountMeta(accounts.platformConfig),
+      getAccountMeta(accounts.pendingConfigChange),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
+    ],
+    data: getUpdatePlatformConfigInstructionDataEncoder().encode(
+      args as UpdatePlatformConfigInstructionDataArgs
+    ),
+    programAddress,
+  } as UpdatePlatformConfigInstruction<
+    TProgramAddress,
+    TAccountUpdateAuthority,
+    TAccountPlatformConfig,
+    TAccountPendingConfigChange,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
+  >);
+}
+
 export type UpdatePlatformConfigInput<
   TAccountUpdateAuthority extends string = string,
   TAccountPlatformConfig extends string = string,
+  TAccountPendingConfigChange extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   updateAuthority: TransactionSigner<TAccountUpdateAuthority>;
   platformConfig: Address<TAccountPlatformConfig>;
+  pendingConfigChange: Address<TAccountPendingConfigChange>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
   platformFeeBp: UpdatePlatformConfigInstructionDataArgs['platformFeeBp'];
   rewardPoolFeeBp: UpdatePlatformConfigInstructionDataArgs['rewardPoolFeeBp'];
   creatorFeeBp: UpdatePlatformConfigInstructionDataArgs['creatorFeeBp'];
@@ -144,22 +313,36 @@ export type UpdatePlatformConfigInput<
   minTimeToStakeSeconds: UpdatePlatformConfigInstructionDataArgs['minTimeToStakeSeconds'];
   revealPeriodSeconds: UpdatePlatformConfigInstructionDataArgs['revealPeriodSeconds'];
   marketResolutionDeadlineSeconds: UpdatePlatformConfigInstructionDataArgs['marketResolutionDeadlineSeconds'];
+  defaultCuPriceMicro: UpdatePlatformConfigInstructionDataArgs['defaultCuPriceMicro'];
+  configChangeDelaySeconds: UpdatePlatformConfigInstructionDataArgs['configChangeDelaySeconds'];
 };
 
 export function getUpdatePlatformConfigInstruction<
   TAccountUpdateAuthority extends string,
   TAccountPlatformConfig extends string,
+  TAccountPendingConfigChange extends string,
+  TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: UpdatePlatformConfigInput<
     TAccountUpdateAuthority,
-    TAccountPlatformConfig
+    TAccountPlatformConfig,
+    TAccountPendingConfigChange,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): UpdatePlatformConfigInstruction<
   TProgramAddress,
   TAccountUpdateAuthority,
-  TAccountPlatformConfig
+  TAccountPlatformConfig,
+  TAccountPendingConfigChange,
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -167,11 +350,15 @@ export function getUpdatePlatformConfigInstruction<
 
   // Original accounts.
   const originalAccounts = {
-    updateAuthority: {
-      value: input.updateAuthority ?? null,
-      isWritable: false,
+    updateAuthority: { value: input.updateAuthority ?? null, isWritable: true },
+    platformConfig: { value: input.platformConfig ?? null, isWritable: false },
+    pendingConfigChange: {
+      value: input.pendingConfigChange ?? null,
+      isWritable: true,
     },
-    platformConfig: { value: input.platformConfig ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -181,11 +368,25 @@ export function getUpdatePlatformConfigInstruction<
   // Original args.
   const args = { ...input };
 
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.updateAuthority),
       getAccountMeta(accounts.platformConfig),
+      getAccountMeta(accounts.pendingConfigChange),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getUpdatePlatformConfigInstructionDataEncoder().encode(
       args as UpdatePlatformConfigInstructionDataArgs
@@ -194,7 +395,11 @@ export function getUpdatePlatformConfigInstruction<
   } as UpdatePlatformConfigInstruction<
     TProgramAddress,
     TAccountUpdateAuthority,
-    TAccountPlatformConfig
+    TAccountPlatformConfig,
+    TAccountPendingConfigChange,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -206,6 +411,10 @@ export type ParsedUpdatePlatformConfigInstruction<
   accounts: {
     updateAuthority: TAccountMetas[0];
     platformConfig: TAccountMetas[1];
+    pendingConfigChange: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
+    eventAuthority: TAccountMetas[4];
+    program: TAccountMetas[5];
   };
   data: UpdatePlatformConfigInstructionData;
 };
@@ -218,7 +427,7 @@ export function parseUpdatePlatformConfigInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUpdatePlatformConfigInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -233,6 +442,10 @@ export function parseUpdatePlatformConfigInstruction<
     accounts: {
       updateAuthority: getNextAccount(),
       platformConfig: getNextAccount(),
+      pendingConfigChange: getNextAccount(),
+      systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getUpdatePlatformConfigInstructionDataDecoder().decode(
       instruction.data
@@ -53,11 +53,15 @@ export type WithdrawRewardInstruction<
   TProgram extends string = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
   TAccountSponsor extends string | AccountMeta<string> = string,
   TAccountMarket extends string | AccountMeta<string> = string,
+  TAccountPlatformConfig extends string | AccountMeta<string> = string,
   TAccountSponsorAccount extends string | AccountMeta<string> = string,
   TAccountTokenMint extends string | AccountMeta<string> = string,
   TAccountMarketTokenAta extends string | AccountMeta<string> = string,
   TAccountRefundTokenAccount extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> =
+    'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -70,6 +74,9 @@ export type WithdrawRewardInstruction<
       TAccountMarket extends string
         ? WritableAccount<TAccountMarket>
         : TAccountMarket,
+      TAccountPlatformConfig extends string
+        ? ReadonlyAccount<TAccountPlatformConfig>
+        : TAccountPlatformConfig,
       TAccountSponsorAccount extends string
         ? WritableAccount<TAccountSponsorAccount>
         : TAccountSponsorAccount,
@@ -85,6 +92,12 @@ export type WithdrawRewardInstruction<
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -121,40 +134,52 @@ export function getWithdrawRewardInstructionDataCodec(): FixedSizeCodec<
 export type WithdrawRewardAsyncInput<
   TAccountSponsor extends string = string,
   TAccountMarket extends string = string,
+  TAccountPlatformConfig extends string = string,
   TAccountSponsorAccount extends string = string,
   TAccountTokenMint extends string = string,
   TAccountMarketTokenAta extends string = string,
   TAccountRefundTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   sponsor: TransactionSigner<TAccountSponsor>;
   market: Address<TAccountMarket>;
+  platformConfig: Address<TAccountPlatformConfig>;
   sponsorAccount?: Address<TAccountSponsorAccount>;
   tokenMint: Address<TAccountTokenMint>;
   marketTokenAta?: Address<TAccountMarketTokenAta>;
   /** Sponsor's destination for refunded reward tokens */
   refundTokenAccount: Address<TAccountRefundTokenAccount>;
   tokenProgram: Address<TAccountTokenProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
 };
 
 export async function getWithdrawRewardInstructionAsync<
   TAccountSponsor extends string,
   TAccountMarket extends string,
+  TAccountPlatformConfig extends string,
   TAccountSponsorAccount extends string,
   TAccountTokenMint extends string,
   TAccountMarketTokenAta extends string,
   TAccountRefundTokenAccount extends string,
   TAccountTokenProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: WithdrawRewardAsyncInput<
     TAccountSponsor,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountSponsorAccount,
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountRefundTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -162,11 +187,14 @@ export async function getWithdrawRewardInstructionAsync<
     TProgramAddress,
     TAccountSponsor,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountSponsorAccount,
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountRefundTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -177,6 +205,7 @@ export async function getWithdrawRewardInstructionAsync<
   const originalAccounts = {
     sponsor: { value: input.sponsor ?? null, isWritable: true },
     market: { value: input.market ?? null, isWritable: true },
+    platformConfig: { value: input.platformConfig ?? null, isWritable: false },
     sponsorAccount: { value: input.sponsorAccount ?? null, isWritable: true },
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     marketTokenAta: { value: input.marketTokenAta ?? null, isWritable: true },
@@ -185,6 +214,8 @@ export async function getWithdrawRewardInstructionAsync<
       isWritable: true,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -215,17 +246,24 @@ export async function getWithdrawRewardInstructionAsync<
       ],
     });
   }
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.sponsor),
       getAccountMeta(accounts.market),
+      getAccountMeta(accounts.platformConfig),
       getAccountMeta(accounts.sponsorAccount),
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.refundTokenAccount),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getWithdrawRewardInstructionDataEncoder().encode({}),
     programAddress,
@@ -233,62 +271,80 @@ export async function getWithdrawRewardInstructionAsync<
     TProgramAddress,
     TAccountSponsor,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountSponsorAccount,
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountRefundTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
 export type WithdrawRewardInput<
   TAccountSponsor extends string = string,
   TAccountMarket extends string = string,
+  TAccountPlatformConfig extends string = string,
   TAccountSponsorAccount extends string = string,
   TAccountTokenMint extends string = string,
   TAccountMarketTokenAta extends string = string,
   TAccountRefundTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   sponsor: TransactionSigner<TAccountSponsor>;
   market: Address<TAccountMarket>;
+  platformConfig: Address<TAccountPlatformConfig>;
   sponsorAccount: Address<TAccountSponsorAccount>;
   tokenMint: Address<TAccountTokenMint>;
   marketTokenAta: Address<TAccountMarketTokenAta>;
   /** Sponsor's destination for refunded reward tokens */
   refundTokenAccount: Address<TAccountRefundTokenAccount>;
   tokenProgram: Address<TAccountTokenProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
 };
 
 export function getWithdrawRewardInstruction<
   TAccountSponsor extends string,
   TAccountMarket extends string,
+  TAccountPlatformConfig extends string,
   TAccountSponsorAccount extends string,
   TAccountTokenMint extends string,
   TAccountMarketTokenAta extends string,
   TAccountRefundTokenAccount extends string,
   TAccountTokenProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
 >(
   input: WithdrawRewardInput<
     TAccountSponsor,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountSponsorAccount,
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountRefundTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): WithdrawRewardInstruction<
   TProgramAddress,
   TAccountSponsor,
   TAccountMarket,
+  TAccountPlatformConfig,
   TAccountSponsorAccount,
   TAccountTokenMint,
   TAccountMarketTokenAta,
   TAccountRefundTokenAccount,
-  TAccountTokenProgram
+  TAccountTokenProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -298,6 +354,7 @@ export function getWithdrawRewardInstruction<
   const originalAccounts = {
     sponsor: { value: input.sponsor ?? null, isWritable: true },
     market: { value: input.market ?? null, isWritable: true },
+    platformConfig: { value: input.platformConfig ?? null, isWritable: false },
     sponsorAccount: { value: input.sponsorAccount ?? null, isWritable: true },
     tokenMint: { value: input.tokenMint ?? null, isWritable: false },
     marketTokenAta: { value: input.marketTokenAta ?? null, isWritable: true },
@@ -306,22 +363,33 @@ export function getWithdrawRewardInstruction<
       isWritable: true,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Resolve default values.
+  if (!accounts.program.value) {
+    accounts.program.value =
+      'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg' as Address<'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.sponsor),
       getAccountMeta(accounts.market),
+      getAccountMeta(accounts.platformConfig),
       getAccountMeta(accounts.sponsorAccount),
       getAccountMeta(accounts.tokenMint),
       getAccountMeta(accounts.marketTokenAta),
       getAccountMeta(accounts.refundTokenAccount),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getWithdrawRewardInstructionDataEncoder().encode({}),
     programAddress,
@@ -329,11 +397,14 @@ export function getWithdrawRewardInstruction<
     TProgramAddress,
     TAccountSponsor,
     TAccountMarket,
+    TAccountPlatformConfig,
     TAccountSponsorAccount,
     TAccountTokenMint,
     TAccountMarketTokenAta,
     TAccountRefundTokenAccount,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -345,12 +416,15 @@ export type ParsedWithdrawRewardInstruction<
   accounts: {
     sponsor: TAccountMetas[0];
     market: TAccountMetas[1];
-    sponsorAccount: TAccountMetas[2];
-    tokenMint: TAccountMetas[3];
-    marketTokenAta: TAccountMetas[4];
+    platformConfig: TAccountMetas[2];
+    sponsorAccount: TAccountMetas[3];
+    tokenMint: TAccountMetas[4];
+    marketTokenAta: TAccountMetas[5];
     /** Sponsor's destination for refunded reward tokens */
-    refundTokenAccount: TAccountMetas[5];
-    tokenProgram: TAccountMetas[6];
+    refundTokenAccount: TAccountMetas[6];
+    tokenProgram: TAccountMetas[7];
+    eventAuthority: TAccountMetas[8];
+    program: TAccountMetas[9];
   };
   data: WithdrawRewardInstructionData;
 };
@@ -363,7 +437,7 @@ export function parseWithdrawRewardInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedWithdrawRewardInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -378,11 +452,14 @@ export function parseWithdrawRewardInstruction<
     accounts: {
       sponsor: getNextAccount(),
       market: getNextAccount(),
+      platformConfig: getNextAccount(),
       sponsorAccount: getNextAccount(),
       tokenMint: getNextAccount(),
       marketTokenAta: getNextAccount(),
       refundTokenAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getWithdrawRewardInstructionDataDecoder().decode(instruction.data),
   };
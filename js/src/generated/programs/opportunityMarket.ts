@@ -14,32 +14,67 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAcceptUpdateAuthorityInstruction,
   type ParsedAddMarketOptionInstruction,
   type ParsedAddRewardInstruction,
+  type ParsedCancelConfigChangeInstruction,
   type ParsedClaimCreatorFeesInstruction,
   type ParsedClaimFeesInstruction,
+  type ParsedClaimRewardTrancheInstruction,
   type ParsedCloseOptionAccountInstruction,
   type ParsedCloseStakeAccountInstruction,
   type ParsedCloseStuckStakeAccountInstruction,
+  type ParsedCreateCategoryInstruction,
   type ParsedCreateMarketInstruction,
+  type ParsedCreateMarketWithOptionsInstruction,
+  type ParsedEmergencyResolveInstruction,
   type ParsedEndRevealPeriodInstruction,
+  type ParsedExecuteConfigChangeInstruction,
   type ParsedFinalizeRevealStakeInstruction,
+  type ParsedFinalizeRevealStakesInstruction,
+  type ParsedFundCrankBudgetInstruction,
+  type ParsedGetClaimableInstruction,
+  type ParsedGetMarketPhaseInstruction,
   type ParsedInitAllowedMintInstruction,
+  type ParsedInitAuditLogInstruction,
   type ParsedInitPlatformConfigInstruction,
   type ParsedInitStakeAccountInstruction,
+  type ParsedInitUserPortfolioInstruction,
+  type ParsedInitializeCompDefsInstruction,
+  type ParsedIssueParticipantPassInstruction,
+  type ParsedMigrateStakeInstruction,
   type ParsedOpenMarketInstruction,
+  type ParsedRemoveMarketOptionInstruction,
   type ParsedResolveMarketInstruction,
+  type ParsedRevealStakeBatchCallbackInstruction,
+  type ParsedRevealStakeBatchCompDefInstruction,
+  type ParsedRevealStakeBatchInstruction,
   type ParsedRevealStakeCallbackInstruction,
   type ParsedRevealStakeCompDefInstruction,
   type ParsedRevealStakeInstruction,
+  type ParsedRevokeParticipantPassInstruction,
   type ParsedSetFeeClaimAuthorityInstruction,
+  type ParsedSetGateMintInstruction,
+  type ParsedSetLoserRebateInstruction,
+  type ParsedSetMarketCategoryInstruction,
+  type ParsedSetParticipantsRestrictedInstruction,
+  type ParsedSetParticipationThresholdInstruction,
+  type ParsedSetPlatformPausedInstruction,
+  type ParsedSetRewardVestingInstruction,
+  type ParsedSetSuccessorMarketInstruction,
+  type ParsedSetTransferHookProgramsInstruction,
   type ParsedSetUpdateAuthorityInstruction,
   type ParsedSetWinningOptionInstruction,
   type ParsedStakeCallbackInstruction,
   type ParsedStakeCompDefInstruction,
   type ParsedStakeInstruction,
   type ParsedUnstakeInstruction,
+  type ParsedUpdateMarketTimingInstruction,
   type ParsedUpdatePlatformConfigInstruction,
+  type ParsedVerifyStakeDisclosureCallbackInstruction,
+  type ParsedVerifyStakeDisclosureCompDefInstruction,
+  type ParsedVerifyStakeDisclosureInstruction,
+  type ParsedWithdrawCrankBudgetInstruction,
   type ParsedWithdrawRewardInstruction,
 } from '../instructions';
 
@@ -49,11 +84,21 @@ export const OPPORTUNITY_MARKET_PROGRAM_ADDRESS =
 export enum OpportunityMarketAccount {
   AllowedMint,
   ArciumSignerAccount,
+  AuditLog,
+  BootstrapState,
+  Category,
+  CategoryMarket,
+  DepositReceipt,
+  MarketResult,
   OpportunityMarket,
   OpportunityMarketOption,
   OpportunityMarketSponsor,
+  ParticipantPass,
+  PendingConfigChange,
   PlatformConfig,
+  ProgramMetrics,
   StakeAccount,
+  UserPortfolio,
 }
 
 export function identifyOpportunityMarketAccount(
@@ -82,6 +127,72 @@ export function identifyOpportunityMarketAccount(
   ) {
     return OpportunityMarketAccount.ArciumSignerAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([230, 207, 176, 233, 170, 130, 101, 244])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketAccount.AuditLog;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([100, 174, 115, 80, 120, 47, 111, 122])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketAccount.BootstrapState;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([242, 35, 245, 232, 221, 227, 98, 52])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketAccount.Category;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([11, 102, 236, 83, 156, 204, 132, 218])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketAccount.CategoryMarket;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([64, 175, 24, 183, 138, 109, 70, 78])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketAccount.DepositReceipt;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([228, 116, 192, 234, 125, 54, 69, 142])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketAccount.MarketResult;
+  }
   if (
     containsBytes(
       data,
@@ -115,6 +226,28 @@ export function identifyOpportunityMarketAccount(
   ) {
     return OpportunityMarketAccount.OpportunityMarketSponsor;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([139, 103, 188, 23, 170, 130, 53, 244])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketAccount.ParticipantPass;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([184, 206, 249, 115, 181, 5, 94, 185])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketAccount.PendingConfigChange;
+  }
   if (
     containsBytes(
       data,
@@ -126,6 +259,17 @@ export function identifyOpportunityMarketAccount(
   ) {
     return OpportunityMarketAccount.PlatformConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([92, 48, 189, 198, 18, 112, 154, 109])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketAccount.ProgramMetrics;
+  }
   if (
     containsBytes(
       data,
@@ -137,38 +281,84 @@ export function identifyOpportunityMarketAccount(
   ) {
     return OpportunityMarketAccount.StakeAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([221, 161, 193, 108, 114, 23, 232, 99])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketAccount.UserPortfolio;
+  }
   throw new Error(
     'The provided account could not be identified as a opportunityMarket account.'
   );
 }
 
 export enum OpportunityMarketInstruction {
+  AcceptUpdateAuthority,
   AddMarketOption,
   AddReward,
+  CancelConfigChange,
   ClaimCreatorFees,
   ClaimFees,
+  ClaimRewardTranche,
   CloseOptionAccount,
   CloseStakeAccount,
   CloseStuckStakeAccount,
+  CreateCategory,
   CreateMarket,
+  CreateMarketWithOptions,
+  EmergencyResolve,
   EndRevealPeriod,
+  ExecuteConfigChange,
   FinalizeRevealStake,
+  FinalizeRevealStakes,
+  FundCrankBudget,
+  GetClaimable,
+  GetMarketPhase,
   InitAllowedMint,
+  InitAuditLog,
   InitPlatformConfig,
   InitStakeAccount,
+  InitUserPortfolio,
+  InitializeCompDefs,
+  IssueParticipantPass,
+  MigrateStake,
   OpenMarket,
+  RemoveMarketOption,
   ResolveMarket,
   RevealStake,
+  RevealStakeBatch,
+  RevealStakeBatchCallback,
+  RevealStakeBatchCompDef,
   RevealStakeCallback,
   RevealStakeCompDef,
+  RevokeParticipantPass,
   SetFeeClaimAuthority,
+  SetGateMint,
+  SetLoserRebate,
+  SetMarketCategory,
+  SetParticipantsRestricted,
+  SetParticipationThreshold,
+  SetPlatformPaused,
+  SetRewardVesting,
+  SetSuccessorMarket,
+  SetTransferHookPrograms,
   SetUpdateAuthority,
   SetWinningOption,
   Stake,
   StakeCallback,
   StakeCompDef,
   Unstake,
+  UpdateMarketTiming,
   UpdatePlatformConfig,
+  VerifyStakeDisclosure,
+  VerifyStakeDisclosureCallback,
+  VerifyStakeDisclosureCompDef,
+  WithdrawCrankBudget,
   WithdrawReward,
 }
 
@@ -176,6 +366,17 @@ export function identifyOpportunityMarketInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): OpportunityMarketInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([217, 137, 147, 68, 176, 66, 58, 181])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.AcceptUpdateAuthority;
+  }
   if (
     containsBytes(
       data,
@@ -198,6 +399,17 @@ export function identifyOpportunityMarketInstruction(
   ) {
     return OpportunityMarketInstruction.AddReward;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([222, 114, 136, 167, 183, 86, 61, 158])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.CancelConfigChange;
+  }
   if (
     containsBytes(
       data,
@@ -220,6 +432,17 @@ export function identifyOpportunityMarketInstruction(
   ) {
     return OpportunityMarketInstruction.ClaimFees;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([150, 7, 158, 62, 243, 241, 40, 239])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.ClaimRewardTranche;
+  }
   if (
     containsBytes(
       data,
@@ -253,6 +476,17 @@ export function identifyOpportunityMarketInstruction(
   ) {
     return OpportunityMarketInstruction.CloseStuckStakeAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([220, 242, 238, 47, 228, 219, 223, 230])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.CreateCategory;
+  }
   if (
     containsBytes(
       data,
@@ -264,6 +498,28 @@ export function identifyOpportunityMarketInstruction(
   ) {
     return OpportunityMarketInstruction.CreateMarket;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([67, 171, 92, 156, 30, 225, 173, 219])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.CreateMarketWithOptions;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([63, 112, 185, 42, 47, 61, 232, 79])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.EmergencyResolve;
+  }
   if (
     containsBytes(
       data,
@@ -275,6 +531,17 @@ export function identifyOpportunityMarketInstruction(
   ) {
     return OpportunityMarketInstruction.EndRevealPeriod;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([100, 150, 198, 236, 103, 241, 118, 71])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.ExecuteConfigChange;
+  }
   if (
     containsBytes(
       data,
@@ -286,6 +553,50 @@ export function identifyOpportunityMarketInstruction(
   ) {
     return OpportunityMarketInstruction.FinalizeRevealStake;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([149, 132, 98, 253, 226, 2, 48, 27])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.FinalizeRevealStakes;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([14, 220, 60, 224, 188, 178, 249, 236])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.FundCrankBudget;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([111, 52, 219, 251, 78, 77, 231, 124])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.GetClaimable;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([230, 252, 92, 108, 106, 155, 178, 59])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.GetMarketPhase;
+  }
   if (
     containsBytes(
       data,
@@ -297,6 +608,17 @@ export function identifyOpportunityMarketInstruction(
   ) {
     return OpportunityMarketInstruction.InitAllowedMint;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([166, 116, 57, 150, 239, 171, 135, 13])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.InitAuditLog;
+  }
   if (
     containsBytes(
       data,
@@ -319,6 +641,50 @@ export function identifyOpportunityMarketInstruction(
   ) {
     return OpportunityMarketInstruction.InitStakeAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([107, 127, 216, 188, 110, 77, 96, 126])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.InitUserPortfolio;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([119, 146, 69, 112, 161, 81, 107, 201])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.InitializeCompDefs;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([22, 137, 24, 91, 244, 215, 94, 203])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.IssueParticipantPass;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([178, 5, 26, 85, 56, 20, 153, 160])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.MigrateStake;
+  }
   if (
     containsBytes(
       data,
@@ -330,6 +696,17 @@ export function identifyOpportunityMarketInstruction(
   ) {
     return OpportunityMarketInstruction.OpenMarket;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([32, 229, 161, 228, 18, 57, 53, 237])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.RemoveMarketOption;
+  }
   if (
     containsBytes(
       data,
@@ -352,6 +729,39 @@ export function identifyOpportunityMarketInstruction(
   ) {
     return OpportunityMarketInstruction.RevealStake;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([36, 78, 215, 228, 214, 152, 107, 0])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.RevealStakeBatch;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([164, 187, 88, 230, 111, 206, 83, 155])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.RevealStakeBatchCallback;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([164, 238, 92, 220, 96, 156, 221, 119])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.RevealStakeBatchCompDef;
+  }
   if (
     containsBytes(
       data,
@@ -374,6 +784,17 @@ export function identifyOpportunityMarketInstruction(
   ) {
     return OpportunityMarketInstruction.RevealStakeCompDef;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([0, 255, 130, 116, 253, 224, 96, 168])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.RevokeParticipantPass;
+  }
   if (
     containsBytes(
       data,
@@ -385,6 +806,105 @@ export function identifyOpportunityMarketInstruction(
   ) {
     return OpportunityMarketInstruction.SetFeeClaimAuthority;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([127, 39, 248, 229, 20, 204, 166, 10])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.SetGateMint;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([135, 42, 28, 164, 180, 149, 243, 143])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.SetLoserRebate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([206, 50, 183, 46, 224, 125, 117, 155])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.SetMarketCategory;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([157, 221, 28, 210, 4, 106, 163, 119])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.SetParticipantsRestricted;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([30, 239, 87, 64, 197, 14, 240, 208])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.SetParticipationThreshold;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([87, 242, 5, 105, 13, 128, 229, 242])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.SetPlatformPaused;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([119, 25, 60, 187, 31, 252, 159, 26])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.SetRewardVesting;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([124, 196, 185, 236, 204, 32, 17, 52])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.SetSuccessorMarket;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([136, 10, 110, 154, 238, 177, 146, 28])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.SetTransferHookPrograms;
+  }
   if (
     containsBytes(
       data,
@@ -451,6 +971,17 @@ export function identifyOpportunityMarketInstruction(
   ) {
     return OpportunityMarketInstruction.Unstake;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([105, 205, 30, 68, 3, 48, 38, 253])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.UpdateMarketTiming;
+  }
   if (
     containsBytes(
       data,
@@ -462,6 +993,50 @@ export function identifyOpportunityMarketInstruction(
   ) {
     return OpportunityMarketInstruction.UpdatePlatformConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([224, 205, 158, 43, 73, 65, 144, 246])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.VerifyStakeDisclosure;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([129, 108, 247, 211, 81, 152, 188, 220])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.VerifyStakeDisclosureCallback;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([74, 59, 30, 117, 40, 190, 171, 47])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.VerifyStakeDisclosureCompDef;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([138, 21, 96, 18, 127, 133, 42, 220])
+      ),
+      0
+    )
+  ) {
+    return OpportunityMarketInstruction.WithdrawCrankBudget;
+  }
   if (
     containsBytes(
       data,
@@ -481,18 +1056,27 @@ export function identifyOpportunityMarketInstruction(
 export type ParsedOpportunityMarketInstruction<
   TProgram extends string = 'B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg',
 > =
+  | ({
+      instructionType: OpportunityMarketInstruction.AcceptUpdateAuthority;
+    } & ParsedAcceptUpdateAuthorityInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.AddMarketOption;
     } & ParsedAddMarketOptionInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.AddReward;
     } & ParsedAddRewardInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.CancelConfigChange;
+    } & ParsedCancelConfigChangeInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.ClaimCreatorFees;
     } & ParsedClaimCreatorFeesInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.ClaimFees;
     } & ParsedClaimFeesInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.ClaimRewardTranche;
+    } & ParsedClaimRewardTrancheInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.CloseOptionAccount;
     } & ParsedCloseOptionAccountInstruction<TProgram>)
@@ -502,42 +1086,123 @@ export type ParsedOpportunityMarketInstruction<
   | ({
       instructionType: OpportunityMarketInstruction.CloseStuckStakeAccount;
     } & ParsedCloseStuckStakeAccountInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.CreateCategory;
+    } & ParsedCreateCategoryInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.CreateMarket;
     } & ParsedCreateMarketInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.CreateMarketWithOptions;
+    } & ParsedCreateMarketWithOptionsInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.EmergencyResolve;
+    } & ParsedEmergencyResolveInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.EndRevealPeriod;
     } & ParsedEndRevealPeriodInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.ExecuteConfigChange;
+    } & ParsedExecuteConfigChangeInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.FinalizeRevealStake;
     } & ParsedFinalizeRevealStakeInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.FinalizeRevealStakes;
+    } & ParsedFinalizeRevealStakesInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.FundCrankBudget;
+    } & ParsedFundCrankBudgetInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.GetClaimable;
+    } & ParsedGetClaimableInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.GetMarketPhase;
+    } & ParsedGetMarketPhaseInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.InitAllowedMint;
     } & ParsedInitAllowedMintInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.InitAuditLog;
+    } & ParsedInitAuditLogInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.InitPlatformConfig;
     } & ParsedInitPlatformConfigInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.InitStakeAccount;
     } & ParsedInitStakeAccountInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.InitUserPortfolio;
+    } & ParsedInitUserPortfolioInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.InitializeCompDefs;
+    } & ParsedInitializeCompDefsInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.IssueParticipantPass;
+    } & ParsedIssueParticipantPassInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.MigrateStake;
+    } & ParsedMigrateStakeInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.OpenMarket;
     } & ParsedOpenMarketInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.RemoveMarketOption;
+    } & ParsedRemoveMarketOptionInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.ResolveMarket;
     } & ParsedResolveMarketInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.RevealStake;
     } & ParsedRevealStakeInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.RevealStakeBatch;
+    } & ParsedRevealStakeBatchInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.RevealStakeBatchCallback;
+    } & ParsedRevealStakeBatchCallbackInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.RevealStakeBatchCompDef;
+    } & ParsedRevealStakeBatchCompDefInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.RevealStakeCallback;
     } & ParsedRevealStakeCallbackInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.RevealStakeCompDef;
     } & ParsedRevealStakeCompDefInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.RevokeParticipantPass;
+    } & ParsedRevokeParticipantPassInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.SetFeeClaimAuthority;
     } & ParsedSetFeeClaimAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.SetGateMint;
+    } & ParsedSetGateMintInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.SetLoserRebate;
+    } & ParsedSetLoserRebateInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.SetMarketCategory;
+    } & ParsedSetMarketCategoryInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.SetParticipantsRestricted;
+    } & ParsedSetParticipantsRestrictedInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.SetParticipationThreshold;
+    } & ParsedSetParticipationThresholdInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.SetPlatformPaused;
+    } & ParsedSetPlatformPausedInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.SetRewardVesting;
+    } & ParsedSetRewardVestingInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.SetSuccessorMarket;
+    } & ParsedSetSuccessorMarketInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.SetTransferHookPrograms;
+    } & ParsedSetTransferHookProgramsInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.SetUpdateAuthority;
     } & ParsedSetUpdateAuthorityInstruction<TProgram>)
@@ -556,9 +1221,24 @@ export type ParsedOpportunityMarketInstruction<
   | ({
       instructionType: OpportunityMarketInstruction.Unstake;
     } & ParsedUnstakeInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.UpdateMarketTiming;
+    } & ParsedUpdateMarketTimingInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.UpdatePlatformConfig;
     } & ParsedUpdatePlatformConfigInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.VerifyStakeDisclosure;
+    } & ParsedVerifyStakeDisclosureInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.VerifyStakeDisclosureCallback;
+    } & ParsedVerifyStakeDisclosureCallbackInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.VerifyStakeDisclosureCompDef;
+    } & ParsedVerifyStakeDisclosureCompDefInstruction<TProgram>)
+  | ({
+      instructionType: OpportunityMarketInstruction.WithdrawCrankBudget;
+    } & ParsedWithdrawCrankBudgetInstruction<TProgram>)
   | ({
       instructionType: OpportunityMarketInstruction.WithdrawReward;
     } & ParsedWithdrawRewardInstruction<TProgram>);
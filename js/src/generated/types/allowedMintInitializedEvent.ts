@@ -12,47 +12,61 @@ import {
   getAddressEncoder,
   getI64Decoder,
   getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type Option,
+  type OptionOrNullable,
 } from '@solana/kit';
 
 export type AllowedMintInitializedEvent = {
+  schemaVersion: number;
   allowedMint: Address;
   platform: Address;
   mint: Address;
+  transferHookProgram: Option<Address>;
   timestamp: bigint;
 };
 
 export type AllowedMintInitializedEventArgs = {
+  schemaVersion: number;
   allowedMint: Address;
   platform: Address;
   mint: Address;
+  transferHookProgram: OptionOrNullable<Address>;
   timestamp: number | bigint;
 };
 
-export function getAllowedMintInitializedEventEncoder(): FixedSizeEncoder<AllowedMintInitializedEventArgs> {
+export function getAllowedMintInitializedEventEncoder(): Encoder<AllowedMintInitializedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['allowedMint', getAddressEncoder()],
     ['platform', getAddressEncoder()],
     ['mint', getAddressEncoder()],
+    ['transferHookProgram', getOptionEncoder(getAddressEncoder())],
     ['timestamp', getI64Encoder()],
   ]);
 }
 
-export function getAllowedMintInitializedEventDecoder(): FixedSizeDecoder<AllowedMintInitializedEvent> {
+export function getAllowedMintInitializedEventDecoder(): Decoder<AllowedMintInitializedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['allowedMint', getAddressDecoder()],
     ['platform', getAddressDecoder()],
     ['mint', getAddressDecoder()],
+    ['transferHookProgram', getOptionDecoder(getAddressDecoder())],
     ['timestamp', getI64Decoder()],
   ]);
 }
 
-export function getAllowedMintInitializedEventCodec(): FixedSizeCodec<
+export function getAllowedMintInitializedEventCodec(): Codec<
   AllowedMintInitializedEventArgs,
   AllowedMintInitializedEvent
 > {
@@ -16,6 +16,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -23,6 +25,7 @@ import {
 } from '@solana/kit';
 
 export type CreatorFeesClaimedEvent = {
+  schemaVersion: number;
   market: Address;
   creatorFeeClaimer: Address;
   mint: Address;
@@ -32,6 +35,7 @@ export type CreatorFeesClaimedEvent = {
 };
 
 export type CreatorFeesClaimedEventArgs = {
+  schemaVersion: number;
   market: Address;
   creatorFeeClaimer: Address;
   mint: Address;
@@ -42,6 +46,7 @@ export type CreatorFeesClaimedEventArgs = {
 
 export function getCreatorFeesClaimedEventEncoder(): FixedSizeEncoder<CreatorFeesClaimedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['market', getAddressEncoder()],
     ['creatorFeeClaimer', getAddressEncoder()],
     ['mint', getAddressEncoder()],
@@ -53,6 +58,7 @@ export function getCreatorFeesClaimedEventEncoder(): FixedSizeEncoder<CreatorFee
 
 export function getCreatorFeesClaimedEventDecoder(): FixedSizeDecoder<CreatorFeesClaimedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['market', getAddressDecoder()],
     ['creatorFeeClaimer', getAddressDecoder()],
     ['mint', getAddressDecoder()],
@@ -14,6 +14,8 @@ import {
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -21,6 +23,7 @@ import {
 } from '@solana/kit';
 
 export type FeeClaimAuthorityChangedEvent = {
+  schemaVersion: number;
   platformConfig: Address;
   oldValue: Address;
   newValue: Address;
@@ -28,6 +31,7 @@ export type FeeClaimAuthorityChangedEvent = {
 };
 
 export type FeeClaimAuthorityChangedEventArgs = {
+  schemaVersion: number;
   platformConfig: Address;
   oldValue: Address;
   newValue: Address;
@@ -36,6 +40,7 @@ export type FeeClaimAuthorityChangedEventArgs = {
 
 export function getFeeClaimAuthorityChangedEventEncoder(): FixedSizeEncoder<FeeClaimAuthorityChangedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['platformConfig', getAddressEncoder()],
     ['oldValue', getAddressEncoder()],
     ['newValue', getAddressEncoder()],
@@ -45,6 +50,7 @@ export function getFeeClaimAuthorityChangedEventEncoder(): FixedSizeEncoder<FeeC
 
 export function getFeeClaimAuthorityChangedEventDecoder(): FixedSizeDecoder<FeeClaimAuthorityChangedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['platformConfig', getAddressDecoder()],
     ['oldValue', getAddressDecoder()],
     ['newValue', getAddressDecoder()],
@@ -16,6 +16,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -23,6 +25,7 @@ import {
 } from '@solana/kit';
 
 export type FeesClaimedEvent = {
+  schemaVersion: number;
   market: Address;
   platform: Address;
   mint: Address;
@@ -32,6 +35,7 @@ export type FeesClaimedEvent = {
 };
 
 export type FeesClaimedEventArgs = {
+  schemaVersion: number;
   market: Address;
   platform: Address;
   mint: Address;
@@ -42,6 +46,7 @@ export type FeesClaimedEventArgs = {
 
 export function getFeesClaimedEventEncoder(): FixedSizeEncoder<FeesClaimedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['market', getAddressEncoder()],
     ['platform', getAddressEncoder()],
     ['mint', getAddressEncoder()],
@@ -53,6 +58,7 @@ export function getFeesClaimedEventEncoder(): FixedSizeEncoder<FeesClaimedEventA
 
 export function getFeesClaimedEventDecoder(): FixedSizeDecoder<FeesClaimedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['market', getAddressDecoder()],
     ['platform', getAddressDecoder()],
     ['mint', getAddressDecoder()],
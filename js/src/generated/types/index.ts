@@ -8,25 +8,43 @@
 
 export * from './activation';
 export * from './allowedMintInitializedEvent';
+export * from './auditEntry';
+export * from './auditLogInitializedEvent';
 export * from './bN254G2BLSPublicKey';
+export * from './categoryCreatedEvent';
 export * from './circuitSource';
+export * from './claimableEstimate';
 export * from './clockAccount';
 export * from './cluster';
 export * from './collectedFees';
 export * from './computationDefinitionAccount';
 export * from './computationDefinitionMeta';
 export * from './computationSignature';
+export * from './configChangeCancelledEvent';
+export * from './configChangeExecutedEvent';
+export * from './configChangeQueuedEvent';
+export * from './crankBudgetFundedEvent';
+export * from './crankBudgetWithdrawnEvent';
 export * from './creatorFeesClaimedEvent';
+export * from './depositReceiptRecordedEvent';
 export * from './epoch';
 export * from './feeClaimAuthorityChangedEvent';
 export * from './feePool';
 export * from './feeRates';
 export * from './feesClaimedEvent';
+export * from './gateMintSetEvent';
 export * from './localCircuitSource';
+export * from './loserRebateSetEvent';
+export * from './marketCategorySetEvent';
 export * from './marketCreatedEvent';
+export * from './marketEmergencyResolvedEvent';
 export * from './marketOpenedEvent';
 export * from './marketOptionCreatedEvent';
+export * from './marketOptionRemovedEvent';
+export * from './marketPhase';
+export * from './marketPhaseInfo';
 export * from './marketResolvedEvent';
+export * from './marketTimingUpdatedEvent';
 export * from './mXEAccount';
 export * from './mxeStatus';
 export * from './nodeMetadata';
@@ -34,22 +52,41 @@ export * from './nodeRef';
 export * from './offChainCircuitSource';
 export * from './onChainCircuitSource';
 export * from './optionClosedEvent';
+export * from './optionResult';
 export * from './output';
 export * from './parameter';
+export * from './participantPassIssuedEvent';
+export * from './participantPassRevokedEvent';
+export * from './participantsRestrictedSetEvent';
+export * from './participationThresholdSetEvent';
+export * from './platformConfigChange';
+export * from './platformPausedEvent';
+export * from './receiptKind';
 export * from './revealPeriodEndedEvent';
+export * from './revealStakeBatchOutput';
+export * from './revealStakeBatchOutputStruct0';
 export * from './revealStakeFinalizedEvent';
 export * from './revealStakeOutput';
 export * from './rewardAddedEvent';
+export * from './rewardTrancheClaimedEvent';
+export * from './rewardVestingSetEvent';
 export * from './rewardWithdrawnEvent';
 export * from './stakeAccountClosedEvent';
 export * from './stakeAccountInitializedEvent';
 export * from './stakedEvent';
+export * from './stakeDisclosureVerifiedEvent';
+export * from './stakeMigratedEvent';
 export * from './stakeOutput';
 export * from './stakeOutputStruct0';
 export * from './stakeRevealedEvent';
 export * from './stuckStakeClosedEvent';
+export * from './successorMarketSetEvent';
 export * from './timestamp';
+export * from './transferHookProgramsChangedEvent';
 export * from './unstakedEvent';
 export * from './updateAuthorityChangedEvent';
+export * from './updateAuthorityProposedEvent';
 export * from './utilityPubkeys';
+export * from './verifyStakeDisclosureOutput';
+export * from './verifyStakeDisclosureOutputStruct0';
 export * from './winningOptionSetEvent';
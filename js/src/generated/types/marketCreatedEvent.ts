@@ -37,6 +37,7 @@ import {
 } from '.';
 
 export type MarketCreatedEvent = {
+  schemaVersion: number;
   market: Address;
   creator: Address;
   platform: Address;
@@ -56,6 +57,7 @@ export type MarketCreatedEvent = {
 };
 
 export type MarketCreatedEventArgs = {
+  schemaVersion: number;
   market: Address;
   creator: Address;
   platform: Address;
@@ -76,6 +78,7 @@ export type MarketCreatedEventArgs = {
 
 export function getMarketCreatedEventEncoder(): FixedSizeEncoder<MarketCreatedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['market', getAddressEncoder()],
     ['creator', getAddressEncoder()],
     ['platform', getAddressEncoder()],
@@ -97,6 +100,7 @@ export function getMarketCreatedEventEncoder(): FixedSizeEncoder<MarketCreatedEv
 
 export function getMarketCreatedEventDecoder(): FixedSizeDecoder<MarketCreatedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['market', getAddressDecoder()],
     ['creator', getAddressDecoder()],
     ['platform', getAddressDecoder()],
@@ -16,6 +16,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -23,6 +25,7 @@ import {
 } from '@solana/kit';
 
 export type MarketOpenedEvent = {
+  schemaVersion: number;
   market: Address;
   creator: Address;
   stakeEndTimestamp: bigint;
@@ -30,6 +33,7 @@ export type MarketOpenedEvent = {
 };
 
 export type MarketOpenedEventArgs = {
+  schemaVersion: number;
   market: Address;
   creator: Address;
   stakeEndTimestamp: number | bigint;
@@ -38,6 +42,7 @@ export type MarketOpenedEventArgs = {
 
 export function getMarketOpenedEventEncoder(): FixedSizeEncoder<MarketOpenedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['market', getAddressEncoder()],
     ['creator', getAddressEncoder()],
     ['stakeEndTimestamp', getU64Encoder()],
@@ -47,6 +52,7 @@ export function getMarketOpenedEventEncoder(): FixedSizeEncoder<MarketOpenedEven
 
 export function getMarketOpenedEventDecoder(): FixedSizeDecoder<MarketOpenedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['market', getAddressDecoder()],
     ['creator', getAddressDecoder()],
     ['stakeEndTimestamp', getU64Decoder()],
@@ -16,6 +16,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -23,6 +25,7 @@ import {
 } from '@solana/kit';
 
 export type MarketOptionCreatedEvent = {
+  schemaVersion: number;
   option: Address;
   market: Address;
   signer: Address;
@@ -31,6 +34,7 @@ export type MarketOptionCreatedEvent = {
 };
 
 export type MarketOptionCreatedEventArgs = {
+  schemaVersion: number;
   option: Address;
   market: Address;
   signer: Address;
@@ -40,6 +44,7 @@ export type MarketOptionCreatedEventArgs = {
 
 export function getMarketOptionCreatedEventEncoder(): FixedSizeEncoder<MarketOptionCreatedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['option', getAddressEncoder()],
     ['market', getAddressEncoder()],
     ['signer', getAddressEncoder()],
@@ -50,6 +55,7 @@ export function getMarketOptionCreatedEventEncoder(): FixedSizeEncoder<MarketOpt
 
 export function getMarketOptionCreatedEventDecoder(): FixedSizeDecoder<MarketOptionCreatedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['option', getAddressDecoder()],
     ['market', getAddressDecoder()],
     ['signer', getAddressDecoder()],
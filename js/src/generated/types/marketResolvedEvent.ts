@@ -14,6 +14,8 @@ import {
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -21,12 +23,14 @@ import {
 } from '@solana/kit';
 
 export type MarketResolvedEvent = {
+  schemaVersion: number;
   market: Address;
   marketAuthority: Address;
   timestamp: bigint;
 };
 
 export type MarketResolvedEventArgs = {
+  schemaVersion: number;
   market: Address;
   marketAuthority: Address;
   timestamp: number | bigint;
@@ -34,6 +38,7 @@ export type MarketResolvedEventArgs = {
 
 export function getMarketResolvedEventEncoder(): FixedSizeEncoder<MarketResolvedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['market', getAddressEncoder()],
     ['marketAuthority', getAddressEncoder()],
     ['timestamp', getI64Encoder()],
@@ -42,6 +47,7 @@ export function getMarketResolvedEventEncoder(): FixedSizeEncoder<MarketResolved
 
 export function getMarketResolvedEventDecoder(): FixedSizeDecoder<MarketResolvedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['market', getAddressDecoder()],
     ['marketAuthority', getAddressDecoder()],
     ['timestamp', getI64Decoder()],
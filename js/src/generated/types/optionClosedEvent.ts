@@ -16,6 +16,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -23,6 +25,7 @@ import {
 } from '@solana/kit';
 
 export type OptionClosedEvent = {
+  schemaVersion: number;
   option: Address;
   optionId: bigint;
   signer: Address;
@@ -32,6 +35,7 @@ export type OptionClosedEvent = {
 };
 
 export type OptionClosedEventArgs = {
+  schemaVersion: number;
   option: Address;
   optionId: number | bigint;
   signer: Address;
@@ -42,6 +46,7 @@ export type OptionClosedEventArgs = {
 
 export function getOptionClosedEventEncoder(): FixedSizeEncoder<OptionClosedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['option', getAddressEncoder()],
     ['optionId', getU64Encoder()],
     ['signer', getAddressEncoder()],
@@ -53,6 +58,7 @@ export function getOptionClosedEventEncoder(): FixedSizeEncoder<OptionClosedEven
 
 export function getOptionClosedEventDecoder(): FixedSizeDecoder<OptionClosedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['option', getAddressDecoder()],
     ['optionId', getU64Decoder()],
     ['signer', getAddressDecoder()],
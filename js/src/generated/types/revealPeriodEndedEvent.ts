@@ -14,6 +14,8 @@ import {
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -21,12 +23,14 @@ import {
 } from '@solana/kit';
 
 export type RevealPeriodEndedEvent = {
+  schemaVersion: number;
   market: Address;
   signer: Address;
   timestamp: bigint;
 };
 
 export type RevealPeriodEndedEventArgs = {
+  schemaVersion: number;
   market: Address;
   signer: Address;
   timestamp: number | bigint;
@@ -34,6 +38,7 @@ export type RevealPeriodEndedEventArgs = {
 
 export function getRevealPeriodEndedEventEncoder(): FixedSizeEncoder<RevealPeriodEndedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['market', getAddressEncoder()],
     ['signer', getAddressEncoder()],
     ['timestamp', getI64Encoder()],
@@ -42,6 +47,7 @@ export function getRevealPeriodEndedEventEncoder(): FixedSizeEncoder<RevealPerio
 
 export function getRevealPeriodEndedEventDecoder(): FixedSizeDecoder<RevealPeriodEndedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['market', getAddressDecoder()],
     ['signer', getAddressDecoder()],
     ['timestamp', getI64Decoder()],
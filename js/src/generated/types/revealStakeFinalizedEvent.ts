@@ -20,6 +20,8 @@ import {
   getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -27,6 +29,7 @@ import {
 } from '@solana/kit';
 
 export type RevealStakeFinalizedEvent = {
+  schemaVersion: number;
   owner: Address;
   market: Address;
   stakeAccount: Address;
@@ -40,6 +43,7 @@ export type RevealStakeFinalizedEvent = {
 };
 
 export type RevealStakeFinalizedEventArgs = {
+  schemaVersion: number;
   owner: Address;
   market: Address;
   stakeAccount: Address;
@@ -54,6 +58,7 @@ export type RevealStakeFinalizedEventArgs = {
 
 export function getRevealStakeFinalizedEventEncoder(): FixedSizeEncoder<RevealStakeFinalizedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['owner', getAddressEncoder()],
     ['market', getAddressEncoder()],
     ['stakeAccount', getAddressEncoder()],
@@ -69,6 +74,7 @@ export function getRevealStakeFinalizedEventEncoder(): FixedSizeEncoder<RevealSt
 
 export function getRevealStakeFinalizedEventDecoder(): FixedSizeDecoder<RevealStakeFinalizedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['owner', getAddressDecoder()],
     ['market', getAddressDecoder()],
     ['stakeAccount', getAddressDecoder()],
@@ -18,6 +18,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -25,6 +27,7 @@ import {
 } from '@solana/kit';
 
 export type RewardAddedEvent = {
+  schemaVersion: number;
   market: Address;
   sponsor: Address;
   amount: bigint;
@@ -34,6 +37,7 @@ export type RewardAddedEvent = {
 };
 
 export type RewardAddedEventArgs = {
+  schemaVersion: number;
   market: Address;
   sponsor: Address;
   amount: number | bigint;
@@ -44,6 +48,7 @@ export type RewardAddedEventArgs = {
 
 export function getRewardAddedEventEncoder(): FixedSizeEncoder<RewardAddedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['market', getAddressEncoder()],
     ['sponsor', getAddressEncoder()],
     ['amount', getU64Encoder()],
@@ -55,6 +60,7 @@ export function getRewardAddedEventEncoder(): FixedSizeEncoder<RewardAddedEventA
 
 export function getRewardAddedEventDecoder(): FixedSizeDecoder<RewardAddedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['market', getAddressDecoder()],
     ['sponsor', getAddressDecoder()],
     ['amount', getU64Decoder()],
@@ -16,6 +16,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -23,6 +25,7 @@ import {
 } from '@solana/kit';
 
 export type RewardWithdrawnEvent = {
+  schemaVersion: number;
   market: Address;
   sponsor: Address;
   rewardAmount: bigint;
@@ -31,6 +34,7 @@ export type RewardWithdrawnEvent = {
 };
 
 export type RewardWithdrawnEventArgs = {
+  schemaVersion: number;
   market: Address;
   sponsor: Address;
   rewardAmount: number | bigint;
@@ -40,6 +44,7 @@ export type RewardWithdrawnEventArgs = {
 
 export function getRewardWithdrawnEventEncoder(): FixedSizeEncoder<RewardWithdrawnEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['market', getAddressEncoder()],
     ['sponsor', getAddressEncoder()],
     ['rewardAmount', getU64Encoder()],
@@ -50,6 +55,7 @@ export function getRewardWithdrawnEventEncoder(): FixedSizeEncoder<RewardWithdra
 
 export function getRewardWithdrawnEventDecoder(): FixedSizeDecoder<RewardWithdrawnEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['market', getAddressDecoder()],
     ['sponsor', getAddressDecoder()],
     ['rewardAmount', getU64Decoder()],
@@ -18,6 +18,8 @@ import {
   getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -25,6 +27,7 @@ import {
 } from '@solana/kit';
 
 export type StakeAccountClosedEvent = {
+  schemaVersion: number;
   owner: Address;
   market: Address;
   stakeAccount: Address;
@@ -39,6 +42,7 @@ export type StakeAccountClosedEvent = {
 };
 
 export type StakeAccountClosedEventArgs = {
+  schemaVersion: number;
   owner: Address;
   market: Address;
   stakeAccount: Address;
@@ -54,6 +58,7 @@ export type StakeAccountClosedEventArgs = {
 
 export function getStakeAccountClosedEventEncoder(): FixedSizeEncoder<StakeAccountClosedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['owner', getAddressEncoder()],
     ['market', getAddressEncoder()],
     ['stakeAccount', getAddressEncoder()],
@@ -70,6 +75,7 @@ export function getStakeAccountClosedEventEncoder(): FixedSizeEncoder<StakeAccou
 
 export function getStakeAccountClosedEventDecoder(): FixedSizeDecoder<StakeAccountClosedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['owner', getAddressDecoder()],
     ['market', getAddressDecoder()],
     ['stakeAccount', getAddressDecoder()],
@@ -16,6 +16,8 @@ import {
   getStructEncoder,
   getU32Decoder,
   getU32Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -23,6 +25,7 @@ import {
 } from '@solana/kit';
 
 export type StakeAccountInitializedEvent = {
+  schemaVersion: number;
   stakeAccount: Address;
   owner: Address;
   market: Address;
@@ -31,6 +34,7 @@ export type StakeAccountInitializedEvent = {
 };
 
 export type StakeAccountInitializedEventArgs = {
+  schemaVersion: number;
   stakeAccount: Address;
   owner: Address;
   market: Address;
@@ -40,6 +44,7 @@ export type StakeAccountInitializedEventArgs = {
 
 export function getStakeAccountInitializedEventEncoder(): FixedSizeEncoder<StakeAccountInitializedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['stakeAccount', getAddressEncoder()],
     ['owner', getAddressEncoder()],
     ['market', getAddressEncoder()],
@@ -50,6 +55,7 @@ export function getStakeAccountInitializedEventEncoder(): FixedSizeEncoder<Stake
 
 export function getStakeAccountInitializedEventDecoder(): FixedSizeDecoder<StakeAccountInitializedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['stakeAccount', getAddressDecoder()],
     ['owner', getAddressDecoder()],
     ['market', getAddressDecoder()],
@@ -18,6 +18,8 @@ import {
   getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -25,6 +27,7 @@ import {
 } from '@solana/kit';
 
 export type StakeRevealedEvent = {
+  schemaVersion: number;
   user: Address;
   market: Address;
   stakeAccount: Address;
@@ -35,6 +38,7 @@ export type StakeRevealedEvent = {
 };
 
 export type StakeRevealedEventArgs = {
+  schemaVersion: number;
   user: Address;
   market: Address;
   stakeAccount: Address;
@@ -46,6 +50,7 @@ export type StakeRevealedEventArgs = {
 
 export function getStakeRevealedEventEncoder(): FixedSizeEncoder<StakeRevealedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['user', getAddressEncoder()],
     ['market', getAddressEncoder()],
     ['stakeAccount', getAddressEncoder()],
@@ -58,6 +63,7 @@ export function getStakeRevealedEventEncoder(): FixedSizeEncoder<StakeRevealedEv
 
 export function getStakeRevealedEventDecoder(): FixedSizeDecoder<StakeRevealedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['user', getAddressDecoder()],
     ['market', getAddressDecoder()],
     ['stakeAccount', getAddressDecoder()],
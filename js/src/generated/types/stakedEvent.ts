@@ -31,6 +31,7 @@ import {
 } from '@solana/kit';
 
 export type StakedEvent = {
+  schemaVersion: number;
   user: Address;
   market: Address;
   stakeAccount: Address;
@@ -44,6 +45,7 @@ export type StakedEvent = {
 };
 
 export type StakedEventArgs = {
+  schemaVersion: number;
   user: Address;
   market: Address;
   stakeAccount: Address;
@@ -58,6 +60,7 @@ export type StakedEventArgs = {
 
 export function getStakedEventEncoder(): FixedSizeEncoder<StakedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['user', getAddressEncoder()],
     ['market', getAddressEncoder()],
     ['stakeAccount', getAddressEncoder()],
@@ -76,6 +79,7 @@ export function getStakedEventEncoder(): FixedSizeEncoder<StakedEventArgs> {
 
 export function getStakedEventDecoder(): FixedSizeDecoder<StakedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['user', getAddressDecoder()],
     ['market', getAddressDecoder()],
     ['stakeAccount', getAddressDecoder()],
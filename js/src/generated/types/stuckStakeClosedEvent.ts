@@ -18,6 +18,8 @@ import {
   getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -25,6 +27,7 @@ import {
 } from '@solana/kit';
 
 export type StuckStakeClosedEvent = {
+  schemaVersion: number;
   owner: Address;
   market: Address;
   stakeAccount: Address;
@@ -37,6 +40,7 @@ export type StuckStakeClosedEvent = {
 };
 
 export type StuckStakeClosedEventArgs = {
+  schemaVersion: number;
   owner: Address;
   market: Address;
   stakeAccount: Address;
@@ -50,6 +54,7 @@ export type StuckStakeClosedEventArgs = {
 
 export function getStuckStakeClosedEventEncoder(): FixedSizeEncoder<StuckStakeClosedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['owner', getAddressEncoder()],
     ['market', getAddressEncoder()],
     ['stakeAccount', getAddressEncoder()],
@@ -64,6 +69,7 @@ export function getStuckStakeClosedEventEncoder(): FixedSizeEncoder<StuckStakeCl
 
 export function getStuckStakeClosedEventDecoder(): FixedSizeDecoder<StuckStakeClosedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['owner', getAddressDecoder()],
     ['market', getAddressDecoder()],
     ['stakeAccount', getAddressDecoder()],
@@ -18,6 +18,8 @@ import {
   getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -25,6 +27,7 @@ import {
 } from '@solana/kit';
 
 export type UnstakedEvent = {
+  schemaVersion: number;
   owner: Address;
   market: Address;
   stakeAccount: Address;
@@ -34,6 +37,7 @@ export type UnstakedEvent = {
 };
 
 export type UnstakedEventArgs = {
+  schemaVersion: number;
   owner: Address;
   market: Address;
   stakeAccount: Address;
@@ -44,6 +48,7 @@ export type UnstakedEventArgs = {
 
 export function getUnstakedEventEncoder(): FixedSizeEncoder<UnstakedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['owner', getAddressEncoder()],
     ['market', getAddressEncoder()],
     ['stakeAccount', getAddressEncoder()],
@@ -55,6 +60,7 @@ export function getUnstakedEventEncoder(): FixedSizeEncoder<UnstakedEventArgs> {
 
 export function getUnstakedEventDecoder(): FixedSizeDecoder<UnstakedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['owner', getAddressDecoder()],
     ['market', getAddressDecoder()],
     ['stakeAccount', getAddressDecoder()],
@@ -14,6 +14,8 @@ import {
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -21,6 +23,7 @@ import {
 } from '@solana/kit';
 
 export type UpdateAuthorityChangedEvent = {
+  schemaVersion: number;
   platformConfig: Address;
   oldValue: Address;
   newValue: Address;
@@ -28,6 +31,7 @@ export type UpdateAuthorityChangedEvent = {
 };
 
 export type UpdateAuthorityChangedEventArgs = {
+  schemaVersion: number;
   platformConfig: Address;
   oldValue: Address;
   newValue: Address;
@@ -36,6 +40,7 @@ export type UpdateAuthorityChangedEventArgs = {
 
 export function getUpdateAuthorityChangedEventEncoder(): FixedSizeEncoder<UpdateAuthorityChangedEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['platformConfig', getAddressEncoder()],
     ['oldValue', getAddressEncoder()],
     ['newValue', getAddressEncoder()],
@@ -45,6 +50,7 @@ export function getUpdateAuthorityChangedEventEncoder(): FixedSizeEncoder<Update
 
 export function getUpdateAuthorityChangedEventDecoder(): FixedSizeDecoder<UpdateAuthorityChangedEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['platformConfig', getAddressDecoder()],
     ['oldValue', getAddressDecoder()],
     ['newValue', getAddressDecoder()],
@@ -18,6 +18,8 @@ import {
   getU16Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -25,6 +27,7 @@ import {
 } from '@solana/kit';
 
 export type WinningOptionSetEvent = {
+  schemaVersion: number;
   market: Address;
   marketAuthority: Address;
   option: Address;
@@ -35,6 +38,7 @@ export type WinningOptionSetEvent = {
 };
 
 export type WinningOptionSetEventArgs = {
+  schemaVersion: number;
   market: Address;
   marketAuthority: Address;
   option: Address;
@@ -46,6 +50,7 @@ export type WinningOptionSetEventArgs = {
 
 export function getWinningOptionSetEventEncoder(): FixedSizeEncoder<WinningOptionSetEventArgs> {
   return getStructEncoder([
+    ['schemaVersion', getU8Encoder()],
     ['market', getAddressEncoder()],
     ['marketAuthority', getAddressEncoder()],
     ['option', getAddressEncoder()],
@@ -58,6 +63,7 @@ export function getWinningOptionSetEventEncoder(): FixedSizeEncoder<WinningOptio
 
 export function getWinningOptionSetEventDecoder(): FixedSizeDecoder<WinningOptionSetEvent> {
   return getStructDecoder([
+    ['schemaVersion', getU8Decoder()],
     ['market', getAddressDecoder()],
     ['marketAuthority', getAddressDecoder()],
     ['option', getAddressDecoder()],
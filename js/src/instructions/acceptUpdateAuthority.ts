@@ -0,0 +1,24 @@
+import { type TransactionSigner, type Address } from "@solana/kit";
+import {
+  getAcceptUpdateAuthorityInstruction,
+  type AcceptUpdateAuthorityInstruction,
+} from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
+import { type BaseInstructionParams } from "./instructionParams";
+
+export interface AcceptUpdateAuthorityParams extends BaseInstructionParams {
+  /** Must be the platform's pending update authority. */
+  newAuthority: TransactionSigner;
+  platformConfig: Address;
+}
+
+export async function acceptUpdateAuthority(
+  input: AcceptUpdateAuthorityParams,
+): Promise<AcceptUpdateAuthorityInstruction<string>> {
+  const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
+  return getAcceptUpdateAuthorityInstruction(
+    { ...params, eventAuthority },
+    programAddress ? { programAddress } : undefined,
+  );
+}
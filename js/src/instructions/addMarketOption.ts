@@ -3,6 +3,7 @@ import {
   getAddMarketOptionInstructionAsync,
   type AddMarketOptionInstruction,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { type BaseInstructionParams } from "./instructionParams";
 
 export interface AddMarketOptionParams extends BaseInstructionParams {
@@ -15,8 +16,9 @@ export async function addMarketOption(
   input: AddMarketOptionParams
 ): Promise<AddMarketOptionInstruction<string>> {
   const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
   return getAddMarketOptionInstructionAsync(
-    params,
+    { ...params, eventAuthority },
     programAddress ? { programAddress } : undefined
   );
 }
@@ -3,11 +3,13 @@ import {
   getAddRewardInstructionAsync,
   type AddRewardInstruction,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { type BaseInstructionParams } from "./instructionParams";
 
 export interface AddRewardParams extends BaseInstructionParams {
   sponsor: TransactionSigner;
   market: Address;
+  platformConfig: Address;
   tokenMint: Address;
   sponsorTokenAccount: Address;
   tokenProgram: Address;
@@ -19,8 +21,9 @@ export async function addReward(
   input: AddRewardParams
 ): Promise<AddRewardInstruction<string>> {
   const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
   return getAddRewardInstructionAsync(
-    params,
+    { ...params, eventAuthority },
     programAddress ? { programAddress } : undefined
   );
 }
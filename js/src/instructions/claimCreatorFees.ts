@@ -3,6 +3,7 @@ import {
   getClaimCreatorFeesInstructionAsync,
   type ClaimCreatorFeesInstruction,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { type BaseInstructionParams } from "./instructionParams";
 
 export interface ClaimCreatorFeesParams extends BaseInstructionParams {
@@ -17,8 +18,9 @@ export async function claimCreatorFees(
   input: ClaimCreatorFeesParams
 ): Promise<ClaimCreatorFeesInstruction<string>> {
   const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
   return getClaimCreatorFeesInstructionAsync(
-    params,
+    { ...params, eventAuthority },
     programAddress ? { programAddress } : undefined
   );
 }
@@ -3,6 +3,7 @@ import {
   getClaimFeesInstructionAsync,
   type ClaimFeesInstruction,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { type BaseInstructionParams } from "./instructionParams";
 
 export interface ClaimFeesParams extends BaseInstructionParams {
@@ -18,8 +19,9 @@ export async function claimFees(
   input: ClaimFeesParams
 ): Promise<ClaimFeesInstruction<string>> {
   const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
   return getClaimFeesInstructionAsync(
-    params,
+    { ...params, eventAuthority },
     programAddress ? { programAddress } : undefined
   );
 }
@@ -3,6 +3,7 @@ import {
   getCloseOptionAccountInstructionAsync,
   type CloseOptionAccountInstruction,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { type BaseInstructionParams } from "./instructionParams";
 
 export interface CloseOptionAccountParams extends BaseInstructionParams {
@@ -16,8 +17,9 @@ export async function closeOptionAccount(
   input: CloseOptionAccountParams
 ): Promise<CloseOptionAccountInstruction<string>> {
   const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
   return getCloseOptionAccountInstructionAsync(
-    params,
+    { ...params, eventAuthority },
     programAddress ? { programAddress } : undefined
   );
 }
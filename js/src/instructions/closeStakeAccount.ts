@@ -3,24 +3,47 @@ import {
   getCloseStakeAccountInstructionAsync,
   type CloseStakeAccountInstruction,
 } from "../generated";
-import { type BaseInstructionParams } from "./instructionParams";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
+import { getDepositReceiptAddress } from "../accounts/depositReceipt";
+import { type ByteArray, toNumberArray } from "../utils";
+import { type BaseInstructionParams, omittedAccount } from "./instructionParams";
 
 export interface CloseStakeAccountParams extends BaseInstructionParams {
   owner: TransactionSigner;
   market: Address;
+  platformConfig: Address;
   tokenMint: Address;
   ownerTokenAccount: Address;
   tokenProgram: Address;
   optionId: number | bigint;
   stakeAccountId: number;
+  /** Integrator reference for a withdrawal receipt. No receipt is created when omitted. */
+  clientId?: ByteArray;
+  sequence?: bigint;
+  /** Required if the position was counted on the owner's portfolio. */
+  userPortfolio?: Address;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address;
 }
 
 export async function closeStakeAccount(
   input: CloseStakeAccountParams
 ): Promise<CloseStakeAccountInstruction<string>> {
-  const { programAddress, ...params } = input;
+  const { programAddress, clientId, sequence, userPortfolio, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
+  // The program seeds the receipt with the raw client ID, not its Option encoding.
+  const depositReceipt = clientId
+    ? (await getDepositReceiptAddress(params.owner.address, clientId, programAddress))[0]
+    : omittedAccount(programAddress);
   return getCloseStakeAccountInstructionAsync(
-    params,
+    {
+      ...params,
+      clientId: clientId ? toNumberArray(clientId) : null,
+      sequence: sequence ?? null,
+      depositReceipt,
+      userPortfolio: userPortfolio ?? omittedAccount(programAddress),
+      eventAuthority,
+    },
     programAddress ? { programAddress } : undefined
   );
 }
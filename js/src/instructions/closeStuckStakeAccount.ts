@@ -3,7 +3,8 @@ import {
   getCloseStuckStakeAccountInstructionAsync,
   type CloseStuckStakeAccountInstruction,
 } from "../generated";
-import { type BaseInstructionParams } from "./instructionParams";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
+import { type BaseInstructionParams, omittedAccount } from "./instructionParams";
 
 export interface CloseStuckStakeAccountParams extends BaseInstructionParams {
   signer: TransactionSigner;
@@ -12,14 +13,21 @@ export interface CloseStuckStakeAccountParams extends BaseInstructionParams {
   signerTokenAccount: Address;
   tokenProgram: Address;
   stakeAccountId: number;
+  /** Required if the position was counted on the signer's portfolio. */
+  userPortfolio?: Address;
 }
 
 export async function closeStuckStakeAccount(
   input: CloseStuckStakeAccountParams
 ): Promise<CloseStuckStakeAccountInstruction<string>> {
-  const { programAddress, ...params } = input;
+  const { programAddress, userPortfolio, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
   return getCloseStuckStakeAccountInstructionAsync(
-    params,
+    {
+      ...params,
+      userPortfolio: userPortfolio ?? omittedAccount(programAddress),
+      eventAuthority,
+    },
     programAddress ? { programAddress } : undefined
   );
 }
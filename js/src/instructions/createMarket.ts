@@ -3,6 +3,7 @@ import {
   getCreateMarketInstructionAsync,
   type CreateMarketInstruction,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { type ByteArray, toNumberArray } from "../utils";
 import { type BaseInstructionParams } from "./instructionParams";
 
@@ -29,10 +30,12 @@ export async function createMarket(
     authorizedReaderPubkey,
     ...rest
   } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
 
   return getCreateMarketInstructionAsync(
     {
       ...rest,
+      eventAuthority,
       authorizedReaderPubkey: toNumberArray(authorizedReaderPubkey),
     },
     programAddress ? { programAddress } : undefined,
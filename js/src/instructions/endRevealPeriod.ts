@@ -1,22 +1,29 @@
 import { type TransactionSigner, type Address } from "@solana/kit";
 import {
-  getEndRevealPeriodInstruction,
+  getEndRevealPeriodInstructionAsync,
   type EndRevealPeriodInstruction,
 } from "../generated";
-import { type BaseInstructionParams } from "./instructionParams";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
+import { type BaseInstructionParams, withRemainingAccounts } from "./instructionParams";
 
 export interface EndRevealPeriodParams extends BaseInstructionParams {
   signer: TransactionSigner;
   platformConfig: Address;
   market: Address;
+  /** The winning option accounts, in the order stored on the market result. */
+  winningOptions: Address[];
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address;
 }
 
-export function endRevealPeriod(
+export async function endRevealPeriod(
   input: EndRevealPeriodParams
-): EndRevealPeriodInstruction<string> {
-  const { programAddress, ...params } = input;
-  return getEndRevealPeriodInstruction(
-    params,
+): Promise<EndRevealPeriodInstruction<string>> {
+  const { programAddress, winningOptions, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
+  const ix = await getEndRevealPeriodInstructionAsync(
+    { ...params, eventAuthority },
     programAddress ? { programAddress } : undefined
   );
+  return withRemainingAccounts(ix, winningOptions);
 }
@@ -2,8 +2,9 @@ import { type TransactionSigner, type Address, type Instruction } from "@solana/
 import {
   fetchMaybePlatformConfig,
   getInitPlatformConfigInstructionAsync,
-  getUpdatePlatformConfigInstruction,
+  getUpdatePlatformConfigInstructionAsync,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { getPlatformConfigAddress } from "../accounts/platformConfig";
 import { type BaseInstructionParams } from "./instructionParams";
 
@@ -59,10 +60,13 @@ export async function ensurePlatformConfig(
       return null;
     }
 
-    return getUpdatePlatformConfigInstruction(
+    // Queues the change; execute_config_change applies it once the platform's delay passes.
+    const [eventAuthority] = await getEventAuthorityAddress(programAddress);
+    return getUpdatePlatformConfigInstructionAsync(
       {
         updateAuthority: signer,
         platformConfig: platformConfigAddress,
+        eventAuthority,
         platformFeeBp,
         rewardPoolFeeBp,
         creatorFeeBp,
@@ -70,9 +74,11 @@ export async function ensurePlatformConfig(
         minTimeToStakeSeconds,
         revealPeriodSeconds,
         marketResolutionDeadlineSeconds,
+        defaultCuPriceMicro: s.defaultCuPriceMicro,
+        configChangeDelaySeconds: s.configChangeDelaySeconds,
       },
       config,
-    ) as Instruction;
+    ) as Promise<Instruction>;
   }
 
   return getInitPlatformConfigInstructionAsync(
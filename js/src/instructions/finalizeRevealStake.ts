@@ -3,6 +3,7 @@ import {
   getFinalizeRevealStakeInstructionAsync,
   type FinalizeRevealStakeInstruction,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { type BaseInstructionParams } from "./instructionParams";
 
 export interface FinalizeRevealStakeParams extends BaseInstructionParams {
@@ -17,8 +18,9 @@ export async function finalizeRevealStake(
   input: FinalizeRevealStakeParams
 ): Promise<FinalizeRevealStakeInstruction<string>> {
   const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
   return getFinalizeRevealStakeInstructionAsync(
-    params,
+    { ...params, eventAuthority },
     programAddress ? { programAddress } : undefined
   );
 }
@@ -0,0 +1,26 @@
+import { type TransactionSigner, type Address } from "@solana/kit";
+import {
+  getFundCrankBudgetInstruction,
+  type FundCrankBudgetInstruction,
+} from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
+import { type BaseInstructionParams } from "./instructionParams";
+
+export interface FundCrankBudgetParams extends BaseInstructionParams {
+  creator: TransactionSigner;
+  market: Address;
+  lamports: bigint;
+  /** Paid to whoever finalizes a revealed stake, while the budget lasts. */
+  crankBountyLamports: bigint;
+}
+
+export async function fundCrankBudget(
+  input: FundCrankBudgetParams,
+): Promise<FundCrankBudgetInstruction<string>> {
+  const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
+  return getFundCrankBudgetInstruction(
+    { ...params, eventAuthority },
+    programAddress ? { programAddress } : undefined,
+  );
+}
@@ -22,4 +22,13 @@ export * from "./claimFees";
 export * from "./claimCreatorFees";
 export * from "./withdrawReward";
 export * from "./setUpdateAuthority";
+export * from "./acceptUpdateAuthority";
 export * from "./setFeeClaimAuthority";
+export * from "./setTransferHookPrograms";
+export * from "./setSuccessorMarket";
+export * from "./migrateStake";
+export * from "./setParticipationThreshold";
+export * from "./fundCrankBudget";
+export * from "./withdrawCrankBudget";
+export * from "./setPlatformPaused";
+export * from "./verifyStakeDisclosure";
@@ -3,6 +3,7 @@ import {
   getInitAllowedMintInstructionAsync,
   type InitAllowedMintInstruction,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { type BaseInstructionParams } from "./instructionParams";
 
 export interface InitAllowedMintParams extends BaseInstructionParams {
@@ -15,8 +16,9 @@ export async function initAllowedMint(
   input: InitAllowedMintParams,
 ): Promise<InitAllowedMintInstruction<string>> {
   const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
   return getInitAllowedMintInstructionAsync(
-    params,
+    { ...params, eventAuthority },
     programAddress ? { programAddress } : undefined,
   );
 }
@@ -18,6 +18,9 @@ import { PublicKey } from "@solana/web3.js";
 import {
   getStakeCompDefInstruction,
   getRevealStakeCompDefInstruction,
+  getRevealStakeBatchCompDefInstruction,
+  getVerifyStakeDisclosureCompDefInstruction,
+  getInitializeCompDefsInstructionAsync,
   OPPORTUNITY_MARKET_PROGRAM_ADDRESS,
   getMXEAccountDecoder,
 } from "../generated";
@@ -26,11 +29,15 @@ import { type BaseInstructionParams } from "./instructionParams";
 
 export type CompDefCircuitName =
   | "stake"
-  | "reveal_stake";
+  | "reveal_stake"
+  | "reveal_stake_batch"
+  | "verify_stake_disclosure";
 
 export const ALL_COMP_DEF_CIRCUITS: CompDefCircuitName[] = [
   "stake",
   "reveal_stake",
+  "reveal_stake_batch",
+  "verify_stake_disclosure",
 ];
 
 
@@ -96,7 +103,41 @@ export async function getInitCompDefInstruction(
     case "reveal_stake":
       return getRevealStakeCompDefInstruction(baseInput, { programAddress: programId });
 
+    case "reveal_stake_batch":
+      return getRevealStakeBatchCompDefInstruction(baseInput, { programAddress: programId });
+
+    case "verify_stake_disclosure":
+      return getVerifyStakeDisclosureCompDefInstruction(baseInput, { programAddress: programId });
+
     default:
       throw new Error(`Unknown circuit: ${circuitName}`);
   }
 }
+
+// Initializes any comp defs still missing and marks the bootstrap complete;
+// create_market is refused until this has run.
+export async function getInitializeCompDefsInstruction(
+  rpc: Rpc<SolanaRpcApi>,
+  payer: TransactionSigner,
+  config: InitCompDefConfig = {}
+): Promise<Instruction> {
+  const programId = config.programAddress ?? OPPORTUNITY_MARKET_PROGRAM_ADDRESS;
+  const mxeAccount = await getMxeAccount(rpc, programId);
+  const lutAddress = getLookupTableAddress(
+    new PublicKey(programId.toString()),
+    new BN(mxeAccount.data.lutOffsetSlot)
+  );
+
+  return getInitializeCompDefsInstructionAsync(
+    {
+      payer,
+      mxeAccount: mxeAccount.address,
+      stakeCompDefAccount: getCompDefAccount("stake", programId),
+      revealStakeCompDefAccount: getCompDefAccount("reveal_stake", programId),
+      revealStakeBatchCompDefAccount: getCompDefAccount("reveal_stake_batch", programId),
+      verifyStakeDisclosureCompDefAccount: getCompDefAccount("verify_stake_disclosure", programId),
+      addressLookupTable: toAddress(lutAddress),
+    },
+    { programAddress: programId }
+  );
+}
@@ -3,6 +3,7 @@ import {
   getInitStakeAccountInstructionAsync,
   type InitStakeAccountInstruction,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { type BaseInstructionParams } from "./instructionParams";
 
 export interface InitStakeAccountParams extends BaseInstructionParams {
@@ -16,8 +17,9 @@ export async function initStakeAccount(
   input: InitStakeAccountParams
 ): Promise<InitStakeAccountInstruction<string>> {
   const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
   return getInitStakeAccountInstructionAsync(
-    params,
+    { ...params, eventAuthority },
     programAddress ? { programAddress } : undefined
   );
 }
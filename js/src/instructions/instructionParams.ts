@@ -1,5 +1,28 @@
-import { type Address } from "@solana/kit";
+import { AccountRole, type Address, type Instruction } from "@solana/kit";
+import { OPPORTUNITY_MARKET_PROGRAM_ADDRESS } from "../generated";
 
 export interface BaseInstructionParams {
   programAddress?: Address;
 }
+
+/**
+ * Placeholder for an optional account that is left out. The async builders would otherwise
+ * derive optional accounts that have a PDA default and pass them.
+ */
+export function omittedAccount(programAddress?: Address): Address {
+  return programAddress ?? OPPORTUNITY_MARKET_PROGRAM_ADDRESS;
+}
+
+/** Appends read-only remaining accounts, such as option accounts, to an instruction. */
+export function withRemainingAccounts<TInstruction extends Instruction>(
+  instruction: TInstruction,
+  addresses: Address[]
+): TInstruction {
+  return {
+    ...instruction,
+    accounts: [
+      ...(instruction.accounts ?? []),
+      ...addresses.map((address) => ({ address, role: AccountRole.READONLY })),
+    ],
+  } as TInstruction;
+}
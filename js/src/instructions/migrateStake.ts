@@ -0,0 +1,62 @@
+import { type TransactionSigner, type Address } from "@solana/kit";
+import {
+  getMigrateStakeInstructionAsync,
+  type MigrateStakeInstruction,
+} from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
+import { type ArciumConfig, getComputeAccounts } from "../arcium/computeAccounts";
+import { type BaseInstructionParams, omittedAccount } from "./instructionParams";
+
+export interface MigrateStakeParams extends BaseInstructionParams {
+  signer: TransactionSigner;
+  payer: TransactionSigner;
+  fromMarket: Address;
+  fromStakeAccountId: number;
+  /** Successor market set on `fromMarket` with `set_successor_market`. */
+  toMarket: Address;
+  /** Initialized, unused stake account of the signer in `toMarket`. */
+  toStakeAccountId: number;
+  platformConfig: Address;
+  tokenMint: Address;
+  tokenProgram: Address;
+  authorizedReaderNonce: bigint;
+  /** u128 nonce committed to encrypted-state derivation of the new stake account. */
+  stateNonce: bigint;
+  /** Priority fee for the callback; the platform default applies when omitted. */
+  cuPriceMicro?: bigint;
+  /** Required if the old position was counted on the signer's portfolio. */
+  userPortfolio?: Address;
+  /** Required when the successor restricts participants. */
+  participantPass?: Address;
+  /** Required when the successor is gated by a mint. */
+  gateTokenAccount?: Address;
+  /** Required when the respective market keeps an audit log. */
+  fromAuditLog?: Address;
+  toAuditLog?: Address;
+}
+
+export async function migrateStake(
+  input: MigrateStakeParams,
+  config: ArciumConfig,
+): Promise<MigrateStakeInstruction<string>> {
+  const {
+    programAddress,
+    cuPriceMicro,
+    userPortfolio,
+    participantPass,
+    ...params
+  } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
+
+  return getMigrateStakeInstructionAsync(
+    {
+      ...getComputeAccounts("stake", config),
+      ...params,
+      cuPriceMicro: cuPriceMicro ?? null,
+      userPortfolio: userPortfolio ?? omittedAccount(programAddress),
+      participantPass: participantPass ?? omittedAccount(programAddress),
+      eventAuthority,
+    },
+    programAddress ? { programAddress } : undefined,
+  );
+}
@@ -3,6 +3,7 @@ import {
   getOpenMarketInstruction,
   type OpenMarketInstruction,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { type BaseInstructionParams } from "./instructionParams";
 
 export interface OpenMarketParams extends BaseInstructionParams {
@@ -10,14 +11,16 @@ export interface OpenMarketParams extends BaseInstructionParams {
   market: Address;
   platformConfig: Address;
   timeToStake: bigint;
+  auditLog?: Address;
 }
 
-export function openMarket(
+export async function openMarket(
   input: OpenMarketParams
-): OpenMarketInstruction<string> {
+): Promise<OpenMarketInstruction<string>> {
   const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
   return getOpenMarketInstruction(
-    params,
+    { ...params, eventAuthority },
     programAddress ? { programAddress } : undefined
   );
 }
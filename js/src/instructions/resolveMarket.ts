@@ -1,21 +1,28 @@
 import { type TransactionSigner, type Address } from "@solana/kit";
 import {
-  getResolveMarketInstruction,
+  getResolveMarketInstructionAsync,
   type ResolveMarketInstruction,
 } from "../generated";
-import { type BaseInstructionParams } from "./instructionParams";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
+import { type BaseInstructionParams, withRemainingAccounts } from "./instructionParams";
 
 export interface ResolveMarketParams extends BaseInstructionParams {
   marketAuthority: TransactionSigner;
   market: Address;
+  /** Every option with a reward set. Use `getOpportunityMarketOptionAddress(market, id)`. */
+  winningOptions: Address[];
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address;
 }
 
-export function resolveMarket(
+export async function resolveMarket(
   input: ResolveMarketParams,
-): ResolveMarketInstruction<string> {
-  const { programAddress, ...params } = input;
-  return getResolveMarketInstruction(
-    params,
+): Promise<ResolveMarketInstruction<string>> {
+  const { programAddress, winningOptions, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
+  const ix = await getResolveMarketInstructionAsync(
+    { ...params, eventAuthority },
     programAddress ? { programAddress } : undefined,
   );
+  return withRemainingAccounts(ix, winningOptions);
 }
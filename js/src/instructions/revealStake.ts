@@ -10,14 +10,28 @@ export interface RevealStakeParams extends BaseInstructionParams {
   signer: TransactionSigner;
   owner: Address;
   market: Address;
+  platformConfig: Address;
   stakeAccountId: number;
+  /** Priority fee for the callback; the platform default applies when omitted. */
+  cuPriceMicro?: bigint;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address;
 }
 
 export async function revealStake(
   input: RevealStakeParams,
   config: ArciumConfig
 ): Promise<RevealStakeInstruction<string>> {
-  const { programAddress, signer, owner, market, stakeAccountId } = input;
+  const {
+    programAddress,
+    signer,
+    owner,
+    market,
+    platformConfig,
+    stakeAccountId,
+    cuPriceMicro,
+    auditLog,
+  } = input;
 
   return getRevealStakeInstructionAsync(
     {
@@ -25,7 +39,10 @@ export async function revealStake(
       signer,
       owner,
       market,
+      platformConfig,
       stakeAccountId,
+      cuPriceMicro: cuPriceMicro ?? null,
+      auditLog,
     },
     programAddress ? { programAddress } : undefined
   );
@@ -3,6 +3,7 @@ import {
   getSetFeeClaimAuthorityInstruction,
   type SetFeeClaimAuthorityInstruction,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { type BaseInstructionParams } from "./instructionParams";
 
 export interface SetFeeClaimAuthorityParams extends BaseInstructionParams {
@@ -11,12 +12,13 @@ export interface SetFeeClaimAuthorityParams extends BaseInstructionParams {
   newFeeClaimAuthority: Address;
 }
 
-export function setFeeClaimAuthority(
+export async function setFeeClaimAuthority(
   input: SetFeeClaimAuthorityParams,
-): SetFeeClaimAuthorityInstruction<string> {
+): Promise<SetFeeClaimAuthorityInstruction<string>> {
   const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
   return getSetFeeClaimAuthorityInstruction(
-    params,
+    { ...params, eventAuthority },
     programAddress ? { programAddress } : undefined,
   );
 }
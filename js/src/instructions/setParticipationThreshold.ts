@@ -0,0 +1,25 @@
+import { type TransactionSigner, type Address } from "@solana/kit";
+import {
+  getSetParticipationThresholdInstruction,
+  type SetParticipationThresholdInstruction,
+} from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
+import { type BaseInstructionParams } from "./instructionParams";
+
+export interface SetParticipationThresholdParams extends BaseInstructionParams {
+  creator: TransactionSigner;
+  market: Address;
+  minTotalStake: bigint;
+  minParticipants: number;
+}
+
+export async function setParticipationThreshold(
+  input: SetParticipationThresholdParams,
+): Promise<SetParticipationThresholdInstruction<string>> {
+  const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
+  return getSetParticipationThresholdInstruction(
+    { ...params, eventAuthority },
+    programAddress ? { programAddress } : undefined,
+  );
+}
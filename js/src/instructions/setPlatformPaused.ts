@@ -0,0 +1,24 @@
+import { type TransactionSigner, type Address } from "@solana/kit";
+import {
+  getSetPlatformPausedInstruction,
+  type SetPlatformPausedInstruction,
+} from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
+import { type BaseInstructionParams } from "./instructionParams";
+
+export interface SetPlatformPausedParams extends BaseInstructionParams {
+  updateAuthority: TransactionSigner;
+  platformConfig: Address;
+  paused: boolean;
+}
+
+export async function setPlatformPaused(
+  input: SetPlatformPausedParams,
+): Promise<SetPlatformPausedInstruction<string>> {
+  const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
+  return getSetPlatformPausedInstruction(
+    { ...params, eventAuthority },
+    programAddress ? { programAddress } : undefined,
+  );
+}
@@ -0,0 +1,25 @@
+import { type TransactionSigner, type Address } from "@solana/kit";
+import {
+  getSetSuccessorMarketInstruction,
+  type SetSuccessorMarketInstruction,
+} from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
+import { type BaseInstructionParams } from "./instructionParams";
+
+export interface SetSuccessorMarketParams extends BaseInstructionParams {
+  creator: TransactionSigner;
+  market: Address;
+  /** Replacement market with the same mint and platform. */
+  successorMarket: Address;
+}
+
+export async function setSuccessorMarket(
+  input: SetSuccessorMarketParams,
+): Promise<SetSuccessorMarketInstruction<string>> {
+  const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
+  return getSetSuccessorMarketInstruction(
+    { ...params, eventAuthority },
+    programAddress ? { programAddress } : undefined,
+  );
+}
@@ -0,0 +1,25 @@
+import { type TransactionSigner, type Address } from "@solana/kit";
+import {
+  getSetTransferHookProgramsInstruction,
+  type SetTransferHookProgramsInstruction,
+} from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
+import { type BaseInstructionParams } from "./instructionParams";
+
+export interface SetTransferHookProgramsParams extends BaseInstructionParams {
+  updateAuthority: TransactionSigner;
+  platformConfig: Address;
+  /** Replaces the platform's allowlist of transfer hook programs. */
+  transferHookPrograms: Address[];
+}
+
+export async function setTransferHookPrograms(
+  input: SetTransferHookProgramsParams,
+): Promise<SetTransferHookProgramsInstruction<string>> {
+  const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
+  return getSetTransferHookProgramsInstruction(
+    { ...params, eventAuthority },
+    programAddress ? { programAddress } : undefined,
+  );
+}
@@ -3,6 +3,7 @@ import {
   getSetUpdateAuthorityInstruction,
   type SetUpdateAuthorityInstruction,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { type BaseInstructionParams } from "./instructionParams";
 
 export interface SetUpdateAuthorityParams extends BaseInstructionParams {
@@ -11,12 +12,13 @@ export interface SetUpdateAuthorityParams extends BaseInstructionParams {
   newAuthority: Address;
 }
 
-export function setUpdateAuthority(
+export async function setUpdateAuthority(
   input: SetUpdateAuthorityParams,
-): SetUpdateAuthorityInstruction<string> {
+): Promise<SetUpdateAuthorityInstruction<string>> {
   const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
   return getSetUpdateAuthorityInstruction(
-    params,
+    { ...params, eventAuthority },
     programAddress ? { programAddress } : undefined,
   );
 }
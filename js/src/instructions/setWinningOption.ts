@@ -3,6 +3,7 @@ import {
   getSetWinningOptionInstructionAsync,
   type SetWinningOptionInstruction,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { type BaseInstructionParams } from "./instructionParams";
 
 export interface SetWinningOptionParams extends BaseInstructionParams {
@@ -16,8 +17,9 @@ export async function setWinningOption(
   input: SetWinningOptionParams,
 ): Promise<SetWinningOptionInstruction<string>> {
   const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
   return getSetWinningOptionInstructionAsync(
-    params,
+    { ...params, eventAuthority },
     programAddress ? { programAddress } : undefined,
   );
 }
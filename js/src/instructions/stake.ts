@@ -3,14 +3,16 @@ import {
   getStakeInstructionAsync,
   type StakeInstruction,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { type ArciumConfig, getComputeAccounts } from "../arcium/computeAccounts";
 import { type ByteArray, toNumberArray } from "../utils";
-import { type BaseInstructionParams } from "./instructionParams";
+import { type BaseInstructionParams, omittedAccount } from "./instructionParams";
 
 export interface StakeParams extends BaseInstructionParams {
   signer: TransactionSigner;
   payer: TransactionSigner;
   market: Address;
+  platformConfig: Address;
   /** PDA of the stake_account being staked into. Use `getStakeAccountAddress(owner, market, id)`. */
   stakeAccount: Address;
   stakeAccountId: number;
@@ -26,6 +28,19 @@ export interface StakeParams extends BaseInstructionParams {
   userPubkey: ByteArray;
   /** u128 nonce committed to encrypted-state derivation. */
   stateNonce: bigint;
+  /** Integrator reference for a deposit receipt. No receipt is created when omitted. */
+  clientId?: ByteArray;
+  sequence?: bigint;
+  /** Priority fee for the callback; the platform default applies when omitted. */
+  cuPriceMicro?: bigint;
+  /** Signer's portfolio, which counts the position when passed. */
+  userPortfolio?: Address;
+  /** Required when the market restricts participants. */
+  participantPass?: Address;
+  /** Required when the market is gated by a mint. */
+  gateTokenAccount?: Address;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address;
 }
 
 export async function stake(
@@ -37,6 +52,7 @@ export async function stake(
     signer,
     payer,
     market,
+    platformConfig,
     stakeAccount,
     stakeAccountId,
     tokenMint,
@@ -48,7 +64,15 @@ export async function stake(
     authorizedReaderNonce,
     userPubkey,
     stateNonce,
+    clientId,
+    sequence,
+    cuPriceMicro,
+    userPortfolio,
+    participantPass,
+    gateTokenAccount,
+    auditLog,
   } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
 
   return getStakeInstructionAsync(
     {
@@ -56,6 +80,7 @@ export async function stake(
       signer,
       payer,
       market,
+      platformConfig,
       stakeAccount,
       stakeAccountId,
       tokenMint,
@@ -67,6 +92,15 @@ export async function stake(
       authorizedReaderNonce,
       userPubkey: toNumberArray(userPubkey),
       stateNonce,
+      clientId: toNumberArray(clientId ?? new Uint8Array(32)),
+      sequence: sequence ?? 0n,
+      cuPriceMicro: cuPriceMicro ?? null,
+      depositReceipt: clientId ? undefined : omittedAccount(programAddress),
+      userPortfolio: userPortfolio ?? omittedAccount(programAddress),
+      participantPass: participantPass ?? omittedAccount(programAddress),
+      gateTokenAccount,
+      auditLog,
+      eventAuthority,
     },
     programAddress ? { programAddress } : undefined,
   );
@@ -3,25 +3,36 @@ import {
   getUnstakeInstructionAsync,
   type UnstakeInstruction,
 } from "../generated";
-import { type BaseInstructionParams } from "./instructionParams";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
+import { type BaseInstructionParams, omittedAccount } from "./instructionParams";
 
 export interface UnstakeParams extends BaseInstructionParams {
   signer: TransactionSigner;
   owner: Address;
   market: Address;
+  platformConfig: Address;
   tokenMint: Address;
   ownerTokenAccount: Address;
   tokenProgram: Address;
   stakeAccountId: number;
+  /** Owner's portfolio, updated when passed. */
+  userPortfolio?: Address;
+  /** Required when the market keeps an audit log. */
+  auditLog?: Address;
 }
 
 export async function unstake(
   input: UnstakeParams,
 ): Promise<UnstakeInstruction<string>> {
-  const { programAddress, ...params } = input;
+  const { programAddress, userPortfolio, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
 
   return getUnstakeInstructionAsync(
-    params,
+    {
+      ...params,
+      userPortfolio: userPortfolio ?? omittedAccount(programAddress),
+      eventAuthority,
+    },
     programAddress ? { programAddress } : undefined
   );
 }
@@ -1,8 +1,9 @@
 import { type TransactionSigner, type Address, type Instruction } from "@solana/kit";
 import {
   fetchMaybePlatformConfig,
-  getUpdatePlatformConfigInstruction,
+  getUpdatePlatformConfigInstructionAsync,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { getPlatformConfigAddress } from "../accounts/platformConfig";
 import { type BaseInstructionParams } from "./instructionParams";
 
@@ -16,6 +17,8 @@ export interface UpdatePlatformConfigParams extends BaseInstructionParams {
   minTimeToStakeSeconds: bigint;
   revealPeriodSeconds: bigint;
   marketResolutionDeadlineSeconds: bigint;
+  defaultCuPriceMicro: bigint;
+  configChangeDelaySeconds: bigint;
 }
 
 export async function updatePlatformConfig(
@@ -33,6 +36,8 @@ export async function updatePlatformConfig(
     minTimeToStakeSeconds,
     revealPeriodSeconds,
     marketResolutionDeadlineSeconds,
+    defaultCuPriceMicro,
+    configChangeDelaySeconds,
   } = params;
 
   const [platformConfigAddress] = await getPlatformConfigAddress(
@@ -47,10 +52,13 @@ export async function updatePlatformConfig(
     );
   }
 
-  return getUpdatePlatformConfigInstruction(
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
+
+  return getUpdatePlatformConfigInstructionAsync(
     {
       updateAuthority: signer,
       platformConfig: platformConfigAddress,
+      eventAuthority,
       platformFeeBp,
       rewardPoolFeeBp,
       creatorFeeBp,
@@ -58,7 +66,9 @@ export async function updatePlatformConfig(
       minTimeToStakeSeconds,
       revealPeriodSeconds,
       marketResolutionDeadlineSeconds,
+      defaultCuPriceMicro,
+      configChangeDelaySeconds,
     },
     programAddress ? { programAddress } : undefined,
-  ) as Instruction;
+  ) as Promise<Instruction>;
 }
@@ -0,0 +1,46 @@
+import { type TransactionSigner, type Address } from "@solana/kit";
+import {
+  getVerifyStakeDisclosureInstructionAsync,
+  type VerifyStakeDisclosureInstruction,
+} from "../generated";
+import { type ArciumConfig, getComputeAccounts } from "../arcium/computeAccounts";
+import { type BaseInstructionParams } from "./instructionParams";
+
+export interface VerifyStakeDisclosureParams extends BaseInstructionParams {
+  /** Owner of the stake account; only they may check a claim against it. */
+  signer: TransactionSigner;
+  market: Address;
+  platformConfig: Address;
+  stakeAccountId: number;
+  claimedOption: bigint;
+  /** Priority fee for the callback; the platform default applies when omitted. */
+  cuPriceMicro?: bigint;
+}
+
+export async function verifyStakeDisclosure(
+  input: VerifyStakeDisclosureParams,
+  config: ArciumConfig
+): Promise<VerifyStakeDisclosureInstruction<string>> {
+  const {
+    programAddress,
+    signer,
+    market,
+    platformConfig,
+    stakeAccountId,
+    claimedOption,
+    cuPriceMicro,
+  } = input;
+
+  return getVerifyStakeDisclosureInstructionAsync(
+    {
+      ...getComputeAccounts("verify_stake_disclosure", config),
+      signer,
+      market,
+      platformConfig,
+      stakeAccountId,
+      claimedOption,
+      cuPriceMicro: cuPriceMicro ?? null,
+    },
+    programAddress ? { programAddress } : undefined
+  );
+}
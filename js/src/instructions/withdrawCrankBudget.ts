@@ -0,0 +1,23 @@
+import { type TransactionSigner, type Address } from "@solana/kit";
+import {
+  getWithdrawCrankBudgetInstruction,
+  type WithdrawCrankBudgetInstruction,
+} from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
+import { type BaseInstructionParams } from "./instructionParams";
+
+export interface WithdrawCrankBudgetParams extends BaseInstructionParams {
+  creator: TransactionSigner;
+  market: Address;
+}
+
+export async function withdrawCrankBudget(
+  input: WithdrawCrankBudgetParams,
+): Promise<WithdrawCrankBudgetInstruction<string>> {
+  const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
+  return getWithdrawCrankBudgetInstruction(
+    { ...params, eventAuthority },
+    programAddress ? { programAddress } : undefined,
+  );
+}
@@ -3,11 +3,13 @@ import {
   getWithdrawRewardInstructionAsync,
   type WithdrawRewardInstruction,
 } from "../generated";
+import { getEventAuthorityAddress } from "../accounts/eventAuthority";
 import { type BaseInstructionParams } from "./instructionParams";
 
 export interface WithdrawRewardParams extends BaseInstructionParams {
   sponsor: TransactionSigner;
   market: Address;
+  platformConfig: Address;
   tokenMint: Address;
   refundTokenAccount: Address;
   tokenProgram: Address;
@@ -17,8 +19,9 @@ export async function withdrawReward(
   input: WithdrawRewardParams
 ): Promise<WithdrawRewardInstruction<string>> {
   const { programAddress, ...params } = input;
+  const [eventAuthority] = await getEventAuthorityAddress(programAddress);
   return getWithdrawRewardInstructionAsync(
-    params,
+    { ...params, eventAuthority },
     programAddress ? { programAddress } : undefined
   );
 }
@@ -1,4 +1,5 @@
-import { randomBytes } from "crypto";
+import { createHash, randomBytes } from "crypto";
+import { getAddressEncoder, getU64Encoder, type Address } from "@solana/kit";
 
 /**
  * Generates a random computation offset for Arcium computations.
@@ -8,6 +9,19 @@ export function randomComputationOffset(): bigint {
   return BigInt("0x" + randomBytes(8).toString("hex"));
 }
 
+/**
+ * Derives the computation offset the program expects for a stake account, given its
+ * `nextComputationSeq`. Mirrors `derive_computation_offset` in the program.
+ */
+export function deriveComputationOffset(account: Address, seq: bigint): bigint {
+  const hash = createHash("sha256")
+    .update("computation_offset")
+    .update(Buffer.from(getAddressEncoder().encode(account)))
+    .update(Buffer.from(getU64Encoder().encode(seq)))
+    .digest();
+  return hash.readBigUInt64LE(0);
+}
+
 /**
  * Generates a random u128 state nonce for encrypted token account initialization.
  * Returns a BigInt from 16 random bytes.
@@ -0,0 +1,69 @@
+/// Declares a `queue_computation_accounts` context with the Arcium accounts appended.
+///
+/// `#[queue_computation_accounts]` validates the Arcium accounts by field name, so they
+/// can't live in an Anchor composite struct. This macro keeps their constraints in one place
+/// instead. The computation offset is passed by name, since it must match the `#[instruction]`
+/// argument the Accounts derive binds.
+///
+/// ```ignore
+/// arcium_queue_accounts!("stake", payer, COMP_DEF_OFFSET_STAKE, computation_offset,
+///     #[instruction(computation_offset: u64)]
+///     pub struct Stake<'info> {
+///         #[account(mut)]
+///         pub payer: Signer<'info>,
+///     }
+/// );
+/// ```
+macro_rules! arcium_queue_accounts {
+    (
+        $encrypted_ix:literal, $payer:ident, $comp_def_offset:ident, $computation_offset:ident,
+        $(#[$meta:meta])*
+        pub struct $name:ident<'info> {
+            $($fields:tt)*
+        }
+    ) => {
+        #[::arcium_anchor::prelude::queue_computation_accounts($encrypted_ix, $payer)]
+        #[derive(Accounts)]
+        $(#[$meta])*
+        pub struct $name<'info> {
+            $($fields)*
+
+            // Arcium accounts
+            // Created once by `initialize_comp_defs`, so queueing never pays its rent.
+            #[account(
+                mut,
+                seeds = [&SIGN_PDA_SEED],
+                bump = sign_pda_account.bump,
+                address = derive_sign_pda!(),
+            )]
+            pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+            #[account(address = derive_mxe_pda!())]
+            pub mxe_account: Box<Account<'info, MXEAccount>>,
+            #[account(mut, address = derive_mempool_pda!(mxe_account))]
+            /// CHECK: mempool_account
+            pub mempool_account: UncheckedAccount<'info>,
+            #[account(mut, address = derive_execpool_pda!(mxe_account))]
+            /// CHECK: executing_pool
+            pub executing_pool: UncheckedAccount<'info>,
+            #[account(
+                mut,
+                address = derive_comp_pda!($computation_offset, mxe_account),
+                constraint = computation_account.data_is_empty() @ $crate::error::ErrorCode::ComputationOffsetInUse,
+            )]
+            /// CHECK: computation_account
+            pub computation_account: UncheckedAccount<'info>,
+            #[account(address = derive_comp_def_pda!($comp_def_offset))]
+            pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+            #[account(mut, address = derive_cluster_pda!(mxe_account))]
+            pub cluster_account: Box<Account<'info, Cluster>>,
+            #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+            pub pool_account: Box<Account<'info, FeePool>>,
+            #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+            pub clock_account: Box<Account<'info, ClockAccount>>,
+            pub system_program: Program<'info, System>,
+            pub arcium_program: Program<'info, Arcium>,
+        }
+    };
+}
+
+pub(crate) use arcium_queue_accounts;
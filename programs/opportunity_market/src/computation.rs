@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+const COMPUTATION_OFFSET_DOMAIN: &[u8] = b"computation_offset";
+
+// Derives a computation_offset from the account the computation is for (e.g. the stake
+// account) and a nonce. Stake accounts require the nonce to be their next_computation_seq,
+// so clients don't have to invent offsets ad hoc.
+// Offsets only need to be unique per MXE; reusing one fails with ComputationOffsetInUse.
+pub fn derive_computation_offset(account: &Pubkey, nonce: u64) -> u64 {
+    let hash = hashv(&[
+        COMPUTATION_OFFSET_DOMAIN,
+        account.as_ref(),
+        &nonce.to_le_bytes(),
+    ]);
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&hash.to_bytes()[..8]);
+    u64::from_le_bytes(bytes)
+}
+
+// The MPC fee a queue_computation call charged its payer, given the payer's balance before
+// and after the call. The payer also funds the new computation account's rent, which Arcium's
+// claim_computation_rent returns later, so that part is not counted.
+pub fn queued_mpc_fee(
+    payer_lamports_before: u64,
+    payer_lamports_after: u64,
+    computation_account_rent: u64,
+) -> u64 {
+    payer_lamports_before
+        .saturating_sub(payer_lamports_after)
+        .saturating_sub(computation_account_rent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derivation_is_deterministic() {
+        let account = Pubkey::new_unique();
+        assert_eq!(
+            derive_computation_offset(&account, 7),
+            derive_computation_offset(&account, 7)
+        );
+    }
+
+    #[test]
+    fn derivation_depends_on_account_and_nonce() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        assert_ne!(
+            derive_computation_offset(&a, 0),
+            derive_computation_offset(&a, 1)
+        );
+        assert_ne!(
+            derive_computation_offset(&a, 0),
+            derive_computation_offset(&b, 0)
+        );
+    }
+
+    #[test]
+    fn mpc_fee_excludes_computation_account_rent() {
+        assert_eq!(queued_mpc_fee(10_000, 6_000, 3_000), 1_000);
+        assert_eq!(queued_mpc_fee(10_000, 7_000, 3_000), 0);
+        assert_eq!(queued_mpc_fee(10_000, 10_000, 0), 0);
+    }
+}
@@ -1,3 +1,5 @@
+use anchor_lang::prelude::*;
+
 pub const MAX_PLATFORM_FEE_BP: u16 = 300;
 pub const MAX_CREATOR_FEE_BP: u16 = 500;
 pub const MAX_REWARD_POOL_FEE_BP: u16 = 10_000;
@@ -7,6 +9,12 @@ pub const MAX_TOTAL_FEE_BP: u16 = 10_000;
 pub const MIN_PLATFORM_NAME_LEN: usize = 3;
 pub const MAX_PLATFORM_NAME_LEN: usize = 20;
 
+/// Maximum length (in bytes) of a market category name.
+pub const MAX_CATEGORY_NAME_LEN: usize = 32;
+
+/// Number of entries an AuditLog keeps before overwriting the oldest.
+pub const AUDIT_LOG_CAPACITY: usize = 64;
+
 #[cfg(feature = "production-settings")]
 pub const MIN_MARKET_RESOLUTION_DEADLINE_SECONDS: u64 = 7 * 24 * 60 * 60;
 
@@ -22,10 +30,87 @@ pub const MAX_EARLINESS_MULTIPLIER: u16 = 20_000;
 
 pub const MAX_TIME_TO_STAKE_SECONDS: u64 = 3 * 30 * 24 * 60 * 60;
 
+/// Upper bound for the priority fee paid to the Arcium mempool when queueing a computation.
+pub const MAX_CU_PRICE_MICRO: u64 = 1_000_000;
+
+/// Upper bound for the MPC fee the crank budget refunds per reveal computation. The fee
+/// includes the signer's chosen priority fee, so an uncapped refund could drain the budget.
+pub const MAX_MPC_FEE_REFUND_LAMPORTS: u64 = 5_000_000;
+
+/// Number of stake accounts revealed by one `reveal_stake_batch` computation.
+pub const REVEAL_BATCH_SIZE: usize = 4;
+
+/// Upper bound for the number of winning options recorded in a market's result.
+pub const MAX_RESULT_OPTIONS: usize = 16;
+
+/// Upper bound for the share of the reward pool a market can rebate to losers.
+pub const MAX_LOSER_REBATE_BP: u16 = 5_000;
+
+/// Upper bound for the number of tranches a market's reward can vest in.
+pub const MAX_REWARD_TRANCHES: u8 = 12;
+
+/// Upper bound for the number of options create_market_with_options can create.
+pub const MAX_OPTIONS_PER_CREATE: usize = 8;
+
+/// Time after stake end before the platform can void an unresolved market.
+pub const EMERGENCY_RESOLVE_DELAY_SECONDS: u64 = 24 * 60 * 60; // 1 day
+
+/// Upper bound for the delay between queueing and executing a platform config change.
+pub const MAX_CONFIG_CHANGE_DELAY_SECONDS: u64 = 30 * 24 * 60 * 60; // 30 days
+
+/// Upper bound for the number of transfer hook programs a platform can allowlist.
+pub const MAX_TRANSFER_HOOK_PROGRAMS: usize = 4;
+
+/// Upper bound (in bytes) for an instruction's Accounts struct; see instructions/mod.rs.
+pub const MAX_ACCOUNTS_CONTEXT_SIZE: usize = 256;
+
+/// Stored as the first field of every account and event. Bump when any layout changes.
+/// Accounts are never migrated, so a new layout needs a fresh deployment.
+#[constant]
+pub const SCHEMA_VERSION: u8 = 1;
+
+/// Byte ranges of StakeAccount fields, including the 8-byte discriminator, for
+/// `ArgBuilder::account` ranges and memcmp filters.
+#[constant]
+pub const STAKE_ACCOUNT_ENCRYPTED_OPTION_OFFSET: u32 = 8 + 1; // discriminator + schema_version
+#[constant]
+pub const STAKE_ACCOUNT_MARKET_OFFSET: u32 = 8 + 1 + 32 + 16 + 1 + 32;
+#[constant]
+pub const STAKE_ACCOUNT_ENCRYPTED_OPTION_DISCLOSURE_OFFSET: u32 =
+    8 + 1 + 32 + 16 + 1 + 32 + 32 + 32;
+#[constant]
+pub const ENCRYPTED_OPTION_LEN: u32 = 32;
+
 /// PDA seeds
+#[constant]
 pub const PLATFORM_CONFIG_SEED: &[u8] = b"platform_config";
+#[constant]
 pub const ALLOWED_MINT_SEED: &[u8] = b"allowed_mint";
+#[constant]
 pub const OPPORTUNITY_MARKET_SEED: &[u8] = b"opportunity_market";
+#[constant]
 pub const OPTION_SEED: &[u8] = b"option";
+#[constant]
 pub const STAKE_ACCOUNT_SEED: &[u8] = b"stake_account";
+#[constant]
 pub const SPONSOR_SEED: &[u8] = b"sponsor";
+#[constant]
+pub const DEPOSIT_RECEIPT_SEED: &[u8] = b"deposit_receipt";
+#[constant]
+pub const BOOTSTRAP_STATE_SEED: &[u8] = b"bootstrap_state";
+#[constant]
+pub const PROGRAM_METRICS_SEED: &[u8] = b"program_metrics";
+#[constant]
+pub const PENDING_CONFIG_CHANGE_SEED: &[u8] = b"pending_config_change";
+#[constant]
+pub const MARKET_RESULT_SEED: &[u8] = b"market_result";
+#[constant]
+pub const PARTICIPANT_PASS_SEED: &[u8] = b"participant_pass";
+#[constant]
+pub const CATEGORY_SEED: &[u8] = b"category";
+#[constant]
+pub const CATEGORY_MARKET_SEED: &[u8] = b"category_market";
+#[constant]
+pub const USER_PORTFOLIO_SEED: &[u8] = b"user_portfolio";
+#[constant]
+pub const AUDIT_LOG_SEED: &[u8] = b"audit_log";
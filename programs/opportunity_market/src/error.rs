@@ -70,4 +70,36 @@ pub enum ErrorCode {
     NoRewardToClaim,
     #[msg("Reward already claimed")]
     RewardAlreadyClaimed,
+    #[msg("Platform is paused")]
+    PlatformPaused,
+    #[msg("No pending update authority")]
+    NoPendingUpdateAuthority,
+    #[msg("Computation offset already in use")]
+    ComputationOffsetInUse,
+    #[msg("Callback already applied")]
+    CallbackAlreadyApplied,
+    #[msg("Computation definitions not initialized")]
+    BootstrapIncomplete,
+    #[msg("Config change delay has not passed")]
+    ConfigChangeNotReady,
+    #[msg("Reward has not fully vested")]
+    RewardNotVested,
+    #[msg("Participant pass required")]
+    ParticipantPassRequired,
+    #[msg("Gate token required")]
+    GateTokenRequired,
+    #[msg("Market is void: it missed its participation threshold or was voided by the platform")]
+    MarketVoid,
+    #[msg("Computation offset does not match the account's next derived offset")]
+    UnexpectedComputationOffset,
+    #[msg("Audit log required")]
+    AuditLogRequired,
+    #[msg("Transfer hook program is not allowlisted or its hook is mutable")]
+    TransferHookNotAllowed,
+    #[msg("User portfolio required")]
+    UserPortfolioRequired,
+    #[msg("Stake was migrated to a successor market")]
+    StakeMigrated,
+    #[msg("Account was written by an unsupported schema version")]
+    SchemaVersionMismatch,
 }
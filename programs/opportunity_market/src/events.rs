@@ -1,22 +1,51 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::Event;
 
-use crate::state::FeeRates;
+use crate::state::{FeeRates, PlatformConfigChange, ReceiptKind};
 
-/// Emits an event with `timestamp` automatically set from `Clock::get()`.
+/// Emits an event through a self-CPI (`#[event_cpi]`) with `schema_version` and `timestamp`
+/// set automatically. Takes the handler's `ctx`, whose accounts must include `event_authority` and `program`.
 macro_rules! emit_ts {
-    ($event:ident { $($field:ident : $value:expr),* $(,)? }) => {{
+    ($ctx:ident, $event:ident { $($field:ident : $value:expr),* $(,)? }) => {{
         let clock = Clock::get()?;
-        emit!($event {
-            $($field: $value,)*
-            timestamp: clock.unix_timestamp,
-        });
+        $crate::events::emit_cpi_event(
+            &$ctx.accounts.event_authority,
+            &$event {
+                schema_version: $crate::constants::SCHEMA_VERSION,
+                $($field: $value,)*
+                timestamp: clock.unix_timestamp,
+            },
+        )?;
     }};
 }
 
 pub(crate) use emit_ts;
 
+// Same as `emit_cpi!`, which can't be called from `emit_ts!` since it expects a local `ctx`.
+pub(crate) fn emit_cpi_event<E: Event>(event_authority: &AccountInfo, event: &E) -> Result<()> {
+    let ix_data: Vec<u8> = anchor_lang::event::EVENT_IX_TAG_LE
+        .iter()
+        .copied()
+        .chain(event.data())
+        .collect();
+    let ix = Instruction::new_with_bytes(
+        crate::ID,
+        &ix_data,
+        vec![AccountMeta::new_readonly(event_authority.key(), true)],
+    );
+    invoke_signed(
+        &ix,
+        std::slice::from_ref(event_authority),
+        &[&[b"__event_authority", &[crate::EVENT_AUTHORITY_AND_BUMP.1]]],
+    )?;
+    Ok(())
+}
+
 #[event]
 pub struct MarketCreatedEvent {
+    pub schema_version: u8,
     pub market: Pubkey,
     pub creator: Pubkey,
     pub platform: Pubkey,
@@ -37,6 +66,17 @@ pub struct MarketCreatedEvent {
 
 #[event]
 pub struct MarketOptionCreatedEvent {
+    pub schema_version: u8,
+    pub option: Pubkey,
+    pub market: Pubkey,
+    pub signer: Pubkey,
+    pub id: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketOptionRemovedEvent {
+    pub schema_version: u8,
     pub option: Pubkey,
     pub market: Pubkey,
     pub signer: Pubkey,
@@ -46,6 +86,7 @@ pub struct MarketOptionCreatedEvent {
 
 #[event]
 pub struct StakedEvent {
+    pub schema_version: u8,
     pub user: Pubkey,
     pub market: Pubkey,
     pub stake_account: Pubkey,
@@ -60,6 +101,7 @@ pub struct StakedEvent {
 
 #[event]
 pub struct StakeRevealedEvent {
+    pub schema_version: u8,
     pub user: Pubkey,
     pub market: Pubkey,
     pub stake_account: Pubkey,
@@ -71,6 +113,7 @@ pub struct StakeRevealedEvent {
 
 #[event]
 pub struct UnstakedEvent {
+    pub schema_version: u8,
     pub owner: Pubkey,
     pub market: Pubkey,
     pub stake_account: Pubkey,
@@ -81,14 +124,25 @@ pub struct UnstakedEvent {
 
 #[event]
 pub struct MarketOpenedEvent {
+    pub schema_version: u8,
     pub market: Pubkey,
     pub creator: Pubkey,
     pub stake_end_timestamp: u64,
     pub timestamp: i64,
 }
 
+#[event]
+pub struct MarketTimingUpdatedEvent {
+    pub schema_version: u8,
+    pub market: Pubkey,
+    pub earliness_cutoff_seconds: u64,
+    pub earliness_multiplier: u16,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct WinningOptionSetEvent {
+    pub schema_version: u8,
     pub market: Pubkey,
     pub market_authority: Pubkey,
     pub option: Pubkey,
@@ -100,6 +154,7 @@ pub struct WinningOptionSetEvent {
 
 #[event]
 pub struct MarketResolvedEvent {
+    pub schema_version: u8,
     pub market: Pubkey,
     pub market_authority: Pubkey,
     pub timestamp: i64,
@@ -107,6 +162,7 @@ pub struct MarketResolvedEvent {
 
 #[event]
 pub struct StakeAccountClosedEvent {
+    pub schema_version: u8,
     pub owner: Pubkey,
     pub market: Pubkey,
     pub stake_account: Pubkey,
@@ -120,8 +176,39 @@ pub struct StakeAccountClosedEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct LoserRebateSetEvent {
+    pub schema_version: u8,
+    pub market: Pubkey,
+    pub loser_rebate_bp: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardVestingSetEvent {
+    pub schema_version: u8,
+    pub market: Pubkey,
+    pub reward_tranches: u8,
+    pub reward_tranche_interval_seconds: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardTrancheClaimedEvent {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub market: Pubkey,
+    pub stake_account: Pubkey,
+    pub stake_account_id: u32,
+    pub amount: u64,
+    pub reward_claimed: u64,
+    pub reward_total: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct RevealStakeFinalizedEvent {
+    pub schema_version: u8,
     pub owner: Pubkey,
     pub market: Pubkey,
     pub stake_account: Pubkey,
@@ -138,6 +225,7 @@ pub struct RevealStakeFinalizedEvent {
 
 #[event]
 pub struct RewardAddedEvent {
+    pub schema_version: u8,
     pub market: Pubkey,
     pub sponsor: Pubkey,
     pub amount: u64,
@@ -148,6 +236,7 @@ pub struct RewardAddedEvent {
 
 #[event]
 pub struct RewardWithdrawnEvent {
+    pub schema_version: u8,
     pub market: Pubkey,
     pub sponsor: Pubkey,
     pub reward_amount: u64,
@@ -157,6 +246,7 @@ pub struct RewardWithdrawnEvent {
 
 #[event]
 pub struct RevealPeriodEndedEvent {
+    pub schema_version: u8,
     pub market: Pubkey,
     pub signer: Pubkey,
     pub timestamp: i64,
@@ -164,6 +254,7 @@ pub struct RevealPeriodEndedEvent {
 
 #[event]
 pub struct StakeAccountInitializedEvent {
+    pub schema_version: u8,
     pub stake_account: Pubkey,
     pub owner: Pubkey,
     pub market: Pubkey,
@@ -173,6 +264,7 @@ pub struct StakeAccountInitializedEvent {
 
 #[event]
 pub struct FeesClaimedEvent {
+    pub schema_version: u8,
     pub market: Pubkey,
     pub platform: Pubkey,
     pub mint: Pubkey,
@@ -183,14 +275,17 @@ pub struct FeesClaimedEvent {
 
 #[event]
 pub struct AllowedMintInitializedEvent {
+    pub schema_version: u8,
     pub allowed_mint: Pubkey,
     pub platform: Pubkey,
     pub mint: Pubkey,
+    pub transfer_hook_program: Option<Pubkey>,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct StuckStakeClosedEvent {
+    pub schema_version: u8,
     pub owner: Pubkey,
     pub market: Pubkey,
     pub stake_account: Pubkey,
@@ -204,6 +299,7 @@ pub struct StuckStakeClosedEvent {
 
 #[event]
 pub struct CreatorFeesClaimedEvent {
+    pub schema_version: u8,
     pub market: Pubkey,
     pub creator_fee_claimer: Pubkey,
     pub mint: Pubkey,
@@ -212,8 +308,18 @@ pub struct CreatorFeesClaimedEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct UpdateAuthorityProposedEvent {
+    pub schema_version: u8,
+    pub platform_config: Pubkey,
+    pub update_authority: Pubkey,
+    pub pending_update_authority: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct UpdateAuthorityChangedEvent {
+    pub schema_version: u8,
     pub platform_config: Pubkey,
     pub old_value: Pubkey,
     pub new_value: Pubkey,
@@ -222,6 +328,7 @@ pub struct UpdateAuthorityChangedEvent {
 
 #[event]
 pub struct FeeClaimAuthorityChangedEvent {
+    pub schema_version: u8,
     pub platform_config: Pubkey,
     pub old_value: Pubkey,
     pub new_value: Pubkey,
@@ -230,6 +337,7 @@ pub struct FeeClaimAuthorityChangedEvent {
 
 #[event]
 pub struct OptionClosedEvent {
+    pub schema_version: u8,
     pub option: Pubkey,
     pub option_id: u64,
     pub signer: Pubkey,
@@ -237,3 +345,189 @@ pub struct OptionClosedEvent {
     pub market: Pubkey,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct DepositReceiptRecordedEvent {
+    pub schema_version: u8,
+    pub receipt: Pubkey,
+    pub owner: Pubkey,
+    pub market: Pubkey,
+    pub stake_account: Pubkey,
+    pub stake_account_id: u32,
+    pub kind: ReceiptKind,
+    pub client_id: [u8; 32],
+    pub sequence: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeDisclosureVerifiedEvent {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub market: Pubkey,
+    pub stake_account: Pubkey,
+    pub stake_account_id: u32,
+    pub claimed_option: u64,
+    pub matches: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformPausedEvent {
+    pub schema_version: u8,
+    pub platform_config: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeQueuedEvent {
+    pub schema_version: u8,
+    pub platform_config: Pubkey,
+    pub pending_config_change: Pubkey,
+    pub executable_at: u64,
+    pub change: PlatformConfigChange,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeExecutedEvent {
+    pub schema_version: u8,
+    pub platform_config: Pubkey,
+    pub change: PlatformConfigChange,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeCancelledEvent {
+    pub schema_version: u8,
+    pub platform_config: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SuccessorMarketSetEvent {
+    pub schema_version: u8,
+    pub market: Pubkey,
+    pub successor_market: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeMigratedEvent {
+    pub schema_version: u8,
+    pub owner: Pubkey,
+    pub from_market: Pubkey,
+    pub from_stake_account: Pubkey,
+    pub to_market: Pubkey,
+    pub to_stake_account: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParticipantsRestrictedSetEvent {
+    pub schema_version: u8,
+    pub market: Pubkey,
+    pub participants_restricted: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParticipantPassIssuedEvent {
+    pub schema_version: u8,
+    pub market: Pubkey,
+    pub participant: Pubkey,
+    pub participant_pass: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParticipantPassRevokedEvent {
+    pub schema_version: u8,
+    pub market: Pubkey,
+    pub participant: Pubkey,
+    pub participant_pass: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GateMintSetEvent {
+    pub schema_version: u8,
+    pub market: Pubkey,
+    pub gate_mint: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParticipationThresholdSetEvent {
+    pub schema_version: u8,
+    pub market: Pubkey,
+    pub min_total_stake: u64,
+    pub min_participants: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CrankBudgetFundedEvent {
+    pub schema_version: u8,
+    pub market: Pubkey,
+    pub lamports: u64,
+    pub crank_budget_lamports: u64,
+    pub crank_bounty_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CrankBudgetWithdrawnEvent {
+    pub schema_version: u8,
+    pub market: Pubkey,
+    pub lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CategoryCreatedEvent {
+    pub schema_version: u8,
+    pub platform: Pubkey,
+    pub category: Pubkey,
+    pub id: u32,
+    pub name: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketCategorySetEvent {
+    pub schema_version: u8,
+    pub market: Pubkey,
+    pub category: Pubkey,
+    pub index: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketEmergencyResolvedEvent {
+    pub schema_version: u8,
+    pub market: Pubkey,
+    pub update_authority: Pubkey,
+    // Hash of the off-chain justification for voiding the market.
+    pub justification_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuditLogInitializedEvent {
+    pub schema_version: u8,
+    pub market: Pubkey,
+    pub audit_log: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferHookProgramsChangedEvent {
+    pub schema_version: u8,
+    pub platform_config: Pubkey,
+    pub transfer_hook_programs: Vec<Pubkey>,
+    pub timestamp: i64,
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::OPTION_SEED;
+use crate::constants::{OPTION_SEED, SCHEMA_VERSION};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, MarketOptionCreatedEvent};
 use crate::state::{OpportunityMarket, OpportunityMarketOption};
@@ -14,6 +14,7 @@ pub struct AddMarketOption<'info> {
     #[account(
         mut,
         constraint = market.resolved_at_timestamp.is_none() @ ErrorCode::WinnerAlreadySelected,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
     pub market: Box<Account<'info, OpportunityMarket>>,
 
@@ -27,6 +28,12 @@ pub struct AddMarketOption<'info> {
     pub option: Box<Account<'info, OpportunityMarketOption>>,
 
     pub system_program: Program<'info, System>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
 pub fn add_market_option(ctx: Context<AddMarketOption>, option_id: u64) -> Result<()> {
@@ -47,17 +54,38 @@ pub fn add_market_option(ctx: Context<AddMarketOption>, option_id: u64) -> Resul
 
     // Initialize the option account
     let option = &mut ctx.accounts.option;
-    option.bump = ctx.bumps.option;
-    option.id = option_id;
-    option.created_at = current_timestamp;
-    option.creator = ctx.accounts.signer.key();
+    init_option(
+        option,
+        ctx.bumps.option,
+        option_id,
+        ctx.accounts.signer.key(),
+        current_timestamp,
+    );
 
-    emit_ts!(MarketOptionCreatedEvent {
-        option: option.key(),
-        market: market.key(),
-        signer: ctx.accounts.signer.key(),
-        id: option.id,
-    });
+    emit_ts!(
+        ctx,
+        MarketOptionCreatedEvent {
+            option: option.key(),
+            market: market.key(),
+            signer: ctx.accounts.signer.key(),
+            id: option.id,
+        }
+    );
 
     Ok(())
 }
+
+// Shared with create_market_with_options, which creates its option accounts by hand.
+pub fn init_option(
+    option: &mut OpportunityMarketOption,
+    bump: u8,
+    option_id: u64,
+    creator: Pubkey,
+    created_at: u64,
+) {
+    option.bump = bump;
+    option.schema_version = SCHEMA_VERSION;
+    option.id = option_id;
+    option.created_at = created_at;
+    option.creator = creator;
+}
@@ -1,12 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
 
-use crate::constants::SPONSOR_SEED;
+use crate::constants::{SCHEMA_VERSION, SPONSOR_SEED};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, RewardAddedEvent};
-use crate::state::{OpportunityMarket, OpportunityMarketSponsor};
+use crate::state::{OpportunityMarket, OpportunityMarketSponsor, PlatformConfig};
+use crate::transfer::transfer_checked;
 
 #[derive(Accounts)]
 pub struct AddReward<'info> {
@@ -16,8 +15,16 @@ pub struct AddReward<'info> {
     #[account(
         mut,
         constraint = market.resolved_at_timestamp.is_none() @ ErrorCode::WinnerAlreadySelected,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
-    pub market: Account<'info, OpportunityMarket>,
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    #[account(
+        address = market.platform @ ErrorCode::Unauthorized,
+        constraint = !platform_config.paused @ ErrorCode::PlatformPaused,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
 
     #[account(
         init_if_needed,
@@ -26,10 +33,10 @@ pub struct AddReward<'info> {
         seeds = [SPONSOR_SEED, sponsor.key().as_ref(), market.key().as_ref()],
         bump,
     )]
-    pub sponsor_account: Account<'info, OpportunityMarketSponsor>,
+    pub sponsor_account: Box<Account<'info, OpportunityMarketSponsor>>,
 
     #[account(address = market.mint)]
-    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
@@ -37,7 +44,7 @@ pub struct AddReward<'info> {
         token::authority = sponsor,
         token::token_program = token_program,
     )]
-    pub sponsor_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub sponsor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// Market-owned ATA holding all program-held tokens for this market.
     #[account(
@@ -46,13 +53,23 @@ pub struct AddReward<'info> {
         associated_token::authority = market,
         associated_token::token_program = token_program,
     )]
-    pub market_token_ata: InterfaceAccount<'info, TokenAccount>,
+    pub market_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
-pub fn add_reward(ctx: Context<AddReward>, amount: u64, lock: bool) -> Result<()> {
+pub fn add_reward<'info>(
+    ctx: Context<'info, AddReward<'info>>,
+    amount: u64,
+    lock: bool,
+) -> Result<()> {
     require!(amount > 0, ErrorCode::InsufficientRewardFunding);
 
     let market = &ctx.accounts.market;
@@ -69,6 +86,7 @@ pub fn add_reward(ctx: Context<AddReward>, amount: u64, lock: bool) -> Result<()
     // Initialize if newly created (sponsor is default)
     if sponsor_account.sponsor == Pubkey::default() {
         sponsor_account.bump = ctx.bumps.sponsor_account;
+        sponsor_account.schema_version = SCHEMA_VERSION;
         sponsor_account.sponsor = ctx.accounts.sponsor.key();
         sponsor_account.market = ctx.accounts.market.key();
     }
@@ -88,7 +106,8 @@ pub fn add_reward(ctx: Context<AddReward>, amount: u64, lock: bool) -> Result<()
                 to: ctx.accounts.market_token_ata.to_account_info(),
                 authority: ctx.accounts.sponsor.to_account_info(),
             },
-        ),
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         amount,
         ctx.accounts.token_mint.decimals,
     )?;
@@ -104,13 +123,16 @@ pub fn add_reward(ctx: Context<AddReward>, amount: u64, lock: bool) -> Result<()
         .checked_add(amount)
         .ok_or(ErrorCode::Overflow)?;
 
-    emit_ts!(RewardAddedEvent {
-        market: market.key(),
-        sponsor: ctx.accounts.sponsor.key(),
-        amount: amount,
-        total_reward_amount: market.reward_amount,
-        locked: sponsor_account.reward_locked,
-    });
+    emit_ts!(
+        ctx,
+        RewardAddedEvent {
+            market: market.key(),
+            sponsor: ctx.accounts.sponsor.key(),
+            amount: amount,
+            total_reward_amount: market.reward_amount,
+            locked: sponsor_account.reward_locked,
+        }
+    );
 
     Ok(())
 }
@@ -1,12 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
 
-use crate::constants::OPPORTUNITY_MARKET_SEED;
+use crate::constants::{OPPORTUNITY_MARKET_SEED, SCHEMA_VERSION};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, CreatorFeesClaimedEvent};
 use crate::state::OpportunityMarket;
+use crate::transfer::transfer_checked;
 
 #[derive(Accounts)]
 pub struct ClaimCreatorFees<'info> {
@@ -20,6 +19,7 @@ pub struct ClaimCreatorFees<'info> {
         constraint = market.reveal_ended @ ErrorCode::TimeWindowMismatch,
         constraint = market.collected_creator_fees > 0 @ ErrorCode::NoFeesToClaim,
         constraint = market.creator_fee_claimer == signer.key() @ ErrorCode::Unauthorized,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
     pub market: Box<Account<'info, OpportunityMarket>>,
 
@@ -42,9 +42,15 @@ pub struct ClaimCreatorFees<'info> {
     pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
-pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+pub fn claim_creator_fees<'info>(ctx: Context<'info, ClaimCreatorFees<'info>>) -> Result<()> {
     let fees = ctx.accounts.market.collected_creator_fees;
 
     let platform = ctx.accounts.market.platform;
@@ -69,20 +75,24 @@ pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
                 authority: ctx.accounts.market.to_account_info(),
             },
             market_seeds,
-        ),
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         fees,
         ctx.accounts.token_mint.decimals,
     )?;
 
     ctx.accounts.market.collected_creator_fees = 0;
 
-    emit_ts!(CreatorFeesClaimedEvent {
-        market: ctx.accounts.market.key(),
-        creator_fee_claimer: ctx.accounts.signer.key(),
-        mint: ctx.accounts.token_mint.key(),
-        destination: ctx.accounts.destination_token_account.key(),
-        amount: fees,
-    });
+    emit_ts!(
+        ctx,
+        CreatorFeesClaimedEvent {
+            market: ctx.accounts.market.key(),
+            creator_fee_claimer: ctx.accounts.signer.key(),
+            mint: ctx.accounts.token_mint.key(),
+            destination: ctx.accounts.destination_token_account.key(),
+            amount: fees,
+        }
+    );
 
     Ok(())
 }
@@ -1,12 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
 
-use crate::constants::OPPORTUNITY_MARKET_SEED;
+use crate::constants::{OPPORTUNITY_MARKET_SEED, SCHEMA_VERSION};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, FeesClaimedEvent};
 use crate::state::{OpportunityMarket, PlatformConfig};
+use crate::transfer::transfer_checked;
 
 #[derive(Accounts)]
 pub struct ClaimFees<'info> {
@@ -17,11 +16,13 @@ pub struct ClaimFees<'info> {
         seeds = [OPPORTUNITY_MARKET_SEED, platform_config.key().as_ref(), market.creator.as_ref(), &market.index.to_le_bytes()],
         bump = market.bump,
         constraint = market.collected_platform_fees > 0 @ ErrorCode::NoFeesToClaim,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
     pub market: Box<Account<'info, OpportunityMarket>>,
 
     #[account(
         constraint = platform_config.fee_claim_authority == signer.key() @ ErrorCode::Unauthorized,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
     pub platform_config: Box<Account<'info, PlatformConfig>>,
 
@@ -44,9 +45,15 @@ pub struct ClaimFees<'info> {
     pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
-pub fn claim_fees(ctx: Context<ClaimFees>) -> Result<()> {
+pub fn claim_fees<'info>(ctx: Context<'info, ClaimFees<'info>>) -> Result<()> {
     let fees = ctx.accounts.market.collected_platform_fees;
 
     let platform = ctx.accounts.market.platform;
@@ -71,20 +78,24 @@ pub fn claim_fees(ctx: Context<ClaimFees>) -> Result<()> {
                 authority: ctx.accounts.market.to_account_info(),
             },
             market_seeds,
-        ),
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         fees,
         ctx.accounts.token_mint.decimals,
     )?;
 
     ctx.accounts.market.collected_platform_fees = 0;
 
-    emit_ts!(FeesClaimedEvent {
-        market: ctx.accounts.market.key(),
-        platform: ctx.accounts.platform_config.key(),
-        mint: ctx.accounts.token_mint.key(),
-        destination: ctx.accounts.destination_token_account.key(),
-        amount: fees,
-    });
+    emit_ts!(
+        ctx,
+        FeesClaimedEvent {
+            market: ctx.accounts.market.key(),
+            platform: ctx.accounts.platform_config.key(),
+            mint: ctx.accounts.token_mint.key(),
+            destination: ctx.accounts.destination_token_account.key(),
+            amount: fees,
+        }
+    );
 
     Ok(())
 }
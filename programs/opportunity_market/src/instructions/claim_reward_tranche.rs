@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::constants::{OPPORTUNITY_MARKET_SEED, OPTION_SEED, SCHEMA_VERSION, STAKE_ACCOUNT_SEED};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, RewardTrancheClaimedEvent};
+use crate::instructions::close_stake_account::winning_reward;
+use crate::state::{OpportunityMarket, OpportunityMarketOption, PlatformConfig, StakeAccount};
+use crate::transfer::transfer_checked;
+
+// Pays out the tranches of a winner's reward that have vested so far. Whatever is left is paid
+// by close_stake_account once the last tranche has vested.
+#[derive(Accounts)]
+#[instruction(option_id: u64, stake_account_id: u32)]
+pub struct ClaimRewardTranche<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [OPPORTUNITY_MARKET_SEED, market.platform.as_ref(), market.creator.as_ref(), &market.index.to_le_bytes()],
+        bump = market.bump,
+        constraint = market.reveal_ended @ ErrorCode::RevealPeriodNotOver,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    #[account(
+        address = market.platform @ ErrorCode::Unauthorized,
+        constraint = !platform_config.paused @ ErrorCode::PlatformPaused,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_ACCOUNT_SEED, owner.key().as_ref(), market.key().as_ref(), &stake_account_id.to_le_bytes()],
+        bump = stake_account.bump,
+        constraint = stake_account.revealed_option == Some(option_id) @ ErrorCode::InvalidOptionId,
+        constraint = stake_account.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub stake_account: Box<Account<'info, StakeAccount>>,
+
+    #[account(
+        seeds = [OPTION_SEED, market.key().as_ref(), &option_id.to_le_bytes()],
+        bump = option.bump,
+    )]
+    pub option: Box<Account<'info, OpportunityMarketOption>>,
+
+    #[account(address = market.mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program,
+    )]
+    pub market_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn claim_reward_tranche<'info>(
+    ctx: Context<'info, ClaimRewardTranche<'info>>,
+    _option_id: u64,
+    _stake_account_id: u32,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    let market = &ctx.accounts.market;
+
+    let reward = winning_reward(
+        &ctx.accounts.stake_account,
+        market,
+        Some(&ctx.accounts.option),
+    )?
+    .ok_or(ErrorCode::NoRewardToClaim)?;
+    let vested = market.vested_reward(reward, current_time)?;
+    let amount = vested
+        .checked_sub(ctx.accounts.stake_account.reward_claimed)
+        .ok_or(ErrorCode::Overflow)?;
+    require!(amount > 0, ErrorCode::NoRewardToClaim);
+
+    let platform = market.platform;
+    let creator = market.creator;
+    let index_bytes = market.index.to_le_bytes();
+    let market_bump = market.bump;
+    let market_seeds: &[&[&[u8]]] = &[&[
+        OPPORTUNITY_MARKET_SEED,
+        platform.as_ref(),
+        creator.as_ref(),
+        &index_bytes,
+        &[market_bump],
+    ]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.key(),
+            TransferChecked {
+                from: ctx.accounts.market_token_ata.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.market.to_account_info(),
+            },
+            market_seeds,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    ctx.accounts.stake_account.reward_claimed = vested;
+
+    emit_ts!(
+        ctx,
+        RewardTrancheClaimedEvent {
+            owner: ctx.accounts.owner.key(),
+            market: ctx.accounts.market.key(),
+            stake_account: ctx.accounts.stake_account.key(),
+            stake_account_id: ctx.accounts.stake_account.id,
+            amount: amount,
+            reward_claimed: vested,
+            reward_total: reward,
+        }
+    );
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{OPPORTUNITY_MARKET_SEED, OPTION_SEED};
+use crate::constants::{OPPORTUNITY_MARKET_SEED, OPTION_SEED, SCHEMA_VERSION};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, OptionClosedEvent};
 use crate::state::{OpportunityMarket, OpportunityMarketOption};
@@ -20,8 +20,9 @@ pub struct CloseOptionAccount<'info> {
         seeds = [OPPORTUNITY_MARKET_SEED, market.platform.as_ref(), market.creator.as_ref(), &market.index.to_le_bytes()],
         bump = market.bump,
         constraint = market.reveal_ended @ ErrorCode::RevealPeriodNotOver,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
-    pub market: Account<'info, OpportunityMarket>,
+    pub market: Box<Account<'info, OpportunityMarket>>,
 
     #[account(
         mut,
@@ -31,9 +32,15 @@ pub struct CloseOptionAccount<'info> {
         constraint = option.total_staked == 0 || option.reward_bp.is_none() @ ErrorCode::OptionStillNeeded,
         has_one = creator @ ErrorCode::CreatorMismatch,
     )]
-    pub option: Account<'info, OpportunityMarketOption>,
+    pub option: Box<Account<'info, OpportunityMarketOption>>,
 
     pub system_program: Program<'info, System>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
 pub fn close_option_account(ctx: Context<CloseOptionAccount>, option_id: u64) -> Result<()> {
@@ -53,12 +60,15 @@ pub fn close_option_account(ctx: Context<CloseOptionAccount>, option_id: u64) ->
     let expired = !resolved && current_time >= select_deadline;
     require!(resolved || expired, ErrorCode::MarketNotResolved);
 
-    emit_ts!(OptionClosedEvent {
-        option: ctx.accounts.option.key(),
-        option_id: option_id,
-        signer: ctx.accounts.signer.key(),
-        creator: ctx.accounts.creator.key(),
-        market: ctx.accounts.market.key(),
-    });
+    emit_ts!(
+        ctx,
+        OptionClosedEvent {
+            option: ctx.accounts.option.key(),
+            option_id: option_id,
+            signer: ctx.accounts.signer.key(),
+            creator: ctx.accounts.creator.key(),
+            market: ctx.accounts.market.key(),
+        }
+    );
     Ok(())
 }
@@ -1,15 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
 
-use crate::constants::{OPPORTUNITY_MARKET_SEED, OPTION_SEED, STAKE_ACCOUNT_SEED};
+use crate::constants::{
+    DEPOSIT_RECEIPT_SEED, OPPORTUNITY_MARKET_SEED, OPTION_SEED, SCHEMA_VERSION, STAKE_ACCOUNT_SEED,
+    USER_PORTFOLIO_SEED,
+};
 use crate::error::ErrorCode;
-use crate::events::{emit_ts, StakeAccountClosedEvent};
-use crate::state::{OpportunityMarket, OpportunityMarketOption, StakeAccount};
+use crate::events::{emit_ts, DepositReceiptRecordedEvent, StakeAccountClosedEvent};
+use crate::state::{
+    AuditInstruction, AuditLog, DepositReceipt, OpportunityMarket, OpportunityMarketOption,
+    PlatformConfig, ReceiptKind, StakeAccount, UserPortfolio,
+};
+use crate::transfer::transfer_checked;
 
 #[derive(Accounts)]
-#[instruction(option_id: u64, stake_account_id: u32)]
+#[instruction(option_id: u64, stake_account_id: u32, client_id: Option<[u8; 32]>)]
 pub struct CloseStakeAccount<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -18,9 +23,17 @@ pub struct CloseStakeAccount<'info> {
         mut,
         seeds = [OPPORTUNITY_MARKET_SEED, market.platform.as_ref(), market.creator.as_ref(), &market.index.to_le_bytes()],
         bump = market.bump,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
     pub market: Box<Account<'info, OpportunityMarket>>,
 
+    #[account(
+        address = market.platform @ ErrorCode::Unauthorized,
+        constraint = !platform_config.paused @ ErrorCode::PlatformPaused,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
     #[account(
         mut,
         seeds = [STAKE_ACCOUNT_SEED, owner.key().as_ref(), market.key().as_ref(), &stake_account_id.to_le_bytes()],
@@ -28,9 +41,28 @@ pub struct CloseStakeAccount<'info> {
         close = owner,
         // Staked tokens must have been returned before closing
         constraint = stake_account.unstaked_at_timestamp.is_some() @ ErrorCode::InvalidAccountState,
+        constraint = stake_account.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
     pub stake_account: Box<Account<'info, StakeAccount>>,
 
+    /// Optional receipt for integrators reconciling against their own ledger; needs client_id.
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + DepositReceipt::INIT_SPACE,
+        seeds = [DEPOSIT_RECEIPT_SEED, owner.key().as_ref(), &client_id.unwrap_or_default()],
+        bump,
+    )]
+    pub deposit_receipt: Option<Box<Account<'info, DepositReceipt>>>,
+
+    /// Required if the position was counted on the owner's portfolio.
+    #[account(
+        mut,
+        seeds = [USER_PORTFOLIO_SEED, owner.key().as_ref()],
+        bump = user_portfolio.bump,
+    )]
+    pub user_portfolio: Option<Box<Account<'info, UserPortfolio>>>,
+
     /// CHECK: May be a closed account for non-winning options. PDA is validated in handler.
     #[account(mut,
         seeds = [OPTION_SEED, market.key().as_ref(), &option_id.to_le_bytes()],
@@ -61,12 +93,24 @@ pub struct CloseStakeAccount<'info> {
 
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+
+    /// Required when the market keeps an audit log.
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
 pub fn close_stake_account<'info>(
     ctx: Context<'info, CloseStakeAccount<'info>>,
     option_id: u64,
     _stake_account_id: u32,
+    client_id: Option<[u8; 32]>,
+    sequence: Option<u64>,
 ) -> Result<()> {
     let clock = Clock::get()?;
     let current_time = clock.unix_timestamp as u64;
@@ -81,7 +125,8 @@ pub fn close_stake_account<'info>(
         .ok_or(ErrorCode::Overflow)?;
 
     let resolved = ctx.accounts.market.resolved_at_timestamp.is_some();
-    let expired = !resolved && current_time >= select_deadline;
+    let expired =
+        !resolved && (current_time >= select_deadline || ctx.accounts.market.is_void(current_time));
     require!(resolved || expired, ErrorCode::MarketNotResolved);
 
     // Load option data if account is still open; a closed non-winning option has
@@ -96,7 +141,10 @@ pub fn close_stake_account<'info>(
         None
     };
 
-    let payout: u64 = if resolved {
+    let payout: u64 = if ctx.accounts.stake_account.migrated {
+        // The stake moved to the successor market and its fees stay here, so nothing is owed.
+        0
+    } else if resolved {
         // Reveal period must be over
         require!(
             ctx.accounts.market.reveal_ended,
@@ -114,9 +162,10 @@ pub fn close_stake_account<'info>(
             &ctx.accounts.stake_account,
             &ctx.accounts.market,
             option_acc.as_ref(),
+            current_time,
         )?
     } else {
-        // Market expired: refund reward_pool_fee + creator_fee
+        // Market expired or void: refund reward_pool_fee + creator_fee
         let collected_fees = ctx.accounts.stake_account.collected_fees;
         ctx.accounts.market.deduct_stake_fees(&collected_fees)?
     };
@@ -144,7 +193,8 @@ pub fn close_stake_account<'info>(
                     authority: ctx.accounts.market.to_account_info(),
                 },
                 market_seeds,
-            ),
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
             payout,
             ctx.accounts.token_mint.decimals,
         )?;
@@ -167,18 +217,70 @@ pub fn close_stake_account<'info>(
         opt.exit(ctx.program_id)?;
     }
 
-    emit_ts!(StakeAccountClosedEvent {
-        owner: ctx.accounts.owner.key(),
-        market: ctx.accounts.market.key(),
-        stake_account: stake_account.key(),
-        stake_account_id: stake_account.id,
-        option_id: option_id,
-        stake_amount: stake_account.amount,
-        reward_amount: if resolved { payout } else { 0 },
-        staked_at_timestamp: staked_at_timestamp,
-        stake_end_timestamp: stake_end_timestamp,
-        score: score,
-    });
+    ctx.accounts.stake_account.close_in_portfolio(
+        ctx.accounts.user_portfolio.as_deref_mut().map(|p| &mut **p),
+        clock.slot,
+    )?;
+
+    if let Some(receipt) = ctx.accounts.deposit_receipt.as_mut() {
+        let Some(client_id) = client_id else {
+            return err!(ErrorCode::InvalidParameters);
+        };
+        let sequence = sequence.unwrap_or_default();
+        receipt.bump = ctx
+            .bumps
+            .deposit_receipt
+            .ok_or(ErrorCode::InvalidAccountState)?;
+        receipt.schema_version = SCHEMA_VERSION;
+        receipt.owner = ctx.accounts.owner.key();
+        receipt.market = ctx.accounts.market.key();
+        receipt.stake_account = stake_account.key();
+        receipt.stake_account_id = stake_account.id;
+        receipt.kind = ReceiptKind::Claim;
+        receipt.client_id = client_id;
+        receipt.sequence = sequence;
+        receipt.amount = payout;
+        receipt.created_at = current_time;
+
+        emit_ts!(
+            ctx,
+            DepositReceiptRecordedEvent {
+                receipt: receipt.key(),
+                owner: receipt.owner,
+                market: receipt.market,
+                stake_account: receipt.stake_account,
+                stake_account_id: receipt.stake_account_id,
+                kind: ReceiptKind::Claim,
+                client_id: client_id,
+                sequence: sequence,
+                amount: payout,
+            }
+        );
+    }
+
+    ctx.accounts.market.record_audit(
+        ctx.accounts.market.key(),
+        ctx.accounts.audit_log.as_ref(),
+        ctx.accounts.owner.key(),
+        AuditInstruction::CloseStakeAccount,
+        0,
+    )?;
+
+    emit_ts!(
+        ctx,
+        StakeAccountClosedEvent {
+            owner: ctx.accounts.owner.key(),
+            market: ctx.accounts.market.key(),
+            stake_account: stake_account.key(),
+            stake_account_id: stake_account.id,
+            option_id: option_id,
+            stake_amount: stake_account.amount,
+            reward_amount: if resolved { payout } else { 0 },
+            staked_at_timestamp: staked_at_timestamp,
+            stake_end_timestamp: stake_end_timestamp,
+            score: score,
+        }
+    );
 
     Ok(())
 }
@@ -187,38 +289,64 @@ fn compute_winning_payout(
     stake_account: &Account<StakeAccount>,
     market: &Account<OpportunityMarket>,
     option: Option<&Account<OpportunityMarketOption>>,
+    current_time: u64,
 ) -> Result<u64> {
-    let option = match option {
+    let reward = match winning_reward(stake_account, market, option)? {
+        // A finalized stake without a reward was on a losing option.
+        None if stake_account.score.is_some() => return market.loser_rebate(stake_account.amount),
         None => return Ok(0),
+        Some(r) => r,
+    };
+
+    // Tranches already paid by claim_reward_tranche are deducted; the rest must have vested.
+    require!(
+        market.vested_reward(reward, current_time)? == reward,
+        ErrorCode::RewardNotVested
+    );
+    let reward = reward
+        .checked_sub(stake_account.reward_claimed)
+        .ok_or(ErrorCode::Overflow)?;
+
+    let fees = stake_account.collected_fees;
+    let fees_refund = fees
+        .reward_pool_fee
+        .checked_add(fees.creator_fee)
+        .ok_or(ErrorCode::Overflow)?;
+
+    reward
+        .checked_add(fees_refund)
+        .ok_or(ErrorCode::Overflow.into())
+}
+
+/// The stake's full share of the reward, or None if it isn't a scored stake on a winning option.
+pub(crate) fn winning_reward(
+    stake_account: &StakeAccount,
+    market: &OpportunityMarket,
+    option: Option<&Account<OpportunityMarketOption>>,
+) -> Result<Option<u64>> {
+    let option = match option {
+        None => return Ok(None),
         Some(o) => o,
     };
 
     if option.reward_bp.is_none() {
-        return Ok(0);
+        return Ok(None);
     }
 
     if stake_account.score.is_none() {
-        return Ok(0);
+        return Ok(None);
     }
 
     let user_score = stake_account.score.ok_or(ErrorCode::NotRevealed)?;
     let total_score = option.total_score;
 
     let reward = (user_score as u128)
-        .checked_mul(market.reward_amount as u128)
+        .checked_mul(market.winner_reward_pool()? as u128)
         .ok_or(ErrorCode::Overflow)?
         .checked_mul(option.reward_bp.unwrap_or(0) as u128)
         .ok_or(ErrorCode::Overflow)?
         .checked_div(total_score.checked_mul(10_000).ok_or(ErrorCode::Overflow)?)
         .ok_or(ErrorCode::Overflow)? as u64;
 
-    let fees = stake_account.collected_fees;
-    let fees_refund = fees
-        .reward_pool_fee
-        .checked_add(fees.creator_fee)
-        .ok_or(ErrorCode::Overflow)?;
-
-    reward
-        .checked_add(fees_refund)
-        .ok_or(ErrorCode::Overflow.into())
+    Ok(Some(reward))
 }
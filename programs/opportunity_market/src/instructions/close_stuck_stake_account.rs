@@ -1,12 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
 
-use crate::constants::{OPPORTUNITY_MARKET_SEED, STAKE_ACCOUNT_SEED};
+use crate::constants::{
+    OPPORTUNITY_MARKET_SEED, SCHEMA_VERSION, STAKE_ACCOUNT_SEED, USER_PORTFOLIO_SEED,
+};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, StuckStakeClosedEvent};
-use crate::state::{OpportunityMarket, StakeAccount};
+use crate::state::{OpportunityMarket, StakeAccount, UserPortfolio};
+use crate::transfer::transfer_checked;
 
 #[derive(Accounts)]
 #[instruction(stake_account_id: u32)]
@@ -15,8 +16,10 @@ pub struct CloseStuckStakeAccount<'info> {
     pub signer: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [OPPORTUNITY_MARKET_SEED, market.platform.as_ref(), market.creator.as_ref(), &market.index.to_le_bytes()],
         bump = market.bump,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
     pub market: Box<Account<'info, OpportunityMarket>>,
 
@@ -26,9 +29,18 @@ pub struct CloseStuckStakeAccount<'info> {
         seeds = [STAKE_ACCOUNT_SEED, signer.key().as_ref(), market.key().as_ref(), &stake_account_id.to_le_bytes()],
         bump = stake_account.bump,
         constraint = stake_account.owner == signer.key() @ ErrorCode::Unauthorized,
+        constraint = stake_account.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
     pub stake_account: Box<Account<'info, StakeAccount>>,
 
+    /// Required if the position was counted on the owner's portfolio.
+    #[account(
+        mut,
+        seeds = [USER_PORTFOLIO_SEED, signer.key().as_ref()],
+        bump = user_portfolio.bump,
+    )]
+    pub user_portfolio: Option<Box<Account<'info, UserPortfolio>>>,
+
     #[account(address = market.mint)]
     pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
@@ -51,10 +63,16 @@ pub struct CloseStuckStakeAccount<'info> {
 
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
-pub fn close_stuck_stake_account(
-    ctx: Context<CloseStuckStakeAccount>,
+pub fn close_stuck_stake_account<'info>(
+    ctx: Context<'info, CloseStuckStakeAccount<'info>>,
     stake_account_id: u32,
 ) -> Result<()> {
     let stake_account = &ctx.accounts.stake_account;
@@ -65,8 +83,25 @@ pub fn close_stuck_stake_account(
         ErrorCode::StakeNotStuck
     );
 
+    let clock = Clock::get()?;
+    ctx.accounts.stake_account.close_in_portfolio(
+        ctx.accounts.user_portfolio.as_deref_mut().map(|p| &mut **p),
+        clock.slot,
+    )?;
+
+    // Stakes are counted when queued; the count is fixed once staking is over.
+    let amount = ctx.accounts.stake_account.amount;
+    if ctx
+        .accounts
+        .market
+        .stake_end_timestamp
+        .is_some_and(|end| (clock.unix_timestamp as u64) <= end)
+    {
+        ctx.accounts.market.remove_participant(amount);
+    }
+
+    let stake_account = &ctx.accounts.stake_account;
     let market = &ctx.accounts.market;
-    let amount = stake_account.amount;
     let total_refund = amount
         .checked_add(stake_account.collected_fees.total()?)
         .ok_or(ErrorCode::Overflow)?;
@@ -94,22 +129,26 @@ pub fn close_stuck_stake_account(
                     authority: ctx.accounts.market.to_account_info(),
                 },
                 market_seeds,
-            ),
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
             total_refund,
             ctx.accounts.token_mint.decimals,
         )?;
     }
 
-    emit_ts!(StuckStakeClosedEvent {
-        owner: ctx.accounts.signer.key(),
-        market: market.key(),
-        stake_account: ctx.accounts.stake_account.key(),
-        stake_account_id: stake_account_id,
-        refunded_amount: amount,
-        refunded_platform_fee: stake_account.collected_fees.platform_fee,
-        refunded_reward_pool_fee: stake_account.collected_fees.reward_pool_fee,
-        refunded_creator_fee: stake_account.collected_fees.creator_fee,
-    });
+    emit_ts!(
+        ctx,
+        StuckStakeClosedEvent {
+            owner: ctx.accounts.signer.key(),
+            market: market.key(),
+            stake_account: ctx.accounts.stake_account.key(),
+            stake_account_id: stake_account_id,
+            refunded_amount: amount,
+            refunded_platform_fee: stake_account.collected_fees.platform_fee,
+            refunded_reward_pool_fee: stake_account.collected_fees.reward_pool_fee,
+            refunded_creator_fee: stake_account.collected_fees.creator_fee,
+        }
+    );
 
     Ok(())
 }
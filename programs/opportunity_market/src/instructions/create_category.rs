@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CATEGORY_SEED, MAX_CATEGORY_NAME_LEN, SCHEMA_VERSION};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, CategoryCreatedEvent};
+use crate::state::{Category, PlatformConfig};
+
+#[derive(Accounts)]
+#[instruction(id: u32)]
+pub struct CreateCategory<'info> {
+    #[account(mut)]
+    pub update_authority: Signer<'info>,
+
+    #[account(
+        has_one = update_authority @ ErrorCode::Unauthorized,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        init,
+        payer = update_authority,
+        space = 8 + Category::INIT_SPACE,
+        seeds = [CATEGORY_SEED, platform_config.key().as_ref(), &id.to_le_bytes()],
+        bump,
+    )]
+    pub category: Box<Account<'info, Category>>,
+
+    pub system_program: Program<'info, System>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn create_category(ctx: Context<CreateCategory>, id: u32, name: String) -> Result<()> {
+    require!(
+        !name.is_empty() && name.len() <= MAX_CATEGORY_NAME_LEN,
+        ErrorCode::InvalidParameters
+    );
+
+    let category = &mut ctx.accounts.category;
+    category.schema_version = SCHEMA_VERSION;
+    category.bump = ctx.bumps.category;
+    category.platform = ctx.accounts.platform_config.key();
+    category.id = id;
+    category.name = name.clone();
+    category.market_count = 0;
+
+    emit_ts!(
+        ctx,
+        CategoryCreatedEvent {
+            platform: category.platform,
+            category: category.key(),
+            id: id,
+            name: name,
+        }
+    );
+
+    Ok(())
+}
@@ -2,11 +2,14 @@ use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::constants::{ALLOWED_MINT_SEED, MAX_EARLINESS_MULTIPLIER, OPPORTUNITY_MARKET_SEED};
+use crate::constants::{
+    ALLOWED_MINT_SEED, BOOTSTRAP_STATE_SEED, MAX_EARLINESS_MULTIPLIER, OPPORTUNITY_MARKET_SEED,
+    SCHEMA_VERSION,
+};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, MarketCreatedEvent};
 use crate::score::PRECISION;
-use crate::state::{AllowedMint, OpportunityMarket, PlatformConfig};
+use crate::state::{AllowedMint, BootstrapState, OpportunityMarket, PlatformConfig};
 
 #[derive(Accounts)]
 #[instruction(market_index: u64)]
@@ -14,6 +17,10 @@ pub struct CreateMarket<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    #[account(
+        constraint = !platform_config.paused @ ErrorCode::PlatformPaused,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
     pub platform_config: Box<Account<'info, PlatformConfig>>,
 
     pub token_mint: Box<InterfaceAccount<'info, Mint>>,
@@ -45,13 +52,52 @@ pub struct CreateMarket<'info> {
     )]
     pub allowed_mint: Box<Account<'info, AllowedMint>>,
 
+    /// Markets can't be staked on until every computation definition exists.
+    #[account(
+        seeds = [BOOTSTRAP_STATE_SEED],
+        bump = bootstrap_state.bump,
+        constraint = bootstrap_state.comp_defs_initialized @ ErrorCode::BootstrapIncomplete,
+    )]
+    pub bootstrap_state: Box<Account<'info, BootstrapState>>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
 pub fn create_market(
-    ctx: Context<CreateMarket>,
+    mut ctx: Context<CreateMarket>,
+    market_index: u64,
+    market_authority: Pubkey,
+    allow_unstaking_early: bool,
+    authorized_reader_pubkey: [u8; 32],
+    earliness_cutoff_seconds: u64,
+    earliness_multiplier: u16,
+    min_stake_amount: u64,
+    creator_fee_claimer: Pubkey,
+) -> Result<()> {
+    init_market(
+        &mut ctx,
+        market_index,
+        market_authority,
+        allow_unstaking_early,
+        authorized_reader_pubkey,
+        earliness_cutoff_seconds,
+        earliness_multiplier,
+        min_stake_amount,
+        creator_fee_claimer,
+    )
+}
+
+/// Shared by create_market and create_market_with_options.
+pub(crate) fn init_market(
+    ctx: &mut Context<CreateMarket>,
     market_index: u64,
     market_authority: Pubkey,
     allow_unstaking_early: bool,
@@ -77,6 +123,7 @@ pub fn create_market(
     let market = &mut ctx.accounts.market;
     let mint = ctx.accounts.token_mint.key();
     market.bump = ctx.bumps.market;
+    market.schema_version = SCHEMA_VERSION;
     market.creator = creator_key;
     market.index = market_index;
     market.platform = platform_key;
@@ -91,24 +138,44 @@ pub fn create_market(
     market.market_resolution_deadline_seconds = market_resolution_deadline_seconds;
     market.reveal_period_seconds = reveal_period_seconds;
     market.min_stake_amount = min_stake_amount;
+    market.reward_tranches = 1;
+    market.reward_tranche_interval_seconds = 0;
+    market.loser_rebate_bp = 0;
+    market.losing_stake_total = 0;
+    market.successor_market = None;
+    market.participants_restricted = false;
+    market.gate_mint = None;
+    market.min_total_stake = 0;
+    market.min_participants = 0;
+    market.total_stake = 0;
+    market.participant_count = 0;
+    market.crank_budget_lamports = 0;
+    market.crank_bounty_lamports = 0;
+    market.mpc_fees_reimbursed = 0;
+    market.category = None;
+    market.emergency_voided = false;
+    market.audit_log_enabled = false;
 
-    emit_ts!(MarketCreatedEvent {
-        market: market.key(),
-        creator: creator_key,
-        platform: platform_key,
-        index: market_index,
-        mint: mint,
-        market_authority: market_authority,
-        authorized_reader_pubkey: authorized_reader_pubkey,
-        allow_unstaking_early: allow_unstaking_early,
-        earliness_cutoff_seconds: earliness_cutoff_seconds,
-        earliness_multiplier: earliness_multiplier,
-        min_stake_amount: min_stake_amount,
-        fee_rates: ctx.accounts.platform_config.fee_rates,
-        creator_fee_claimer: creator_fee_claimer,
-        market_resolution_deadline_seconds: market_resolution_deadline_seconds,
-        reveal_period_seconds: reveal_period_seconds,
-    });
+    emit_ts!(
+        ctx,
+        MarketCreatedEvent {
+            market: market.key(),
+            creator: creator_key,
+            platform: platform_key,
+            index: market_index,
+            mint: mint,
+            market_authority: market_authority,
+            authorized_reader_pubkey: authorized_reader_pubkey,
+            allow_unstaking_early: allow_unstaking_early,
+            earliness_cutoff_seconds: earliness_cutoff_seconds,
+            earliness_multiplier: earliness_multiplier,
+            min_stake_amount: min_stake_amount,
+            fee_rates: ctx.accounts.platform_config.fee_rates,
+            creator_fee_claimer: creator_fee_claimer,
+            market_resolution_deadline_seconds: market_resolution_deadline_seconds,
+            reveal_period_seconds: reveal_period_seconds,
+        }
+    );
 
     Ok(())
 }
@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+
+use crate::constants::{MAX_OPTIONS_PER_CREATE, OPTION_SEED};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, MarketOptionCreatedEvent};
+use crate::instructions::add_market_option::init_option;
+use crate::instructions::create_market::{init_market, CreateMarket};
+use crate::state::OpportunityMarketOption;
+
+// Creates the market and its initial options in one transaction. Option PDAs are passed as
+// remaining accounts, in the same order as `option_ids`.
+pub fn create_market_with_options<'info>(
+    mut ctx: Context<'info, CreateMarket<'info>>,
+    market_index: u64,
+    market_authority: Pubkey,
+    allow_unstaking_early: bool,
+    authorized_reader_pubkey: [u8; 32],
+    earliness_cutoff_seconds: u64,
+    earliness_multiplier: u16,
+    min_stake_amount: u64,
+    creator_fee_claimer: Pubkey,
+    option_ids: Vec<u64>,
+) -> Result<()> {
+    require!(
+        !option_ids.is_empty() && option_ids.len() <= MAX_OPTIONS_PER_CREATE,
+        ErrorCode::InvalidParameters
+    );
+    require!(
+        ctx.remaining_accounts.len() == option_ids.len(),
+        ErrorCode::InvalidParameters
+    );
+
+    init_market(
+        &mut ctx,
+        market_index,
+        market_authority,
+        allow_unstaking_early,
+        authorized_reader_pubkey,
+        earliness_cutoff_seconds,
+        earliness_multiplier,
+        min_stake_amount,
+        creator_fee_claimer,
+    )?;
+
+    let market_key = ctx.accounts.market.key();
+    let creator_key = ctx.accounts.creator.key();
+    let current_timestamp = Clock::get()?.unix_timestamp as u64;
+    let space = 8 + OpportunityMarketOption::INIT_SPACE;
+    let rent = Rent::get()?;
+
+    for (option_id, option_info) in option_ids.iter().zip(ctx.remaining_accounts) {
+        let id_bytes = option_id.to_le_bytes();
+        let (option_key, bump) = Pubkey::find_program_address(
+            &[OPTION_SEED, market_key.as_ref(), &id_bytes],
+            &crate::ID,
+        );
+        require_keys_eq!(option_info.key(), option_key, ErrorCode::InvalidParameters);
+
+        // Fails if the option already exists, which also rejects duplicate IDs.
+        create_option_account(
+            &ctx.accounts.creator.to_account_info(),
+            option_info,
+            &ctx.accounts.system_program.key(),
+            &[OPTION_SEED, market_key.as_ref(), &id_bytes, &[bump]],
+            space,
+            &rent,
+        )?;
+
+        let mut option = OpportunityMarketOption::default();
+        init_option(
+            &mut option,
+            bump,
+            *option_id,
+            creator_key,
+            current_timestamp,
+        );
+        option.try_serialize(&mut &mut option_info.try_borrow_mut_data()?[..])?;
+
+        emit_ts!(
+            ctx,
+            MarketOptionCreatedEvent {
+                option: option_key,
+                market: market_key,
+                signer: creator_key,
+                id: *option_id,
+            }
+        );
+    }
+
+    ctx.accounts.market.total_options = option_ids.len() as u64;
+
+    Ok(())
+}
+
+// Same as the account creation of Anchor's `init`: an option PDA that already holds lamports
+// can't be created with `create_account`, so it is topped up, allocated and assigned instead.
+fn create_option_account<'info>(
+    payer: &AccountInfo<'info>,
+    option_info: &AccountInfo<'info>,
+    system_program: &Pubkey,
+    seeds: &[&[u8]],
+    space: usize,
+    rent: &Rent,
+) -> Result<()> {
+    let current_lamports = option_info.lamports();
+    if current_lamports == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                *system_program,
+                CreateAccount {
+                    from: payer.clone(),
+                    to: option_info.clone(),
+                },
+                &[seeds],
+            ),
+            rent.minimum_balance(space),
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let required_lamports = rent
+        .minimum_balance(space)
+        .max(1)
+        .saturating_sub(current_lamports);
+    if required_lamports > 0 {
+        transfer(
+            CpiContext::new(
+                *system_program,
+                Transfer {
+                    from: payer.clone(),
+                    to: option_info.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            *system_program,
+            Allocate {
+                account_to_allocate: option_info.clone(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            *system_program,
+            Assign {
+                account_to_assign: option_info.clone(),
+            },
+            &[seeds],
+        ),
+        &crate::ID,
+    )
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EMERGENCY_RESOLVE_DELAY_SECONDS, SCHEMA_VERSION};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, MarketEmergencyResolvedEvent};
+use crate::state::{AuditInstruction, AuditLog, OpportunityMarket, PlatformConfig};
+
+// Escape hatch for markets that can't be resolved normally, e.g. when the market authority
+// is gone. The market becomes void, so stakers and sponsors are refunded as if it expired.
+// An opened market waits EMERGENCY_RESOLVE_DELAY_SECONDS past stake end; a market that never
+// opened holds no stakes and can be voided at once.
+#[derive(Accounts)]
+pub struct EmergencyResolve<'info> {
+    pub update_authority: Signer<'info>,
+
+    #[account(
+        has_one = update_authority @ ErrorCode::Unauthorized,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        constraint = market.platform == platform_config.key() @ ErrorCode::Unauthorized,
+        constraint = market.resolved_at_timestamp.is_none() @ ErrorCode::WinnerAlreadySelected,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    /// Required when the market keeps an audit log.
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn emergency_resolve(
+    ctx: Context<EmergencyResolve>,
+    justification_hash: [u8; 32],
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    // An open market can only be voided some time after staking ends, never mid-stake. An
+    // unopened one has nothing at stake, so it isn't delayed.
+    if let Some(stake_end) = market.stake_end_timestamp {
+        let current_timestamp = Clock::get()?.unix_timestamp as u64;
+        let unlocked_at = stake_end
+            .checked_add(EMERGENCY_RESOLVE_DELAY_SECONDS)
+            .ok_or(ErrorCode::Overflow)?;
+        require!(
+            current_timestamp >= unlocked_at,
+            ErrorCode::TimeWindowMismatch
+        );
+    }
+
+    market.emergency_voided = true;
+
+    market.record_audit(
+        market.key(),
+        ctx.accounts.audit_log.as_ref(),
+        ctx.accounts.update_authority.key(),
+        AuditInstruction::EmergencyResolve,
+        0,
+    )?;
+
+    emit_ts!(
+        ctx,
+        MarketEmergencyResolvedEvent {
+            market: market.key(),
+            update_authority: ctx.accounts.update_authority.key(),
+            justification_hash: justification_hash,
+        }
+    );
+
+    Ok(())
+}
@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{MARKET_RESULT_SEED, SCHEMA_VERSION};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, RevealPeriodEndedEvent};
-use crate::state::{OpportunityMarket, PlatformConfig};
+use crate::state::{
+    AuditInstruction, AuditLog, MarketResult, OpportunityMarket, OptionResult, PlatformConfig,
+};
 
+// Remaining accounts: the winning options, in the order stored on the market result.
 #[derive(Accounts)]
 pub struct EndRevealPeriod<'info> {
     pub signer: Signer<'info>,
@@ -11,14 +15,35 @@ pub struct EndRevealPeriod<'info> {
     #[account(
         mut,
         constraint = !market.reveal_ended @ ErrorCode::RevealPeriodEnded,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
-    pub market: Account<'info, OpportunityMarket>,
+    pub market: Box<Account<'info, OpportunityMarket>>,
 
-    #[account(address = market.platform @ ErrorCode::Unauthorized)]
-    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        address = market.platform @ ErrorCode::Unauthorized,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_RESULT_SEED, market.key().as_ref()],
+        bump = market_result.bump,
+    )]
+    pub market_result: Box<Account<'info, MarketResult>>,
+
+    /// Required when the market keeps an audit log.
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
-pub fn end_reveal_period(ctx: Context<EndRevealPeriod>) -> Result<()> {
+pub fn end_reveal_period<'info>(ctx: Context<'info, EndRevealPeriod<'info>>) -> Result<()> {
     let market = &mut ctx.accounts.market;
     let platform_config = &ctx.accounts.platform_config;
 
@@ -43,10 +68,43 @@ pub fn end_reveal_period(ctx: Context<EndRevealPeriod>) -> Result<()> {
 
     market.reveal_ended = true;
 
-    emit_ts!(RevealPeriodEndedEvent {
-        market: market.key(),
-        signer: ctx.accounts.signer.key(),
-    });
+    // Snapshot the winners' totals, which no longer change once reveals are closed.
+    let market_key = market.key();
+    let market_result = &mut ctx.accounts.market_result;
+    require!(
+        ctx.remaining_accounts.len() == market_result.winning_options.len(),
+        ErrorCode::InvalidParameters
+    );
+    for (stored, option_info) in market_result
+        .winning_options
+        .iter_mut()
+        .zip(ctx.remaining_accounts)
+    {
+        let result = OptionResult::from_option_account(option_info, &market_key)?;
+        require!(
+            result.option_id == stored.option_id,
+            ErrorCode::InvalidParameters
+        );
+        stored.total_staked = result.total_staked;
+        stored.total_score = result.total_score;
+    }
+    market_result.reveal_ended = true;
+
+    market.record_audit(
+        market.key(),
+        ctx.accounts.audit_log.as_ref(),
+        ctx.accounts.signer.key(),
+        AuditInstruction::EndRevealPeriod,
+        0,
+    )?;
+
+    emit_ts!(
+        ctx,
+        RevealPeriodEndedEvent {
+            market: market.key(),
+            signer: ctx.accounts.signer.key(),
+        }
+    );
 
     Ok(())
 }
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{PENDING_CONFIG_CHANGE_SEED, SCHEMA_VERSION};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, ConfigChangeExecutedEvent};
+use crate::state::{PendingConfigChange, PlatformConfig};
+
+// Permissionless once the delay has passed, so a queued change can't be held back.
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        close = update_authority,
+        seeds = [PENDING_CONFIG_CHANGE_SEED, platform_config.key().as_ref()],
+        bump = pending_config_change.bump,
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChange>>,
+
+    /// CHECK: Receives the pending change's rent; checked against platform_config.
+    #[account(mut, address = platform_config.update_authority @ ErrorCode::Unauthorized)]
+    pub update_authority: UncheckedAccount<'info>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp as u64;
+    require!(
+        now >= ctx.accounts.pending_config_change.executable_at,
+        ErrorCode::ConfigChangeNotReady
+    );
+
+    let change = ctx.accounts.pending_config_change.change;
+    ctx.accounts.platform_config.apply_change(&change);
+
+    emit_ts!(
+        ctx,
+        ConfigChangeExecutedEvent {
+            platform_config: ctx.accounts.platform_config.key(),
+            change: change,
+        }
+    );
+
+    Ok(())
+}
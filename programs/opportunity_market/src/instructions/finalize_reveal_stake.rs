@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{OPTION_SEED, STAKE_ACCOUNT_SEED};
+use crate::constants::{
+    MAX_MPC_FEE_REFUND_LAMPORTS, OPTION_SEED, SCHEMA_VERSION, STAKE_ACCOUNT_SEED,
+};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, RevealStakeFinalizedEvent};
 use crate::score::calculate_user_score;
@@ -15,8 +17,11 @@ pub struct FinalizeRevealStake<'info> {
     /// CHECK: this is a permissionless operation
     pub owner: UncheckedAccount<'info>,
 
-    #[account(mut)]
-    pub market: Account<'info, OpportunityMarket>,
+    #[account(
+        mut,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
 
     #[account(
         mut,
@@ -24,17 +29,24 @@ pub struct FinalizeRevealStake<'info> {
         bump = stake_account.bump,
 
         constraint = stake_account.score.is_none() @ ErrorCode::TallyAlreadyIncremented,
+        constraint = stake_account.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
-    pub stake_account: Account<'info, StakeAccount>,
+    pub stake_account: Box<Account<'info, StakeAccount>>,
 
     #[account(
         mut,
         seeds = [OPTION_SEED, market.key().as_ref(), &option_id.to_le_bytes()],
         bump = option.bump,
     )]
-    pub option: Account<'info, OpportunityMarketOption>,
+    pub option: Box<Account<'info, OpportunityMarketOption>>,
 
     pub system_program: Program<'info, System>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
 pub fn finalize_reveal_stake(
@@ -42,44 +54,79 @@ pub fn finalize_reveal_stake(
     option_id: u64,
     _stake_account_id: u32,
 ) -> Result<()> {
-    let market = &ctx.accounts.market;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let user_score = finalize_stake(
+        &mut ctx.accounts.market,
+        &mut ctx.accounts.option,
+        &mut ctx.accounts.stake_account,
+        option_id,
+        current_time,
+    )?;
 
+    let market_info = ctx.accounts.market.to_account_info();
+    pay_crank_bounty(
+        &mut ctx.accounts.market,
+        &market_info,
+        &ctx.accounts.signer.to_account_info(),
+        1,
+    )?;
+
+    emit_ts!(
+        ctx,
+        RevealStakeFinalizedEvent {
+            owner: ctx.accounts.owner.key(),
+            market: ctx.accounts.market.key(),
+            stake_account: ctx.accounts.stake_account.key(),
+            stake_account_id: ctx.accounts.stake_account.id,
+            option_id: option_id,
+            user_stake: ctx.accounts.stake_account.amount,
+            user_score: user_score,
+
+            total_score: ctx.accounts.option.total_score,
+            total_stake: ctx.accounts.option.total_staked,
+        }
+    );
+
+    Ok(())
+}
+
+/// Scores a revealed stake and adds it to its option's tally. Returns the stake's score.
+pub(crate) fn finalize_stake(
+    market: &mut OpportunityMarket,
+    option: &mut OpportunityMarketOption,
+    stake_account: &mut StakeAccount,
+    option_id: u64,
+    current_time: u64,
+) -> Result<u64> {
     // Check that we are within the reveal window
     let reveal_start = market.stake_end_timestamp.ok_or(ErrorCode::MarketNotOpen)?;
-    let clock = Clock::get()?;
-    let current_time = clock.unix_timestamp as u64;
 
     require!(current_time >= reveal_start, ErrorCode::TimeWindowMismatch);
     require!(!market.reveal_ended, ErrorCode::RevealPeriodEnded);
 
-    let revealed_option = ctx
-        .accounts
-        .stake_account
+    let revealed_option = stake_account
         .revealed_option
         .ok_or(ErrorCode::NotRevealed)?;
     require!(revealed_option == option_id, ErrorCode::InvalidOptionId);
 
-    let stake_amount = ctx.accounts.stake_account.amount;
+    let stake_amount = stake_account.amount;
 
-    ctx.accounts.option.total_staked = ctx
-        .accounts
-        .option
+    option.total_staked = option
         .total_staked
         .checked_add(stake_amount)
         .ok_or(ErrorCode::Overflow)?;
 
-    let stake_account = &ctx.accounts.stake_account;
-
     let staked_at_timestamp = stake_account
         .staked_at_timestamp
         .ok_or(ErrorCode::NoStake)?;
     let user_stake_end = stake_account.unstaked_at_timestamp.unwrap_or(reveal_start);
 
     let stake_base_amount = stake_amount
-        .checked_add(ctx.accounts.stake_account.collected_fees.total()?)
+        .checked_add(stake_account.collected_fees.total()?)
         .ok_or(ErrorCode::Overflow)?;
     let user_score = calculate_user_score(
-        ctx.accounts.option.created_at,
+        option.created_at,
         reveal_start,
         staked_at_timestamp,
         user_stake_end,
@@ -88,35 +135,70 @@ pub fn finalize_reveal_stake(
         market.earliness_multiplier,
     )?;
 
-    ctx.accounts.option.total_score = ctx
-        .accounts
-        .option
+    option.total_score = option
         .total_score
         .checked_add(user_score as u128)
         .ok_or(ErrorCode::Overflow)?;
 
     // Store the user's score in their stake account for reward calculation
-    ctx.accounts.stake_account.score = Some(user_score);
+    stake_account.score = Some(user_score);
 
     // Winning option means stake fees get refunded, so deduct from market account.
     // Actual refund transfer happens in `close_stake_account` together with reward.
-    if ctx.accounts.option.reward_bp.is_some() {
-        let fees = ctx.accounts.stake_account.collected_fees;
-        ctx.accounts.market.deduct_stake_fees(&fees)?;
+    if option.reward_bp.is_some() {
+        let fees = stake_account.collected_fees;
+        market.deduct_stake_fees(&fees)?;
+    } else {
+        market.losing_stake_total = market
+            .losing_stake_total
+            .checked_add(stake_amount)
+            .ok_or(ErrorCode::Overflow)?;
+    }
+
+    Ok(user_score)
+}
+
+/// Pays the cranker the market's bounty for `finalized` stakes, as far as the budget allows.
+pub(crate) fn pay_crank_bounty(
+    market: &mut OpportunityMarket,
+    market_info: &AccountInfo,
+    cranker: &AccountInfo,
+    finalized: u64,
+) -> Result<()> {
+    let bounty = market
+        .crank_bounty_lamports
+        .saturating_mul(finalized)
+        .min(market.crank_budget_lamports);
+    if bounty == 0 {
+        return Ok(());
+    }
+
+    market.crank_budget_lamports -= bounty;
+    **market_info.try_borrow_mut_lamports()? -= bounty;
+    **cranker.try_borrow_mut_lamports()? += bounty;
+
+    Ok(())
+}
+
+/// Refunds the MPC fee a reveal computation charged its payer, up to MAX_MPC_FEE_REFUND_LAMPORTS
+/// and as far as the crank budget allows.
+pub(crate) fn reimburse_mpc_fee(
+    market: &mut OpportunityMarket,
+    market_info: &AccountInfo,
+    payer: &AccountInfo,
+    mpc_fee: u64,
+) -> Result<()> {
+    let refund = mpc_fee
+        .min(MAX_MPC_FEE_REFUND_LAMPORTS)
+        .min(market.crank_budget_lamports);
+    if refund == 0 {
+        return Ok(());
     }
 
-    emit_ts!(RevealStakeFinalizedEvent {
-        owner: ctx.accounts.owner.key(),
-        market: ctx.accounts.market.key(),
-        stake_account: ctx.accounts.stake_account.key(),
-        stake_account_id: ctx.accounts.stake_account.id,
-        option_id: option_id,
-        user_stake: stake_amount,
-        user_score: user_score,
-
-        total_score: ctx.accounts.option.total_score,
-        total_stake: ctx.accounts.option.total_staked,
-    });
+    market.crank_budget_lamports -= refund;
+    market.mpc_fees_reimbursed = market.mpc_fees_reimbursed.saturating_add(refund);
+    **market_info.try_borrow_mut_lamports()? -= refund;
+    **payer.try_borrow_mut_lamports()? += refund;
 
     Ok(())
 }
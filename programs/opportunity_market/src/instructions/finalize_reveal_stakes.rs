@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{OPTION_SEED, SCHEMA_VERSION};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, RevealStakeFinalizedEvent};
+use crate::instructions::finalize_reveal_stake::{finalize_stake, pay_crank_bounty};
+use crate::state::{OpportunityMarket, OpportunityMarketOption, StakeAccount};
+
+// Remaining accounts: (stake_account, option) pairs, both writable, where option is the
+// stake's revealed option. Pairs may share an option.
+#[derive(Accounts)]
+pub struct FinalizeRevealStakes<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn finalize_reveal_stakes<'info>(
+    ctx: Context<'info, FinalizeRevealStakes<'info>>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    let market_key = ctx.accounts.market.key();
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % 2 == 0,
+        ErrorCode::InvalidParameters
+    );
+
+    let mut finalized = 0;
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let mut stake_account = Account::<StakeAccount>::try_from(&pair[0])?;
+        require!(
+            stake_account.schema_version == SCHEMA_VERSION,
+            ErrorCode::SchemaVersionMismatch
+        );
+        require_keys_eq!(
+            stake_account.market,
+            market_key,
+            ErrorCode::InvalidAccountState
+        );
+        require!(
+            stake_account.score.is_none(),
+            ErrorCode::TallyAlreadyIncremented
+        );
+
+        // Reloaded per pair, so tallies written by an earlier pair on the same option are kept.
+        let mut option = Account::<OpportunityMarketOption>::try_from(&pair[1])?;
+        let option_key = Pubkey::create_program_address(
+            &[
+                OPTION_SEED,
+                market_key.as_ref(),
+                &option.id.to_le_bytes(),
+                &[option.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| ErrorCode::InvalidParameters)?;
+        require_keys_eq!(pair[1].key(), option_key, ErrorCode::InvalidParameters);
+
+        let option_id = option.id;
+        let user_score = finalize_stake(
+            &mut ctx.accounts.market,
+            &mut option,
+            &mut stake_account,
+            option_id,
+            current_time,
+        )?;
+        stake_account.exit(&crate::ID)?;
+        option.exit(&crate::ID)?;
+        finalized += 1;
+
+        emit_ts!(
+            ctx,
+            RevealStakeFinalizedEvent {
+                owner: stake_account.owner,
+                market: market_key,
+                stake_account: stake_account.key(),
+                stake_account_id: stake_account.id,
+                option_id: option_id,
+                user_stake: stake_account.amount,
+                user_score: user_score,
+
+                total_score: option.total_score,
+                total_stake: option.total_staked,
+            }
+        );
+    }
+
+    let market_info = ctx.accounts.market.to_account_info();
+    pay_crank_bounty(
+        &mut ctx.accounts.market,
+        &market_info,
+        &ctx.accounts.signer.to_account_info(),
+        finalized,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::constants::SCHEMA_VERSION;
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, CrankBudgetFundedEvent, CrankBudgetWithdrawnEvent};
+use crate::state::OpportunityMarket;
+
+// The budget is held as lamports on the market account and pays crank_bounty_lamports to
+// whoever finalizes a revealed stake.
+#[derive(Accounts)]
+pub struct FundCrankBudget<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::Unauthorized,
+        constraint = !market.reveal_ended @ ErrorCode::RevealPeriodEnded,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    pub system_program: Program<'info, System>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn fund_crank_budget(
+    ctx: Context<FundCrankBudget>,
+    lamports: u64,
+    crank_bounty_lamports: u64,
+) -> Result<()> {
+    if lamports > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.key(),
+                Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.market.to_account_info(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+
+    let market = &mut ctx.accounts.market;
+    market.crank_budget_lamports = market
+        .crank_budget_lamports
+        .checked_add(lamports)
+        .ok_or(ErrorCode::Overflow)?;
+    market.crank_bounty_lamports = crank_bounty_lamports;
+
+    emit_ts!(
+        ctx,
+        CrankBudgetFundedEvent {
+            market: market.key(),
+            lamports: lamports,
+            crank_budget_lamports: market.crank_budget_lamports,
+            crank_bounty_lamports: crank_bounty_lamports,
+        }
+    );
+
+    Ok(())
+}
+
+// Returns what is left of the budget once the reveal period is over, or once the market is
+// void or expired, since those never reach a reveal period.
+#[derive(Accounts)]
+pub struct WithdrawCrankBudget<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::Unauthorized,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn withdraw_crank_budget(ctx: Context<WithdrawCrankBudget>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp as u64;
+    require!(
+        ctx.accounts.market.crank_budget_releasable(now)?,
+        ErrorCode::RevealPeriodNotOver
+    );
+
+    let lamports = ctx.accounts.market.crank_budget_lamports;
+    ctx.accounts.market.crank_budget_lamports = 0;
+    **ctx
+        .accounts
+        .market
+        .to_account_info()
+        .try_borrow_mut_lamports()? -= lamports;
+    **ctx
+        .accounts
+        .creator
+        .to_account_info()
+        .try_borrow_mut_lamports()? += lamports;
+
+    emit_ts!(
+        ctx,
+        CrankBudgetWithdrawnEvent {
+            market: ctx.accounts.market.key(),
+            lamports: lamports,
+        }
+    );
+
+    Ok(())
+}
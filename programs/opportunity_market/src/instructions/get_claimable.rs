@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{OPTION_SEED, SCHEMA_VERSION};
+use crate::error::ErrorCode;
+use crate::instructions::close_stake_account::winning_reward;
+use crate::state::{MarketPhase, OpportunityMarket, OpportunityMarketOption, StakeAccount};
+
+#[derive(Clone, Copy, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimableEstimate {
+    // Reward the stake is entitled to, including a loser rebate. Final once reveals end.
+    pub reward_total: u64,
+    // Part of reward_total that has vested and is not claimed yet.
+    pub reward_claimable: u64,
+    // Fees refunded when the stake account is closed.
+    pub fee_refund: u64,
+}
+
+// Read-only: returns what the stake could claim now through return data, for simulation.
+#[derive(Accounts)]
+pub struct GetClaimable<'info> {
+    #[account(
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    #[account(
+        constraint = stake_account.market == market.key() @ ErrorCode::InvalidAccountState,
+        constraint = stake_account.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub stake_account: Box<Account<'info, StakeAccount>>,
+
+    /// The option the stake was revealed for; omitted if that option account was closed.
+    #[account(
+        seeds = [OPTION_SEED, market.key().as_ref(), &option.id.to_le_bytes()],
+        bump = option.bump,
+        constraint = stake_account.revealed_option == Some(option.id) @ ErrorCode::InvalidOptionId,
+    )]
+    pub option: Option<Box<Account<'info, OpportunityMarketOption>>>,
+}
+
+pub fn get_claimable(ctx: Context<GetClaimable>) -> Result<ClaimableEstimate> {
+    let market = &ctx.accounts.market;
+    let stake_account = &ctx.accounts.stake_account;
+    let now = Clock::get()?.unix_timestamp as u64;
+    let fees = stake_account.collected_fees;
+    let fee_refund = fees
+        .reward_pool_fee
+        .checked_add(fees.creator_fee)
+        .ok_or(ErrorCode::Overflow)?;
+
+    match market.phase(now)?.0 {
+        MarketPhase::Revealing | MarketPhase::Settled => {
+            match winning_reward(stake_account, market, ctx.accounts.option.as_deref())? {
+                Some(reward) => Ok(ClaimableEstimate {
+                    reward_total: reward,
+                    reward_claimable: market
+                        .vested_reward(reward, now)?
+                        .saturating_sub(stake_account.reward_claimed),
+                    fee_refund,
+                }),
+                None if stake_account.score.is_some() => {
+                    let rebate = market.loser_rebate(stake_account.amount)?;
+                    Ok(ClaimableEstimate {
+                        reward_total: rebate,
+                        reward_claimable: rebate,
+                        fee_refund: 0,
+                    })
+                }
+                None => Ok(ClaimableEstimate::default()),
+            }
+        }
+        MarketPhase::Expired | MarketPhase::Void => Ok(ClaimableEstimate {
+            fee_refund,
+            ..Default::default()
+        }),
+        _ => Ok(ClaimableEstimate::default()),
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::SCHEMA_VERSION;
+use crate::error::ErrorCode;
+use crate::state::{MarketPhase, OpportunityMarket};
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct MarketPhaseInfo {
+    pub phase: MarketPhase,
+    // When the current phase ends, for phases that end at a fixed time.
+    pub phase_ends_at: Option<u64>,
+    pub total_stake: u64,
+    pub participant_count: u32,
+}
+
+// Read-only: the result is returned through return data, so clients can get the phase by
+// simulating this instruction instead of reimplementing the timing rules.
+#[derive(Accounts)]
+pub struct GetMarketPhase<'info> {
+    #[account(
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+}
+
+pub fn get_market_phase(ctx: Context<GetMarketPhase>) -> Result<MarketPhaseInfo> {
+    let market = &ctx.accounts.market;
+    let (phase, phase_ends_at) = market.phase(Clock::get()?.unix_timestamp as u64)?;
+
+    Ok(MarketPhaseInfo {
+        phase,
+        phase_ends_at,
+        total_stake: market.total_stake,
+        participant_count: market.participant_count,
+    })
+}
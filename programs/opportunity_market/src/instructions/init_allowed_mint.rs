@@ -1,20 +1,20 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_2022::spl_token_2022::extension::{
-    BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    transfer_hook::TransferHook, BaseStateWithExtensions, ExtensionType, StateWithExtensions,
 };
 use anchor_spl::token_2022::spl_token_2022::state::Mint as SplMint;
 use anchor_spl::token_interface::Mint;
 
-use crate::constants::ALLOWED_MINT_SEED;
+use crate::constants::{ALLOWED_MINT_SEED, SCHEMA_VERSION};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, AllowedMintInitializedEvent};
 use crate::state::{AllowedMint, PlatformConfig};
 
 // Reject mints with extensions that can break contract logic.
+// TransferHook is allowed separately, see transfer_hook_program.
 const FORBIDDEN_MINT_EXTENSIONS: &[ExtensionType] = &[
     ExtensionType::TransferFeeConfig,
     ExtensionType::PermanentDelegate,
-    ExtensionType::TransferHook,
     ExtensionType::NonTransferable,
     ExtensionType::Pausable,
     ExtensionType::DefaultAccountState,
@@ -33,6 +33,7 @@ pub struct InitAllowedMint<'info> {
 
     #[account(
         has_one = update_authority @ ErrorCode::Unauthorized,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
     pub platform_config: Box<Account<'info, PlatformConfig>>,
 
@@ -48,6 +49,12 @@ pub struct InitAllowedMint<'info> {
     pub allowed_mint: Box<Account<'info, AllowedMint>>,
 
     pub system_program: Program<'info, System>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
 pub fn init_allowed_mint(ctx: Context<InitAllowedMint>) -> Result<()> {
@@ -64,18 +71,49 @@ pub fn init_allowed_mint(ctx: Context<InitAllowedMint>) -> Result<()> {
             ErrorCode::InvalidMint
         );
     }
+    let transfer_hook_program =
+        transfer_hook_program(&mint_with_ext, &ctx.accounts.platform_config)?;
     drop(mint_data);
 
     let allowed_mint = &mut ctx.accounts.allowed_mint;
     allowed_mint.bump = ctx.bumps.allowed_mint;
+    allowed_mint.schema_version = SCHEMA_VERSION;
     allowed_mint.platform = ctx.accounts.platform_config.key();
     allowed_mint.mint = ctx.accounts.token_mint.key();
+    allowed_mint.transfer_hook_program = transfer_hook_program;
 
-    emit_ts!(AllowedMintInitializedEvent {
-        allowed_mint: allowed_mint.key(),
-        platform: allowed_mint.platform,
-        mint: allowed_mint.mint,
-    });
+    emit_ts!(
+        ctx,
+        AllowedMintInitializedEvent {
+            allowed_mint: allowed_mint.key(),
+            platform: allowed_mint.platform,
+            mint: allowed_mint.mint,
+            transfer_hook_program: transfer_hook_program,
+        }
+    );
 
     Ok(())
 }
+
+// A hooked mint is only allowed if the platform allowlists its hook program and the hook
+// authority is revoked, so the program can't be swapped for one that blocks payouts.
+fn transfer_hook_program(
+    mint: &StateWithExtensions<SplMint>,
+    platform_config: &PlatformConfig,
+) -> Result<Option<Pubkey>> {
+    let Ok(hook) = mint.get_extension::<TransferHook>() else {
+        return Ok(None);
+    };
+    let program_id = Option::<Pubkey>::from(hook.program_id);
+    require!(
+        Option::<Pubkey>::from(hook.authority).is_none(),
+        ErrorCode::TransferHookNotAllowed
+    );
+    if let Some(program_id) = program_id {
+        require!(
+            platform_config.transfer_hook_programs.contains(&program_id),
+            ErrorCode::TransferHookNotAllowed
+        );
+    }
+    Ok(program_id)
+}
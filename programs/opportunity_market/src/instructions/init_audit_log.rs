@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{AUDIT_LOG_SEED, SCHEMA_VERSION};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, AuditLogInitializedEvent};
+use crate::state::{AuditLog, OpportunityMarket};
+
+// Only before opening, so the log covers the market's whole history.
+#[derive(Accounts)]
+pub struct InitAuditLog<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::Unauthorized,
+        constraint = market.stake_end_timestamp.is_none() @ ErrorCode::MarketAlreadyOpen,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + std::mem::size_of::<AuditLog>(),
+        seeds = [AUDIT_LOG_SEED, market.key().as_ref()],
+        bump,
+    )]
+    pub audit_log: AccountLoader<'info, AuditLog>,
+
+    pub system_program: Program<'info, System>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn init_audit_log(ctx: Context<InitAuditLog>) -> Result<()> {
+    let mut audit_log = ctx.accounts.audit_log.load_init()?;
+    audit_log.schema_version = SCHEMA_VERSION;
+    audit_log.bump = ctx.bumps.audit_log;
+    audit_log.market = ctx.accounts.market.key();
+    drop(audit_log);
+
+    ctx.accounts.market.audit_log_enabled = true;
+
+    emit_ts!(
+        ctx,
+        AuditLogInitializedEvent {
+            market: ctx.accounts.market.key(),
+            audit_log: ctx.accounts.audit_log.key(),
+        }
+    );
+
+    Ok(())
+}
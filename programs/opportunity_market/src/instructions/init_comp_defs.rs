@@ -5,7 +5,12 @@ use arcium_client::idl::arcium::types::{CircuitSource, OffChainCircuitSource};
 #[cfg(feature = "production-settings")]
 use arcium_macros::circuit_hash;
 
-use crate::ID;
+use crate::constants::{BOOTSTRAP_STATE_SEED, PROGRAM_METRICS_SEED, SCHEMA_VERSION};
+use crate::state::{BootstrapState, ProgramMetrics};
+use crate::{
+    ArciumSignerAccount, COMP_DEF_OFFSET_REVEAL_STAKE, COMP_DEF_OFFSET_REVEAL_STAKE_BATCH,
+    COMP_DEF_OFFSET_STAKE, COMP_DEF_OFFSET_VERIFY_STAKE_DISCLOSURE, ID, ID_CONST,
+};
 
 #[init_computation_definition_accounts("stake", payer)]
 #[derive(Accounts)]
@@ -28,10 +33,14 @@ pub struct StakeCompDef<'info> {
 }
 
 pub fn stake_comp_def(ctx: Context<StakeCompDef>) -> Result<()> {
+    init_stake_comp_def(ctx.accounts)
+}
+
+fn init_stake_comp_def(accounts: &StakeCompDef) -> Result<()> {
     #[cfg(feature = "production-settings")]
     {
         init_computation_def(
-            ctx.accounts,
+            accounts,
             Some(CircuitSource::OffChain(OffChainCircuitSource {
                 source: "https://pub-f4c38b2a6f20431a8856eb3b17373497.r2.dev/stake.arcis"
                     .to_string(),
@@ -41,7 +50,7 @@ pub fn stake_comp_def(ctx: Context<StakeCompDef>) -> Result<()> {
     }
     #[cfg(not(feature = "production-settings"))]
     {
-        init_computation_def(ctx.accounts, None)?;
+        init_computation_def(accounts, None)?;
     }
     Ok(())
 }
@@ -67,10 +76,14 @@ pub struct RevealStakeCompDef<'info> {
 }
 
 pub fn reveal_stake_comp_def(ctx: Context<RevealStakeCompDef>) -> Result<()> {
+    init_reveal_stake_comp_def(ctx.accounts)
+}
+
+fn init_reveal_stake_comp_def(accounts: &RevealStakeCompDef) -> Result<()> {
     #[cfg(feature = "production-settings")]
     {
         init_computation_def(
-            ctx.accounts,
+            accounts,
             Some(CircuitSource::OffChain(OffChainCircuitSource {
                 source: "https://pub-f4c38b2a6f20431a8856eb3b17373497.r2.dev/reveal_stake.arcis"
                     .to_string(),
@@ -80,7 +93,218 @@ pub fn reveal_stake_comp_def(ctx: Context<RevealStakeCompDef>) -> Result<()> {
     }
     #[cfg(not(feature = "production-settings"))]
     {
-        init_computation_def(ctx.accounts, None)?;
+        init_computation_def(accounts, None)?;
     }
     Ok(())
 }
+
+#[init_computation_definition_accounts("reveal_stake_batch", payer)]
+#[derive(Accounts)]
+pub struct RevealStakeBatchCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    /// CHECK: address_lookup_table, checked by arcium program.
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    pub address_lookup_table: UncheckedAccount<'info>,
+    /// CHECK: lut_program is the Address Lookup Table program.
+    #[account(address = LUT_PROGRAM_ID)]
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn reveal_stake_batch_comp_def(ctx: Context<RevealStakeBatchCompDef>) -> Result<()> {
+    init_reveal_stake_batch_comp_def(ctx.accounts)
+}
+
+fn init_reveal_stake_batch_comp_def(accounts: &RevealStakeBatchCompDef) -> Result<()> {
+    #[cfg(feature = "production-settings")]
+    {
+        init_computation_def(
+            accounts,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source:
+                    "https://pub-f4c38b2a6f20431a8856eb3b17373497.r2.dev/reveal_stake_batch.arcis"
+                        .to_string(),
+                hash: circuit_hash!("reveal_stake_batch"),
+            })),
+        )?;
+    }
+    #[cfg(not(feature = "production-settings"))]
+    {
+        init_computation_def(accounts, None)?;
+    }
+    Ok(())
+}
+
+#[init_computation_definition_accounts("verify_stake_disclosure", payer)]
+#[derive(Accounts)]
+pub struct VerifyStakeDisclosureCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    /// CHECK: address_lookup_table, checked by arcium program.
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    pub address_lookup_table: UncheckedAccount<'info>,
+    /// CHECK: lut_program is the Address Lookup Table program.
+    #[account(address = LUT_PROGRAM_ID)]
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn verify_stake_disclosure_comp_def(ctx: Context<VerifyStakeDisclosureCompDef>) -> Result<()> {
+    init_verify_stake_disclosure_comp_def(ctx.accounts)
+}
+
+fn init_verify_stake_disclosure_comp_def(accounts: &VerifyStakeDisclosureCompDef) -> Result<()> {
+    #[cfg(feature = "production-settings")]
+    {
+        init_computation_def(
+            accounts,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source:
+                    "https://pub-f4c38b2a6f20431a8856eb3b17373497.r2.dev/verify_stake_disclosure.arcis"
+                        .to_string(),
+                hash: circuit_hash!("verify_stake_disclosure"),
+            })),
+        )?;
+    }
+    #[cfg(not(feature = "production-settings"))]
+    {
+        init_computation_def(accounts, None)?;
+    }
+    Ok(())
+}
+
+/// Initializes every computation definition in one transaction. Definitions that already
+/// exist are skipped, so the instruction can be re-run after a partial deployment.
+#[derive(Accounts)]
+pub struct InitializeCompDefs<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + BootstrapState::INIT_SPACE,
+        seeds = [BOOTSTRAP_STATE_SEED],
+        bump,
+    )]
+    pub bootstrap_state: Box<Account<'info, BootstrapState>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ProgramMetrics::INIT_SPACE,
+        seeds = [PROGRAM_METRICS_SEED],
+        bump,
+    )]
+    pub program_metrics: Box<Account<'info, ProgramMetrics>>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut, address = derive_comp_def_pda!(COMP_DEF_OFFSET_STAKE))]
+    /// CHECK: stake comp_def_account, checked by arcium program.
+    pub stake_comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_STAKE))]
+    /// CHECK: reveal_stake comp_def_account, checked by arcium program.
+    pub reveal_stake_comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_STAKE_BATCH))]
+    /// CHECK: reveal_stake_batch comp_def_account, checked by arcium program.
+    pub reveal_stake_batch_comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_def_pda!(COMP_DEF_OFFSET_VERIFY_STAKE_DISCLOSURE))]
+    /// CHECK: verify_stake_disclosure comp_def_account, checked by arcium program.
+    pub verify_stake_disclosure_comp_def_account: UncheckedAccount<'info>,
+    /// CHECK: address_lookup_table, checked by arcium program.
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    pub address_lookup_table: UncheckedAccount<'info>,
+    /// CHECK: lut_program is the Address Lookup Table program.
+    #[account(address = LUT_PROGRAM_ID)]
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_comp_defs(ctx: Context<InitializeCompDefs>) -> Result<()> {
+    let accs = &ctx.accounts;
+
+    if accs.stake_comp_def_account.data_is_empty() {
+        init_stake_comp_def(&StakeCompDef {
+            payer: accs.payer.clone(),
+            mxe_account: accs.mxe_account.clone(),
+            comp_def_account: accs.stake_comp_def_account.clone(),
+            address_lookup_table: accs.address_lookup_table.clone(),
+            lut_program: accs.lut_program.clone(),
+            arcium_program: accs.arcium_program.clone(),
+            system_program: accs.system_program.clone(),
+        })?;
+    }
+
+    if accs.reveal_stake_comp_def_account.data_is_empty() {
+        init_reveal_stake_comp_def(&RevealStakeCompDef {
+            payer: accs.payer.clone(),
+            mxe_account: accs.mxe_account.clone(),
+            comp_def_account: accs.reveal_stake_comp_def_account.clone(),
+            address_lookup_table: accs.address_lookup_table.clone(),
+            lut_program: accs.lut_program.clone(),
+            arcium_program: accs.arcium_program.clone(),
+            system_program: accs.system_program.clone(),
+        })?;
+    }
+
+    if accs.reveal_stake_batch_comp_def_account.data_is_empty() {
+        init_reveal_stake_batch_comp_def(&RevealStakeBatchCompDef {
+            payer: accs.payer.clone(),
+            mxe_account: accs.mxe_account.clone(),
+            comp_def_account: accs.reveal_stake_batch_comp_def_account.clone(),
+            address_lookup_table: accs.address_lookup_table.clone(),
+            lut_program: accs.lut_program.clone(),
+            arcium_program: accs.arcium_program.clone(),
+            system_program: accs.system_program.clone(),
+        })?;
+    }
+
+    if accs
+        .verify_stake_disclosure_comp_def_account
+        .data_is_empty()
+    {
+        init_verify_stake_disclosure_comp_def(&VerifyStakeDisclosureCompDef {
+            payer: accs.payer.clone(),
+            mxe_account: accs.mxe_account.clone(),
+            comp_def_account: accs.verify_stake_disclosure_comp_def_account.clone(),
+            address_lookup_table: accs.address_lookup_table.clone(),
+            lut_program: accs.lut_program.clone(),
+            arcium_program: accs.arcium_program.clone(),
+            system_program: accs.system_program.clone(),
+        })?;
+    }
+
+    let bootstrap_state = &mut ctx.accounts.bootstrap_state;
+    bootstrap_state.bump = ctx.bumps.bootstrap_state;
+    bootstrap_state.schema_version = SCHEMA_VERSION;
+    bootstrap_state.comp_defs_initialized = true;
+
+    let program_metrics = &mut ctx.accounts.program_metrics;
+    program_metrics.bump = ctx.bumps.program_metrics;
+    program_metrics.schema_version = SCHEMA_VERSION;
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    Ok(())
+}
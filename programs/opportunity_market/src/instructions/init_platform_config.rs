@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::constants::{
     MAX_PLATFORM_NAME_LEN, MAX_REVEAL_PERIOD_SECONDS, MIN_PLATFORM_NAME_LEN,
-    MIN_REVEAL_PERIOD_SECONDS, PLATFORM_CONFIG_SEED,
+    MIN_REVEAL_PERIOD_SECONDS, PLATFORM_CONFIG_SEED, SCHEMA_VERSION,
 };
 #[cfg(feature = "production-settings")]
 use crate::constants::{MIN_MARKET_RESOLUTION_DEADLINE_SECONDS, MIN_TIME_TO_STAKE_FLOOR_SECONDS};
@@ -22,7 +22,7 @@ pub struct InitPlatformConfig<'info> {
         seeds = [PLATFORM_CONFIG_SEED, payer.key().as_ref(), name.as_bytes()],
         bump,
     )]
-    pub platform_config: Account<'info, PlatformConfig>,
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
 
     pub system_program: Program<'info, System>,
 }
@@ -61,6 +61,7 @@ pub fn init_platform_config(
 
     let platform_config = &mut ctx.accounts.platform_config;
     platform_config.bump = ctx.bumps.platform_config;
+    platform_config.schema_version = SCHEMA_VERSION;
     platform_config.name = name;
     platform_config.update_authority = ctx.accounts.payer.key();
     platform_config.fee_rates = FeeRates::new(platform_fee_bp, reward_pool_fee_bp, creator_fee_bp)?;
@@ -69,6 +70,11 @@ pub fn init_platform_config(
     platform_config.min_time_to_stake_seconds = min_time_to_stake_seconds;
     platform_config.reveal_period_seconds = reveal_period_seconds;
     platform_config.market_resolution_deadline_seconds = market_resolution_deadline_seconds;
+    platform_config.paused = false;
+    platform_config.pending_update_authority = None;
+    platform_config.default_cu_price_micro = 0;
+    platform_config.config_change_delay_seconds = 0;
+    platform_config.transfer_hook_programs = Vec::new();
 
     Ok(())
 }
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::STAKE_ACCOUNT_SEED;
+use crate::constants::{SCHEMA_VERSION, STAKE_ACCOUNT_SEED};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, StakeAccountInitializedEvent};
 use crate::state::{OpportunityMarket, StakeAccount};
@@ -17,8 +17,9 @@ pub struct InitStakeAccount<'info> {
 
     #[account(
         constraint = market.stake_end_timestamp.is_some() @ ErrorCode::MarketNotOpen,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
-    pub market: Account<'info, OpportunityMarket>,
+    pub market: Box<Account<'info, OpportunityMarket>>,
 
     #[account(
         init,
@@ -27,25 +28,38 @@ pub struct InitStakeAccount<'info> {
         seeds = [STAKE_ACCOUNT_SEED, owner.key().as_ref(), market.key().as_ref(), &stake_account_id.to_le_bytes()],
         bump,
     )]
-    pub stake_account: Account<'info, StakeAccount>,
+    pub stake_account: Box<Account<'info, StakeAccount>>,
 
     pub system_program: Program<'info, System>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
 pub fn init_stake_account(ctx: Context<InitStakeAccount>, stake_account_id: u32) -> Result<()> {
     let stake_account = &mut ctx.accounts.stake_account;
 
     stake_account.bump = ctx.bumps.stake_account;
+    stake_account.schema_version = SCHEMA_VERSION;
     stake_account.owner = ctx.accounts.owner.key();
     stake_account.market = ctx.accounts.market.key();
     stake_account.id = stake_account_id;
+    stake_account.next_computation_seq = Clock::get()?.slot;
+    stake_account.portfolio_tracked = false;
+    stake_account.migrated = false;
 
-    emit_ts!(StakeAccountInitializedEvent {
-        stake_account: stake_account.key(),
-        owner: stake_account.owner,
-        account_id: stake_account_id,
-        market: stake_account.market,
-    });
+    emit_ts!(
+        ctx,
+        StakeAccountInitializedEvent {
+            stake_account: stake_account.key(),
+            owner: stake_account.owner,
+            account_id: stake_account_id,
+            market: stake_account.market,
+        }
+    );
 
     Ok(())
 }
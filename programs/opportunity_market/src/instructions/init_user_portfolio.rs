@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{SCHEMA_VERSION, USER_PORTFOLIO_SEED};
+use crate::state::UserPortfolio;
+
+#[derive(Accounts)]
+pub struct InitUserPortfolio<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: owner is verified by being a PDA seed input.
+    /// No signature required: this instruction is permissionless.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + UserPortfolio::INIT_SPACE,
+        seeds = [USER_PORTFOLIO_SEED, owner.key().as_ref()],
+        bump,
+    )]
+    pub user_portfolio: Box<Account<'info, UserPortfolio>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_user_portfolio(ctx: Context<InitUserPortfolio>) -> Result<()> {
+    let user_portfolio = &mut ctx.accounts.user_portfolio;
+
+    user_portfolio.bump = ctx.bumps.user_portfolio;
+    user_portfolio.schema_version = SCHEMA_VERSION;
+    user_portfolio.owner = ctx.accounts.owner.key();
+    user_portfolio.last_activity_slot = Clock::get()?.slot;
+
+    Ok(())
+}
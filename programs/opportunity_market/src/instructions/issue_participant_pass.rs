@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{PARTICIPANT_PASS_SEED, SCHEMA_VERSION};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, ParticipantPassIssuedEvent, ParticipantPassRevokedEvent};
+use crate::state::{OpportunityMarket, ParticipantPass};
+
+#[derive(Accounts)]
+pub struct IssueParticipantPass<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        has_one = creator @ ErrorCode::Unauthorized,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    /// CHECK: only used as a PDA seed; the pass is bound to this wallet.
+    pub participant: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + ParticipantPass::INIT_SPACE,
+        seeds = [PARTICIPANT_PASS_SEED, market.key().as_ref(), participant.key().as_ref()],
+        bump,
+    )]
+    pub participant_pass: Box<Account<'info, ParticipantPass>>,
+
+    pub system_program: Program<'info, System>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn issue_participant_pass(ctx: Context<IssueParticipantPass>) -> Result<()> {
+    let pass = &mut ctx.accounts.participant_pass;
+    pass.schema_version = SCHEMA_VERSION;
+    pass.bump = ctx.bumps.participant_pass;
+    pass.market = ctx.accounts.market.key();
+    pass.participant = ctx.accounts.participant.key();
+    pass.issued_at = Clock::get()?.unix_timestamp as u64;
+
+    emit_ts!(
+        ctx,
+        ParticipantPassIssuedEvent {
+            market: pass.market,
+            participant: pass.participant,
+            participant_pass: pass.key(),
+        }
+    );
+
+    Ok(())
+}
+
+// Revoking only stops future stakes; stakes already made are unaffected.
+#[derive(Accounts)]
+pub struct RevokeParticipantPass<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        has_one = creator @ ErrorCode::Unauthorized,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [PARTICIPANT_PASS_SEED, market.key().as_ref(), participant_pass.participant.as_ref()],
+        bump = participant_pass.bump,
+    )]
+    pub participant_pass: Box<Account<'info, ParticipantPass>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn revoke_participant_pass(ctx: Context<RevokeParticipantPass>) -> Result<()> {
+    emit_ts!(
+        ctx,
+        ParticipantPassRevokedEvent {
+            market: ctx.accounts.market.key(),
+            participant: ctx.accounts.participant_pass.participant,
+            participant_pass: ctx.accounts.participant_pass.key(),
+        }
+    );
+
+    Ok(())
+}
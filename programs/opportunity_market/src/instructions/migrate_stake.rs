@@ -0,0 +1,352 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+
+use crate::arcium_accounts::arcium_queue_accounts;
+use crate::computation::queued_mpc_fee;
+use crate::constants::{
+    ENCRYPTED_OPTION_LEN, OPPORTUNITY_MARKET_SEED, PARTICIPANT_PASS_SEED, PROGRAM_METRICS_SEED,
+    SCHEMA_VERSION, STAKE_ACCOUNT_ENCRYPTED_OPTION_OFFSET, STAKE_ACCOUNT_SEED, USER_PORTFOLIO_SEED,
+};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, StakeMigratedEvent};
+use crate::instructions::StakeCallback;
+use crate::state::{
+    AuditInstruction, AuditLog, CollectedFees, OpportunityMarket, ParticipantPass, PlatformConfig,
+    ProgramMetrics, StakeAccount, UserPortfolio,
+};
+use crate::transfer::transfer_checked;
+use crate::COMP_DEF_OFFSET_STAKE;
+use crate::{ArciumSignerAccount, ID, ID_CONST};
+
+arcium_queue_accounts!(
+    "stake",
+    payer,
+    COMP_DEF_OFFSET_STAKE,
+    computation_offset,
+    #[instruction(computation_offset: u64, from_stake_account_id: u32, to_stake_account_id: u32)]
+    pub struct MigrateStake<'info> {
+        pub signer: Signer<'info>,
+
+        #[account(mut)]
+        pub payer: Signer<'info>,
+
+        #[account(
+            mut,
+            seeds = [OPPORTUNITY_MARKET_SEED, from_market.platform.as_ref(), from_market.creator.as_ref(), &from_market.index.to_le_bytes()],
+            bump = from_market.bump,
+            constraint = from_market.successor_market == Some(to_market.key()) @ ErrorCode::InvalidParameters,
+            constraint = from_market.resolved_at_timestamp.is_none() @ ErrorCode::WinnerAlreadySelected,
+            constraint = from_market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub from_market: Box<Account<'info, OpportunityMarket>>,
+
+        #[account(
+            mut,
+            seeds = [STAKE_ACCOUNT_SEED, signer.key().as_ref(), from_market.key().as_ref(), &from_stake_account_id.to_le_bytes()],
+            bump = from_stake_account.bump,
+            constraint = from_stake_account.staked_at_timestamp.is_some() @ ErrorCode::NoStake,
+            constraint = from_stake_account.unstaked_at_timestamp.is_none() @ ErrorCode::AlreadyUnstaked,
+            constraint = from_stake_account.pending_stake_computation.is_none() @ ErrorCode::Locked,
+            constraint = !from_stake_account.pending_reveal @ ErrorCode::Locked,
+            constraint = from_stake_account.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub from_stake_account: Box<Account<'info, StakeAccount>>,
+
+        #[account(
+            mut,
+            constraint = to_market.mint == from_market.mint @ ErrorCode::InvalidParameters,
+            constraint = to_market.stake_end_timestamp.is_some() @ ErrorCode::MarketNotOpen,
+            constraint = to_market.resolved_at_timestamp.is_none() @ ErrorCode::WinnerAlreadySelected,
+            constraint = to_market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub to_market: Box<Account<'info, OpportunityMarket>>,
+
+        #[account(
+            mut,
+            seeds = [STAKE_ACCOUNT_SEED, signer.key().as_ref(), to_market.key().as_ref(), &to_stake_account_id.to_le_bytes()],
+            bump = to_stake_account.bump,
+            constraint = to_stake_account.staked_at_timestamp.is_none() @ ErrorCode::AlreadyStaked,
+            constraint = to_stake_account.unstaked_at_timestamp.is_none() @ ErrorCode::AlreadyUnstaked,
+            constraint = to_stake_account.pending_stake_computation.is_none() @ ErrorCode::Locked,
+            constraint = to_stake_account.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub to_stake_account: Box<Account<'info, StakeAccount>>,
+
+        /// Required if the old position was counted on the owner's portfolio.
+        #[account(
+            mut,
+            seeds = [USER_PORTFOLIO_SEED, signer.key().as_ref()],
+            bump = user_portfolio.bump,
+        )]
+        pub user_portfolio: Option<Box<Account<'info, UserPortfolio>>>,
+
+        /// Required when the successor restricts participants.
+        #[account(
+            seeds = [PARTICIPANT_PASS_SEED, to_market.key().as_ref(), signer.key().as_ref()],
+            bump = participant_pass.bump,
+        )]
+        pub participant_pass: Option<Box<Account<'info, ParticipantPass>>>,
+
+        /// Required when the successor is gated by a mint.
+        pub gate_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+        #[account(
+            address = to_market.platform @ ErrorCode::Unauthorized,
+            constraint = !platform_config.paused @ ErrorCode::PlatformPaused,
+            constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+        // SPL token accounts
+        #[account(address = from_market.mint)]
+        pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+        #[account(
+            mut,
+            associated_token::mint = token_mint,
+            associated_token::authority = from_market,
+            associated_token::token_program = token_program,
+        )]
+        pub from_market_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+        #[account(
+            mut,
+            associated_token::mint = token_mint,
+            associated_token::authority = to_market,
+            associated_token::token_program = token_program,
+        )]
+        pub to_market_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+        pub token_program: Interface<'info, TokenInterface>,
+
+        // Event CPI accounts
+        /// CHECK: event authority PDA, checked by address.
+        #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+        pub event_authority: UncheckedAccount<'info>,
+        pub program: Program<'info, crate::program::OpportunityMarket>,
+
+        /// Required when the respective market keeps an audit log.
+        #[account(mut)]
+        pub from_audit_log: Option<AccountLoader<'info, AuditLog>>,
+        #[account(mut)]
+        pub to_audit_log: Option<AccountLoader<'info, AuditLog>>,
+
+        #[account(mut, seeds = [PROGRAM_METRICS_SEED], bump = program_metrics.bump)]
+        pub program_metrics: Box<Account<'info, ProgramMetrics>>,
+    }
+);
+
+// Moves an unresolved stake into the market's designated successor. The stake circuit
+// re-encrypts the stored option for the new stake account and the successor's authorized
+// reader, so the option stays hidden. Fees were paid on entry to the old market and are not
+// charged again, nor refunded: they stay with the old market. The old stake is left with no
+// amount or fees, so it scores nothing, and is marked migrated so its option can't be revealed.
+pub fn migrate_stake<'info>(
+    ctx: Context<'info, MigrateStake<'info>>,
+    computation_offset: u64,
+    _from_stake_account_id: u32,
+    _to_stake_account_id: u32,
+    authorized_reader_nonce: u128,
+    state_nonce: u128,
+    cu_price_micro: Option<u64>,
+) -> Result<()> {
+    let stake_end = ctx
+        .accounts
+        .to_market
+        .stake_end_timestamp
+        .ok_or(ErrorCode::MarketNotOpen)?;
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp as u64;
+    require!(
+        current_timestamp <= stake_end,
+        ErrorCode::TimeWindowMismatch
+    );
+    require!(
+        !ctx.accounts.to_market.is_void(current_timestamp),
+        ErrorCode::MarketVoid
+    );
+
+    require!(
+        !ctx.accounts.to_market.participants_restricted || ctx.accounts.participant_pass.is_some(),
+        ErrorCode::ParticipantPassRequired
+    );
+    ctx.accounts.to_market.check_gate(
+        ctx.accounts.gate_token_account.as_deref().map(|a| &**a),
+        &ctx.accounts.signer.key(),
+    )?;
+
+    let amount = ctx.accounts.from_stake_account.amount;
+    require!(amount > 0, ErrorCode::NoStake);
+    require!(
+        amount >= ctx.accounts.to_market.min_stake_amount,
+        ErrorCode::StakeBelowMinimum
+    );
+
+    let from_market = &ctx.accounts.from_market;
+    let platform = from_market.platform;
+    let creator = from_market.creator;
+    let index_bytes = from_market.index.to_le_bytes();
+    let market_bump = from_market.bump;
+    let market_seeds: &[&[&[u8]]] = &[&[
+        OPPORTUNITY_MARKET_SEED,
+        platform.as_ref(),
+        creator.as_ref(),
+        &index_bytes,
+        &[market_bump],
+    ]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.key(),
+            TransferChecked {
+                from: ctx.accounts.from_market_token_ata.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.to_market_token_ata.to_account_info(),
+                authority: ctx.accounts.from_market.to_account_info(),
+            },
+            market_seeds,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    if ctx
+        .accounts
+        .from_market
+        .stake_end_timestamp
+        .is_some_and(|end| current_timestamp < end)
+    {
+        ctx.accounts.from_market.remove_participant(amount);
+    }
+
+    let from_stake_account = &mut ctx.accounts.from_stake_account;
+    from_stake_account.amount = 0;
+    from_stake_account.collected_fees = CollectedFees::default();
+    from_stake_account.unstaked_at_timestamp = Some(current_timestamp);
+    from_stake_account.migrated = true;
+    let from_stake_account_key = from_stake_account.key();
+    let from_state_nonce = from_stake_account.state_nonce;
+    let user_pubkey = from_stake_account.user_pubkey;
+
+    ctx.accounts.to_market.record_participant(amount);
+
+    // The old account stays open until closed, so the successor stake is a new position.
+    require!(
+        !ctx.accounts.from_stake_account.portfolio_tracked || ctx.accounts.user_portfolio.is_some(),
+        ErrorCode::UserPortfolioRequired
+    );
+    ctx.accounts.to_stake_account.open_in_portfolio(
+        ctx.accounts.user_portfolio.as_deref_mut().map(|p| &mut **p),
+        clock.slot,
+    );
+
+    let to_stake_account = &mut ctx.accounts.to_stake_account;
+    to_stake_account.staked_at_timestamp = Some(current_timestamp);
+    to_stake_account.amount = amount;
+    to_stake_account.user_pubkey = user_pubkey;
+    to_stake_account.state_nonce = state_nonce;
+    to_stake_account.pending_stake_computation = Some(ctx.accounts.computation_account.key());
+    let to_stake_account_key = to_stake_account.key();
+    to_stake_account.consume_computation_offset(&to_stake_account_key, computation_offset)?;
+    to_stake_account.queue_computation_offset(computation_offset);
+    let to_market_key = ctx.accounts.to_market.key();
+
+    emit_ts!(
+        ctx,
+        StakeMigratedEvent {
+            owner: ctx.accounts.signer.key(),
+            from_market: ctx.accounts.from_market.key(),
+            from_stake_account: from_stake_account_key,
+            to_market: to_market_key,
+            to_stake_account: to_stake_account_key,
+            amount: amount,
+        }
+    );
+
+    let args = ArgBuilder::new()
+        // Old stake account encrypted option (Enc<Shared, SelectedOption>)
+        .x25519_pubkey(user_pubkey)
+        .plaintext_u128(from_state_nonce)
+        .account(
+            from_stake_account_key,
+            STAKE_ACCOUNT_ENCRYPTED_OPTION_OFFSET,
+            ENCRYPTED_OPTION_LEN,
+        )
+        // Successor's authorized reader context (Shared)
+        .x25519_pubkey(ctx.accounts.to_market.authorized_reader_pubkey)
+        .plaintext_u128(authorized_reader_nonce)
+        // New stake account context (Shared for MXE output encryption)
+        .x25519_pubkey(user_pubkey)
+        .plaintext_u128(state_nonce)
+        .build();
+
+    ctx.accounts.from_market.record_audit(
+        ctx.accounts.from_market.key(),
+        ctx.accounts.from_audit_log.as_ref(),
+        ctx.accounts.signer.key(),
+        AuditInstruction::MigrateStake,
+        computation_offset,
+    )?;
+
+    ctx.accounts.to_market.record_audit(
+        to_market_key,
+        ctx.accounts.to_audit_log.as_ref(),
+        ctx.accounts.signer.key(),
+        AuditInstruction::MigrateStake,
+        computation_offset,
+    )?;
+
+    let cu_price_micro = ctx
+        .accounts
+        .platform_config
+        .cu_price_micro(cu_price_micro)?;
+    ctx.accounts.program_metrics.record_queued();
+    ctx.accounts.program_metrics.stakes = ctx.accounts.program_metrics.stakes.saturating_add(1);
+    let payer_lamports = ctx.accounts.payer.lamports();
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![StakeCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: to_stake_account_key,
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: to_market_key,
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.program_metrics.key(),
+                    is_writable: true,
+                },
+                // #[event_cpi] accounts
+                CallbackAccount {
+                    pubkey: crate::EVENT_AUTHORITY_AND_BUMP.0,
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: crate::ID,
+                    is_writable: false,
+                },
+            ],
+        )?],
+        1,
+        cu_price_micro,
+    )?;
+
+    let mpc_fee = queued_mpc_fee(
+        payer_lamports,
+        ctx.accounts.payer.lamports(),
+        ctx.accounts.computation_account.lamports(),
+    );
+    ctx.accounts.program_metrics.record_mpc_fee(mpc_fee);
+
+    Ok(())
+}
@@ -2,48 +2,174 @@ pub mod add_market_option;
 pub mod add_reward;
 pub mod claim_creator_fees;
 pub mod claim_fees;
+pub mod claim_reward_tranche;
 pub mod close_option_account;
 pub mod close_stake_account;
 pub mod close_stuck_stake_account;
+pub mod create_category;
 pub mod create_market;
+pub mod create_market_with_options;
+pub mod emergency_resolve;
 pub mod end_reveal_period;
+pub mod execute_config_change;
 pub mod finalize_reveal_stake;
+pub mod finalize_reveal_stakes;
+pub mod fund_crank_budget;
+pub mod get_claimable;
+pub mod get_market_phase;
 pub mod init_allowed_mint;
+pub mod init_audit_log;
 pub mod init_comp_defs;
 pub mod init_platform_config;
 pub mod init_stake_account;
+pub mod init_user_portfolio;
+pub mod issue_participant_pass;
+pub mod migrate_stake;
 pub mod open_market;
+pub mod remove_market_option;
 pub mod resolve_market;
 pub mod reveal_stake;
+pub mod reveal_stake_batch;
 pub mod set_fee_claim_authority;
+pub mod set_gate_mint;
+pub mod set_loser_rebate;
+pub mod set_market_category;
+pub mod set_participants_restricted;
+pub mod set_participation_threshold;
+pub mod set_platform_paused;
+pub mod set_reward_vesting;
+pub mod set_successor_market;
+pub mod set_transfer_hook_programs;
 pub mod set_update_authority;
 pub mod set_winning_option;
 pub mod stake;
 pub mod unstake;
+pub mod update_market_timing;
 pub mod update_platform_config;
+pub mod verify_stake_disclosure;
 pub mod withdraw_reward;
 
 pub use add_market_option::*;
 pub use add_reward::*;
 pub use claim_creator_fees::*;
 pub use claim_fees::*;
+pub use claim_reward_tranche::*;
 pub use close_option_account::*;
 pub use close_stake_account::*;
 pub use close_stuck_stake_account::*;
+pub use create_category::*;
 pub use create_market::*;
+pub use create_market_with_options::*;
+pub use emergency_resolve::*;
 pub use end_reveal_period::*;
+pub use execute_config_change::*;
 pub use finalize_reveal_stake::*;
+pub use finalize_reveal_stakes::*;
+pub use fund_crank_budget::*;
+pub use get_claimable::*;
+pub use get_market_phase::*;
 pub use init_allowed_mint::*;
+pub use init_audit_log::*;
 pub use init_comp_defs::*;
 pub use init_platform_config::*;
 pub use init_stake_account::*;
+pub use init_user_portfolio::*;
+pub use issue_participant_pass::*;
+pub use migrate_stake::*;
 pub use open_market::*;
+pub use remove_market_option::*;
 pub use resolve_market::*;
 pub use reveal_stake::*;
+pub use reveal_stake_batch::*;
 pub use set_fee_claim_authority::*;
+pub use set_gate_mint::*;
+pub use set_loser_rebate::*;
+pub use set_market_category::*;
+pub use set_participants_restricted::*;
+pub use set_participation_threshold::*;
+pub use set_platform_paused::*;
+pub use set_reward_vesting::*;
+pub use set_successor_market::*;
+pub use set_transfer_hook_programs::*;
 pub use set_update_authority::*;
 pub use set_winning_option::*;
 pub use stake::*;
 pub use unstake::*;
+pub use update_market_timing::*;
 pub use update_platform_config::*;
+pub use verify_stake_disclosure::*;
 pub use withdraw_reward::*;
+
+use crate::constants::MAX_ACCOUNTS_CONTEXT_SIZE;
+
+// Contexts are deserialized on the 4KB BPF stack, so accounts are boxed. This fails to compile
+// if a context outgrows the budget, which almost always means an unboxed account was added.
+macro_rules! assert_context_size {
+    ($($ctx:ident),* $(,)?) => {
+        $(const _: () = assert!(core::mem::size_of::<$ctx<'static>>() <= MAX_ACCOUNTS_CONTEXT_SIZE);)*
+    };
+}
+
+assert_context_size!(
+    AcceptUpdateAuthority,
+    AddMarketOption,
+    AddReward,
+    CancelConfigChange,
+    ClaimCreatorFees,
+    ClaimFees,
+    ClaimRewardTranche,
+    CloseOptionAccount,
+    CloseStakeAccount,
+    CloseStuckStakeAccount,
+    CreateCategory,
+    CreateMarket,
+    EmergencyResolve,
+    EndRevealPeriod,
+    ExecuteConfigChange,
+    FinalizeRevealStake,
+    FinalizeRevealStakes,
+    FundCrankBudget,
+    GetClaimable,
+    GetMarketPhase,
+    InitAllowedMint,
+    InitAuditLog,
+    InitPlatformConfig,
+    InitStakeAccount,
+    InitUserPortfolio,
+    InitializeCompDefs,
+    IssueParticipantPass,
+    MigrateStake,
+    OpenMarket,
+    RemoveMarketOption,
+    ResolveMarket,
+    RevealStake,
+    RevealStakeCallback,
+    RevealStakeBatch,
+    RevealStakeBatchCallback,
+    RevealStakeBatchCompDef,
+    RevealStakeCompDef,
+    RevokeParticipantPass,
+    SetFeeClaimAuthority,
+    SetGateMint,
+    SetLoserRebate,
+    SetMarketCategory,
+    SetParticipantsRestricted,
+    SetParticipationThreshold,
+    SetPlatformPaused,
+    SetRewardVesting,
+    SetSuccessorMarket,
+    SetTransferHookPrograms,
+    SetUpdateAuthority,
+    SetWinningOption,
+    Stake,
+    StakeCallback,
+    StakeCompDef,
+    Unstake,
+    UpdateMarketTiming,
+    UpdatePlatformConfig,
+    VerifyStakeDisclosure,
+    VerifyStakeDisclosureCallback,
+    VerifyStakeDisclosureCompDef,
+    WithdrawCrankBudget,
+    WithdrawReward,
+);
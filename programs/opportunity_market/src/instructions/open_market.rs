@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::MAX_TIME_TO_STAKE_SECONDS;
+use crate::constants::{MAX_TIME_TO_STAKE_SECONDS, SCHEMA_VERSION};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, MarketOpenedEvent};
-use crate::state::{OpportunityMarket, PlatformConfig};
+use crate::state::{AuditInstruction, AuditLog, OpportunityMarket, PlatformConfig};
 
 #[derive(Accounts)]
 pub struct OpenMarket<'info> {
@@ -13,11 +13,26 @@ pub struct OpenMarket<'info> {
         mut,
         has_one = market_authority @ ErrorCode::Unauthorized,
         constraint = market.stake_end_timestamp.is_none() @ ErrorCode::MarketAlreadyOpen,
+        constraint = !market.emergency_voided @ ErrorCode::MarketVoid,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
-    pub market: Account<'info, OpportunityMarket>,
+    pub market: Box<Account<'info, OpportunityMarket>>,
 
-    #[account(address = market.platform @ ErrorCode::Unauthorized)]
-    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        address = market.platform @ ErrorCode::Unauthorized,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// Required when the market keeps an audit log.
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
 pub fn open_market(ctx: Context<OpenMarket>, time_to_stake: u64) -> Result<()> {
@@ -38,11 +53,22 @@ pub fn open_market(ctx: Context<OpenMarket>, time_to_stake: u64) -> Result<()> {
 
     market.stake_end_timestamp = Some(stake_end_timestamp);
 
-    emit_ts!(MarketOpenedEvent {
-        market: market.key(),
-        creator: market.creator,
-        stake_end_timestamp: stake_end_timestamp,
-    });
+    market.record_audit(
+        market.key(),
+        ctx.accounts.audit_log.as_ref(),
+        ctx.accounts.market_authority.key(),
+        AuditInstruction::OpenMarket,
+        0,
+    )?;
+
+    emit_ts!(
+        ctx,
+        MarketOpenedEvent {
+            market: market.key(),
+            creator: market.creator,
+            stake_end_timestamp: stake_end_timestamp,
+        }
+    );
 
     Ok(())
 }
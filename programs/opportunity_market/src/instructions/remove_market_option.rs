@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{OPTION_SEED, SCHEMA_VERSION};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, MarketOptionRemovedEvent};
+use crate::state::{OpportunityMarket, OpportunityMarketOption};
+
+// Nobody can have staked before the market opens, so an option can still be withdrawn then.
+// Rent goes back to whoever added the option.
+#[derive(Accounts)]
+#[instruction(option_id: u64)]
+pub struct RemoveMarketOption<'info> {
+    pub signer: Signer<'info>,
+
+    /// CHECK: Receives the option's rent; checked against option.creator.
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = market.stake_end_timestamp.is_none() @ ErrorCode::MarketAlreadyOpen,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [OPTION_SEED, market.key().as_ref(), &option_id.to_le_bytes()],
+        bump = option.bump,
+        has_one = creator @ ErrorCode::CreatorMismatch,
+        constraint = signer.key() == market.creator || signer.key() == option.creator @ ErrorCode::Unauthorized,
+    )]
+    pub option: Box<Account<'info, OpportunityMarketOption>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn remove_market_option(ctx: Context<RemoveMarketOption>, option_id: u64) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    market.total_options = market
+        .total_options
+        .checked_sub(1)
+        .ok_or(ErrorCode::Overflow)?;
+
+    emit_ts!(
+        ctx,
+        MarketOptionRemovedEvent {
+            option: ctx.accounts.option.key(),
+            market: market.key(),
+            signer: ctx.accounts.signer.key(),
+            id: option_id,
+        }
+    );
+
+    Ok(())
+}
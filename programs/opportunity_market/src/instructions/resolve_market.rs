@@ -1,21 +1,46 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{MARKET_RESULT_SEED, MAX_RESULT_OPTIONS, SCHEMA_VERSION};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, MarketResolvedEvent};
-use crate::state::OpportunityMarket;
+use crate::state::{AuditInstruction, AuditLog, MarketResult, OpportunityMarket, OptionResult};
 
+// Remaining accounts: every option with a reward_bp set, so the result lists all winners.
 #[derive(Accounts)]
 pub struct ResolveMarket<'info> {
+    #[account(mut)]
     pub market_authority: Signer<'info>,
 
     #[account(
         mut,
         has_one = market_authority @ ErrorCode::Unauthorized,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
-    pub market: Account<'info, OpportunityMarket>,
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    #[account(
+        init,
+        payer = market_authority,
+        space = 8 + MarketResult::INIT_SPACE,
+        seeds = [MARKET_RESULT_SEED, market.key().as_ref()],
+        bump,
+    )]
+    pub market_result: Box<Account<'info, MarketResult>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Required when the market keeps an audit log.
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
-pub fn resolve_market(ctx: Context<ResolveMarket>) -> Result<()> {
+pub fn resolve_market<'info>(ctx: Context<'info, ResolveMarket<'info>>) -> Result<()> {
     let market = &mut ctx.accounts.market;
 
     require!(
@@ -35,6 +60,7 @@ pub fn resolve_market(ctx: Context<ResolveMarket>) -> Result<()> {
         current_timestamp >= stake_end,
         ErrorCode::TimeWindowMismatch,
     );
+    require!(!market.is_void(current_timestamp), ErrorCode::MarketVoid);
 
     let select_deadline = stake_end
         .checked_add(market.market_resolution_deadline_seconds)
@@ -44,12 +70,59 @@ pub fn resolve_market(ctx: Context<ResolveMarket>) -> Result<()> {
         ErrorCode::SelectOptionsDeadlinePassed,
     );
 
+    // Winners must be distinct and account for the whole allocation, so none is left out.
+    require!(
+        ctx.remaining_accounts.len() <= MAX_RESULT_OPTIONS,
+        ErrorCode::InvalidParameters
+    );
+    let market_key = market.key();
+    let mut winning_options = Vec::with_capacity(ctx.remaining_accounts.len());
+    let mut allocation: u16 = 0;
+    for option_info in ctx.remaining_accounts {
+        let result = OptionResult::from_option_account(option_info, &market_key)?;
+        require!(
+            winning_options
+                .iter()
+                .all(|o: &OptionResult| o.option_id != result.option_id),
+            ErrorCode::InvalidParameters
+        );
+        allocation = allocation
+            .checked_add(result.reward_bp)
+            .ok_or(ErrorCode::Overflow)?;
+        winning_options.push(result);
+    }
+    require!(
+        allocation == market.winning_option_allocation,
+        ErrorCode::InvalidParameters
+    );
+
     market.resolved_at_timestamp = Some(current_timestamp);
 
-    emit_ts!(MarketResolvedEvent {
-        market: market.key(),
-        market_authority: ctx.accounts.market_authority.key(),
-    });
+    let market_result = &mut ctx.accounts.market_result;
+    market_result.schema_version = SCHEMA_VERSION;
+    market_result.bump = ctx.bumps.market_result;
+    market_result.market = market_key;
+    market_result.resolver = ctx.accounts.market_authority.key();
+    market_result.resolved_at_timestamp = current_timestamp;
+    market_result.resolved_slot = clock.slot;
+    market_result.reveal_ended = false;
+    market_result.winning_options = winning_options;
+
+    market.record_audit(
+        market.key(),
+        ctx.accounts.audit_log.as_ref(),
+        ctx.accounts.market_authority.key(),
+        AuditInstruction::ResolveMarket,
+        0,
+    )?;
+
+    emit_ts!(
+        ctx,
+        MarketResolvedEvent {
+            market: market.key(),
+            market_authority: ctx.accounts.market_authority.key(),
+        }
+    );
 
     Ok(())
 }
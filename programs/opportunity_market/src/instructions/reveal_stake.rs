@@ -2,66 +2,66 @@ use anchor_lang::prelude::*;
 use arcium_anchor::prelude::*;
 use arcium_client::idl::arcium::types::CallbackAccount;
 
-use crate::constants::STAKE_ACCOUNT_SEED;
+use crate::arcium_accounts::arcium_queue_accounts;
+use crate::computation::queued_mpc_fee;
+use crate::constants::{
+    ENCRYPTED_OPTION_LEN, PROGRAM_METRICS_SEED, SCHEMA_VERSION,
+    STAKE_ACCOUNT_ENCRYPTED_OPTION_OFFSET, STAKE_ACCOUNT_SEED,
+};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, StakeRevealedEvent};
-use crate::state::{OpportunityMarket, StakeAccount};
+use crate::instructions::finalize_reveal_stake::reimburse_mpc_fee;
+use crate::state::{
+    AuditInstruction, AuditLog, OpportunityMarket, PlatformConfig, ProgramMetrics, StakeAccount,
+};
 use crate::COMP_DEF_OFFSET_REVEAL_STAKE;
 use crate::{ArciumSignerAccount, ID, ID_CONST};
 
-#[queue_computation_accounts("reveal_stake", signer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64, stake_account_id: u32)]
-pub struct RevealStake<'info> {
-    #[account(mut)]
-    pub signer: Signer<'info>,
+arcium_queue_accounts!(
+    "reveal_stake",
+    signer,
+    COMP_DEF_OFFSET_REVEAL_STAKE,
+    computation_offset,
+    #[instruction(computation_offset: u64, stake_account_id: u32)]
+    pub struct RevealStake<'info> {
+        #[account(mut)]
+        pub signer: Signer<'info>,
 
-    /// CHECK: Any account, this operation is permissionless.
-    pub owner: UncheckedAccount<'info>,
+        /// CHECK: Any account, this operation is permissionless.
+        pub owner: UncheckedAccount<'info>,
 
-    pub market: Box<Account<'info, OpportunityMarket>>,
+        #[account(
+            mut,
+            constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub market: Box<Account<'info, OpportunityMarket>>,
 
-    #[account(
-        mut,
-        seeds = [STAKE_ACCOUNT_SEED, owner.key().as_ref(), market.key().as_ref(), &stake_account_id.to_le_bytes()],
-        bump = stake_account.bump,
-        constraint = stake_account.revealed_option.is_none() @ ErrorCode::AlreadyRevealed,
-        constraint = stake_account.pending_stake_computation.is_none() || stake_account.pending_reveal @ ErrorCode::Locked,
-    )]
-    pub stake_account: Box<Account<'info, StakeAccount>>,
+        #[account(
+            address = market.platform @ ErrorCode::Unauthorized,
+            constraint = !platform_config.paused @ ErrorCode::PlatformPaused,
+            constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub platform_config: Box<Account<'info, PlatformConfig>>,
 
-    // Arcium accounts
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = signer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
-    #[account(address = derive_mxe_pda!())]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut, address = derive_mempool_pda!(mxe_account))]
-    /// CHECK: mempool_account
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(mut, address = derive_execpool_pda!(mxe_account))]
-    /// CHECK: executing_pool
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account))]
-    /// CHECK: computation_account
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_STAKE))]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(mut, address = derive_cluster_pda!(mxe_account))]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
-    pub pool_account: Account<'info, FeePool>,
-    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
-    pub clock_account: Account<'info, ClockAccount>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
+        #[account(
+            mut,
+            seeds = [STAKE_ACCOUNT_SEED, owner.key().as_ref(), market.key().as_ref(), &stake_account_id.to_le_bytes()],
+            bump = stake_account.bump,
+            constraint = stake_account.revealed_option.is_none() @ ErrorCode::AlreadyRevealed,
+            constraint = !stake_account.migrated @ ErrorCode::StakeMigrated,
+            constraint = stake_account.pending_stake_computation.is_none() || stake_account.pending_reveal @ ErrorCode::Locked,
+            constraint = stake_account.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub stake_account: Box<Account<'info, StakeAccount>>,
+
+        /// Required when the market keeps an audit log.
+        #[account(mut)]
+        pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+
+        #[account(mut, seeds = [PROGRAM_METRICS_SEED], bump = program_metrics.bump)]
+        pub program_metrics: Box<Account<'info, ProgramMetrics>>,
+    }
+);
 
 // This operation is permissionless:
 // after the staking period has ended and an option has been selected, anyone can reveal anyones vote.
@@ -69,6 +69,7 @@ pub fn reveal_stake(
     ctx: Context<RevealStake>,
     computation_offset: u64,
     _stake_account_id: u32,
+    cu_price_micro: Option<u64>,
 ) -> Result<()> {
     let market = &ctx.accounts.market;
 
@@ -80,7 +81,14 @@ pub fn reveal_stake(
     let stake_account_key = ctx.accounts.stake_account.key();
     let stake_account_nonce = ctx.accounts.stake_account.state_nonce;
 
+    let reveal_requeued = ctx.accounts.stake_account.pending_reveal;
     ctx.accounts.stake_account.pending_reveal = true;
+    ctx.accounts
+        .stake_account
+        .consume_computation_offset(&stake_account_key, computation_offset)?;
+    ctx.accounts
+        .stake_account
+        .queue_computation_offset(computation_offset);
 
     let user_pubkey = ctx.accounts.stake_account.user_pubkey;
 
@@ -89,11 +97,29 @@ pub fn reveal_stake(
         // Stake account encrypted option (Enc<Shared, SelectedOption>)
         .x25519_pubkey(user_pubkey)
         .plaintext_u128(stake_account_nonce)
-        .account(stake_account_key, 8, 32)
+        .account(
+            stake_account_key,
+            STAKE_ACCOUNT_ENCRYPTED_OPTION_OFFSET,
+            ENCRYPTED_OPTION_LEN,
+        )
         .build();
 
+    ctx.accounts.market.record_audit(
+        ctx.accounts.market.key(),
+        ctx.accounts.audit_log.as_ref(),
+        ctx.accounts.signer.key(),
+        AuditInstruction::RevealStake,
+        computation_offset,
+    )?;
+
+    let cu_price_micro = ctx
+        .accounts
+        .platform_config
+        .cu_price_micro(cu_price_micro)?;
+    ctx.accounts.program_metrics.record_queued();
+    ctx.accounts.program_metrics.reveals = ctx.accounts.program_metrics.reveals.saturating_add(1);
+    let payer_lamports = ctx.accounts.signer.lamports();
     // Queue computation with callback
-    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
     queue_computation(
         ctx.accounts,
         computation_offset,
@@ -101,15 +127,47 @@ pub fn reveal_stake(
         vec![RevealStakeCallback::callback_ix(
             computation_offset,
             &ctx.accounts.mxe_account,
-            &[CallbackAccount {
-                pubkey: stake_account_key,
-                is_writable: true,
-            }],
+            &[
+                CallbackAccount {
+                    pubkey: stake_account_key,
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.program_metrics.key(),
+                    is_writable: true,
+                },
+                // #[event_cpi] accounts
+                CallbackAccount {
+                    pubkey: crate::EVENT_AUTHORITY_AND_BUMP.0,
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: crate::ID,
+                    is_writable: false,
+                },
+            ],
         )?],
         1,
-        0,
+        cu_price_micro,
     )?;
 
+    let mpc_fee = queued_mpc_fee(
+        payer_lamports,
+        ctx.accounts.signer.lamports(),
+        ctx.accounts.computation_account.lamports(),
+    );
+    ctx.accounts.program_metrics.record_mpc_fee(mpc_fee);
+    // Only a first attempt is refunded, so re-queueing a pending reveal can't drain the budget.
+    if !reveal_requeued {
+        let market_info = ctx.accounts.market.to_account_info();
+        reimburse_mpc_fee(
+            &mut ctx.accounts.market,
+            &market_info,
+            &ctx.accounts.signer.to_account_info(),
+            mpc_fee,
+        )?;
+    }
+
     Ok(())
 }
 
@@ -130,16 +188,32 @@ pub struct RevealStakeCallback<'info> {
     pub instructions_sysvar: UncheckedAccount<'info>,
 
     // Callback accounts
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = stake_account.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
     pub stake_account: Box<Account<'info, StakeAccount>>,
+    #[account(mut, seeds = [PROGRAM_METRICS_SEED], bump = program_metrics.bump)]
+    pub program_metrics: Box<Account<'info, ProgramMetrics>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
 pub fn reveal_stake_callback(
     ctx: Context<RevealStakeCallback>,
     output: SignedComputationOutputs<RevealStakeOutput>,
 ) -> Result<()> {
-    // On failure, revert so the account stays locked ith pending_reveal=true,
+    // On abort, count it and leave the account locked with pending_reveal=true,
     // allowing the user to retry reveal_stake
+    if let SignedComputationOutputs::Failure(_) = output {
+        ctx.accounts.program_metrics.record_callback(false);
+        return Ok(());
+    }
+
     let revealed_option = match output.verify_output(
         &ctx.accounts.cluster_account,
         &ctx.accounts.computation_account,
@@ -148,27 +222,48 @@ pub fn reveal_stake_callback(
         Err(e) => return Err(e),
     };
 
+    let computation_account = ctx.accounts.computation_account.key();
+    apply_reveal(
+        &mut ctx.accounts.stake_account,
+        computation_account,
+        &ctx.accounts.mxe_account,
+        revealed_option,
+    )?;
+
+    ctx.accounts.program_metrics.record_callback(true);
+
+    emit_ts!(
+        ctx,
+        StakeRevealedEvent {
+            user: ctx.accounts.stake_account.owner,
+            market: ctx.accounts.stake_account.market,
+            stake_account: ctx.accounts.stake_account.key(),
+            stake_account_id: ctx.accounts.stake_account.id,
+            stake_amount: ctx.accounts.stake_account.amount,
+            selected_option: revealed_option,
+        }
+    );
+
+    Ok(())
+}
+
+/// Records a revealed option on the stake account the reveal was queued for.
+pub(crate) fn apply_reveal(
+    stake_account: &mut StakeAccount,
+    computation_account: Pubkey,
+    mxe_account: &MXEAccount,
+    revealed_option: u64,
+) -> Result<()> {
     // Only run on the queue-time stake_account.
     // A late callback delivered after close_stake_account + re-init would see pending_reveal=false
     require!(
-        ctx.accounts.stake_account.pending_reveal
-            && ctx.accounts.stake_account.revealed_option.is_none(),
+        stake_account.pending_reveal && stake_account.revealed_option.is_none(),
         ErrorCode::InvalidAccountState
     );
 
-    ctx.accounts.stake_account.pending_reveal = false;
-
-    // Set revealed option
-    ctx.accounts.stake_account.revealed_option = Some(revealed_option);
-
-    emit_ts!(StakeRevealedEvent {
-        user: ctx.accounts.stake_account.owner,
-        market: ctx.accounts.stake_account.market,
-        stake_account: ctx.accounts.stake_account.key(),
-        stake_account_id: ctx.accounts.stake_account.id,
-        stake_amount: ctx.accounts.stake_account.amount,
-        selected_option: revealed_option,
-    });
+    stake_account.apply_callback(computation_account, mxe_account)?;
+    stake_account.pending_reveal = false;
+    stake_account.revealed_option = Some(revealed_option);
 
     Ok(())
 }
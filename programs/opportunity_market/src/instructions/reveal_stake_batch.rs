@@ -0,0 +1,316 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+
+use crate::arcium_accounts::arcium_queue_accounts;
+use crate::computation::queued_mpc_fee;
+use crate::constants::{
+    ENCRYPTED_OPTION_LEN, PROGRAM_METRICS_SEED, REVEAL_BATCH_SIZE, SCHEMA_VERSION,
+    STAKE_ACCOUNT_ENCRYPTED_OPTION_OFFSET,
+};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, StakeRevealedEvent};
+use crate::instructions::finalize_reveal_stake::reimburse_mpc_fee;
+use crate::instructions::reveal_stake::apply_reveal;
+use crate::state::{
+    AuditInstruction, AuditLog, OpportunityMarket, PlatformConfig, ProgramMetrics, StakeAccount,
+};
+use crate::COMP_DEF_OFFSET_REVEAL_STAKE_BATCH;
+use crate::{ArciumSignerAccount, ID, ID_CONST};
+
+arcium_queue_accounts!(
+    "reveal_stake_batch",
+    signer,
+    COMP_DEF_OFFSET_REVEAL_STAKE_BATCH,
+    computation_offset,
+    #[instruction(computation_offset: u64)]
+    pub struct RevealStakeBatch<'info> {
+        #[account(mut)]
+        pub signer: Signer<'info>,
+
+        #[account(
+            mut,
+            constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub market: Box<Account<'info, OpportunityMarket>>,
+
+        #[account(
+            address = market.platform @ ErrorCode::Unauthorized,
+            constraint = !platform_config.paused @ ErrorCode::PlatformPaused,
+            constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+        /// Required when the market keeps an audit log.
+        #[account(mut)]
+        pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+
+        #[account(mut, seeds = [PROGRAM_METRICS_SEED], bump = program_metrics.bump)]
+        pub program_metrics: Box<Account<'info, ProgramMetrics>>,
+    }
+);
+
+// Permissionless like reveal_stake, but one computation reveals up to REVEAL_BATCH_SIZE stake
+// accounts of the market, passed as writable remaining accounts. A shorter batch pads the
+// circuit inputs with its first account, and the callback ignores the padded outputs.
+pub fn reveal_stake_batch<'info>(
+    ctx: Context<'info, RevealStakeBatch<'info>>,
+    computation_offset: u64,
+    cu_price_micro: Option<u64>,
+) -> Result<()> {
+    let market_key = ctx.accounts.market.key();
+    require!(
+        ctx.accounts.market.resolved_at_timestamp.is_some(),
+        ErrorCode::MarketNotResolved,
+    );
+    let batch_size = ctx.remaining_accounts.len();
+    require!(
+        (1..=REVEAL_BATCH_SIZE).contains(&batch_size),
+        ErrorCode::InvalidParameters
+    );
+
+    let mut args = ArgBuilder::new();
+    let mut padding = None;
+    let mut reveal_requeued = false;
+    let mut callback_accounts = Vec::with_capacity(REVEAL_BATCH_SIZE + 3);
+    for (i, stake_account_info) in ctx.remaining_accounts.iter().enumerate() {
+        require!(
+            ctx.remaining_accounts[..i]
+                .iter()
+                .all(|other| other.key() != stake_account_info.key()),
+            ErrorCode::InvalidParameters
+        );
+
+        let mut stake_account = Account::<StakeAccount>::try_from(stake_account_info)?;
+        require!(
+            stake_account.schema_version == SCHEMA_VERSION,
+            ErrorCode::SchemaVersionMismatch
+        );
+        require_keys_eq!(
+            stake_account.market,
+            market_key,
+            ErrorCode::InvalidAccountState
+        );
+        require!(
+            stake_account.revealed_option.is_none(),
+            ErrorCode::AlreadyRevealed
+        );
+        require!(!stake_account.migrated, ErrorCode::StakeMigrated);
+        require!(
+            stake_account.pending_stake_computation.is_none() || stake_account.pending_reveal,
+            ErrorCode::Locked
+        );
+
+        reveal_requeued |= stake_account.pending_reveal;
+        stake_account.pending_reveal = true;
+        // The batch's offset is derived from its first stake account.
+        if i == 0 {
+            stake_account.consume_computation_offset(stake_account_info.key, computation_offset)?;
+        }
+        stake_account.queue_computation_offset(computation_offset);
+        stake_account.exit(&crate::ID)?;
+
+        // Stake account encrypted option (Enc<Shared, SelectedOption>)
+        let input = (
+            stake_account.user_pubkey,
+            stake_account.state_nonce,
+            stake_account_info.key(),
+        );
+        padding.get_or_insert(input);
+        args = batch_input(args, input);
+        callback_accounts.push(CallbackAccount {
+            pubkey: stake_account_info.key(),
+            is_writable: true,
+        });
+    }
+    // Unused slots: padded circuit inputs, and the program ID for the absent callback accounts.
+    for _ in batch_size..REVEAL_BATCH_SIZE {
+        args = batch_input(args, padding.ok_or(ErrorCode::InvalidParameters)?);
+        callback_accounts.push(CallbackAccount {
+            pubkey: crate::ID,
+            is_writable: false,
+        });
+    }
+    callback_accounts.extend([
+        CallbackAccount {
+            pubkey: ctx.accounts.program_metrics.key(),
+            is_writable: true,
+        },
+        // #[event_cpi] accounts
+        CallbackAccount {
+            pubkey: crate::EVENT_AUTHORITY_AND_BUMP.0,
+            is_writable: false,
+        },
+        CallbackAccount {
+            pubkey: crate::ID,
+            is_writable: false,
+        },
+    ]);
+
+    ctx.accounts.market.record_audit(
+        market_key,
+        ctx.accounts.audit_log.as_ref(),
+        ctx.accounts.signer.key(),
+        AuditInstruction::RevealStake,
+        computation_offset,
+    )?;
+
+    let cu_price_micro = ctx
+        .accounts
+        .platform_config
+        .cu_price_micro(cu_price_micro)?;
+    ctx.accounts.program_metrics.record_queued();
+    ctx.accounts.program_metrics.reveals = ctx
+        .accounts
+        .program_metrics
+        .reveals
+        .saturating_add(batch_size as u64);
+    let payer_lamports = ctx.accounts.signer.lamports();
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args.build(),
+        vec![RevealStakeBatchCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &callback_accounts,
+        )?],
+        1,
+        cu_price_micro,
+    )?;
+
+    let mpc_fee = queued_mpc_fee(
+        payer_lamports,
+        ctx.accounts.signer.lamports(),
+        ctx.accounts.computation_account.lamports(),
+    );
+    ctx.accounts.program_metrics.record_mpc_fee(mpc_fee);
+    // Only a first attempt is refunded, so re-queueing a pending reveal can't drain the budget.
+    if !reveal_requeued {
+        let market_info = ctx.accounts.market.to_account_info();
+        reimburse_mpc_fee(
+            &mut ctx.accounts.market,
+            &market_info,
+            &ctx.accounts.signer.to_account_info(),
+            mpc_fee,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn batch_input(
+    args: ArgBuilder,
+    (user_pubkey, state_nonce, stake_account): ([u8; 32], u128, Pubkey),
+) -> ArgBuilder {
+    args.x25519_pubkey(user_pubkey)
+        .plaintext_u128(state_nonce)
+        .account(
+            stake_account,
+            STAKE_ACCOUNT_ENCRYPTED_OPTION_OFFSET,
+            ENCRYPTED_OPTION_LEN,
+        )
+}
+
+#[callback_accounts("reveal_stake_batch")]
+#[derive(Accounts)]
+pub struct RevealStakeBatchCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_STAKE_BATCH))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::arcium_anchor::solana_instructions_sysvar::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    // Callback accounts, one per REVEAL_BATCH_SIZE; absent for the slots a short batch pads.
+    #[account(
+        mut,
+        constraint = stake_account_0.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub stake_account_0: Box<Account<'info, StakeAccount>>,
+    #[account(
+        mut,
+        constraint = stake_account_1.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub stake_account_1: Option<Box<Account<'info, StakeAccount>>>,
+    #[account(
+        mut,
+        constraint = stake_account_2.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub stake_account_2: Option<Box<Account<'info, StakeAccount>>>,
+    #[account(
+        mut,
+        constraint = stake_account_3.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub stake_account_3: Option<Box<Account<'info, StakeAccount>>>,
+    #[account(mut, seeds = [PROGRAM_METRICS_SEED], bump = program_metrics.bump)]
+    pub program_metrics: Box<Account<'info, ProgramMetrics>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn reveal_stake_batch_callback(
+    ctx: Context<RevealStakeBatchCallback>,
+    output: SignedComputationOutputs<RevealStakeBatchOutput>,
+) -> Result<()> {
+    // On abort, the accounts stay pending_reveal and can be revealed again, alone or in a batch.
+    if let SignedComputationOutputs::Failure(_) = output {
+        ctx.accounts.program_metrics.record_callback(false);
+        return Ok(());
+    }
+
+    let res = match output.verify_output(
+        &ctx.accounts.cluster_account,
+        &ctx.accounts.computation_account,
+    ) {
+        Ok(RevealStakeBatchOutput { field_0 }) => field_0,
+        Err(e) => return Err(e),
+    };
+
+    let computation_account = ctx.accounts.computation_account.key();
+    let revealed_options = [res.field_0, res.field_1, res.field_2, res.field_3];
+    for (i, revealed_option) in revealed_options.into_iter().enumerate() {
+        let stake_account = match i {
+            0 => Some(&mut ctx.accounts.stake_account_0),
+            1 => ctx.accounts.stake_account_1.as_mut(),
+            2 => ctx.accounts.stake_account_2.as_mut(),
+            _ => ctx.accounts.stake_account_3.as_mut(),
+        };
+        // Padded slot of a short batch.
+        let Some(stake_account) = stake_account else {
+            continue;
+        };
+        apply_reveal(
+            stake_account,
+            computation_account,
+            &ctx.accounts.mxe_account,
+            revealed_option,
+        )?;
+
+        emit_ts!(
+            ctx,
+            StakeRevealedEvent {
+                user: stake_account.owner,
+                market: stake_account.market,
+                stake_account: stake_account.key(),
+                stake_account_id: stake_account.id,
+                stake_amount: stake_account.amount,
+                selected_option: revealed_option,
+            }
+        );
+    }
+
+    ctx.accounts.program_metrics.record_callback(true);
+
+    Ok(())
+}
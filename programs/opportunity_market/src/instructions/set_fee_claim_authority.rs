@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::SCHEMA_VERSION;
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, FeeClaimAuthorityChangedEvent};
 use crate::state::PlatformConfig;
@@ -11,11 +12,18 @@ pub struct SetFeeClaimAuthority<'info> {
     #[account(
         mut,
         has_one = update_authority @ ErrorCode::Unauthorized,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
-    pub platform_config: Account<'info, PlatformConfig>,
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
 
     /// CHECK: Address-only; becomes the new fee-claim authority.
     pub new_fee_claim_authority: UncheckedAccount<'info>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
 pub fn set_fee_claim_authority(ctx: Context<SetFeeClaimAuthority>) -> Result<()> {
@@ -23,11 +31,14 @@ pub fn set_fee_claim_authority(ctx: Context<SetFeeClaimAuthority>) -> Result<()>
     let new_value = ctx.accounts.new_fee_claim_authority.key();
     ctx.accounts.platform_config.fee_claim_authority = new_value;
 
-    emit_ts!(FeeClaimAuthorityChangedEvent {
-        platform_config: ctx.accounts.platform_config.key(),
-        old_value: old_value,
-        new_value: new_value,
-    });
+    emit_ts!(
+        ctx,
+        FeeClaimAuthorityChangedEvent {
+            platform_config: ctx.accounts.platform_config.key(),
+            old_value: old_value,
+            new_value: new_value,
+        }
+    );
 
     Ok(())
 }
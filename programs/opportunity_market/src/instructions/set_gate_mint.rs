@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    non_transferable::NonTransferable, BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as SplMint;
+use anchor_spl::token_interface::Mint;
+
+use crate::constants::SCHEMA_VERSION;
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, GateMintSetEvent};
+use crate::state::OpportunityMarket;
+
+// Fixed once the market opens. Omitting gate_mint removes the gate. The mint must be
+// non-transferable, so a gate token can't be passed between wallets to stake from each.
+#[derive(Accounts)]
+pub struct SetGateMint<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::Unauthorized,
+        constraint = market.stake_end_timestamp.is_none() @ ErrorCode::MarketAlreadyOpen,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    pub gate_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn set_gate_mint(ctx: Context<SetGateMint>) -> Result<()> {
+    if let Some(gate_mint) = ctx.accounts.gate_mint.as_ref() {
+        let mint_ai = gate_mint.to_account_info();
+        let mint_data = mint_ai.try_borrow_data()?;
+        let mint_with_ext = StateWithExtensions::<SplMint>::unpack(&mint_data)
+            .map_err(|_| error!(ErrorCode::InvalidMint))?;
+        require!(
+            mint_with_ext.get_extension::<NonTransferable>().is_ok(),
+            ErrorCode::InvalidMint
+        );
+    }
+
+    let gate_mint = ctx.accounts.gate_mint.as_ref().map(|mint| mint.key());
+    let market = &mut ctx.accounts.market;
+    market.gate_mint = gate_mint;
+
+    emit_ts!(
+        ctx,
+        GateMintSetEvent {
+            market: market.key(),
+            gate_mint: gate_mint,
+        }
+    );
+
+    Ok(())
+}
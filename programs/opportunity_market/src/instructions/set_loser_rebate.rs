@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_LOSER_REBATE_BP, SCHEMA_VERSION};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, LoserRebateSetEvent};
+use crate::state::OpportunityMarket;
+
+// Fixed once the market opens, so stakers know the split between winners and losers up front.
+#[derive(Accounts)]
+pub struct SetLoserRebate<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::Unauthorized,
+        constraint = market.stake_end_timestamp.is_none() @ ErrorCode::MarketAlreadyOpen,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn set_loser_rebate(ctx: Context<SetLoserRebate>, loser_rebate_bp: u16) -> Result<()> {
+    require!(
+        loser_rebate_bp <= MAX_LOSER_REBATE_BP,
+        ErrorCode::InvalidParameters
+    );
+
+    let market = &mut ctx.accounts.market;
+    market.loser_rebate_bp = loser_rebate_bp;
+
+    emit_ts!(
+        ctx,
+        LoserRebateSetEvent {
+            market: market.key(),
+            loser_rebate_bp: loser_rebate_bp,
+        }
+    );
+
+    Ok(())
+}
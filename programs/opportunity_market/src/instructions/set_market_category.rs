@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CATEGORY_MARKET_SEED, SCHEMA_VERSION};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, MarketCategorySetEvent};
+use crate::state::{Category, CategoryMarket, OpportunityMarket};
+
+// A market is listed once, before it opens, so a category's index only ever grows.
+#[derive(Accounts)]
+pub struct SetMarketCategory<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::Unauthorized,
+        constraint = market.stake_end_timestamp.is_none() @ ErrorCode::MarketAlreadyOpen,
+        constraint = market.category.is_none() @ ErrorCode::InvalidAccountState,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    #[account(
+        mut,
+        constraint = category.platform == market.platform @ ErrorCode::Unauthorized,
+    )]
+    pub category: Box<Account<'info, Category>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + CategoryMarket::INIT_SPACE,
+        seeds = [CATEGORY_MARKET_SEED, category.key().as_ref(), &category.market_count.to_le_bytes()],
+        bump,
+    )]
+    pub category_market: Box<Account<'info, CategoryMarket>>,
+
+    pub system_program: Program<'info, System>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn set_market_category(ctx: Context<SetMarketCategory>) -> Result<()> {
+    let category_key = ctx.accounts.category.key();
+    let index = ctx.accounts.category.market_count;
+
+    let entry = &mut ctx.accounts.category_market;
+    entry.schema_version = SCHEMA_VERSION;
+    entry.bump = ctx.bumps.category_market;
+    entry.category = category_key;
+    entry.index = index;
+    entry.market = ctx.accounts.market.key();
+
+    ctx.accounts.category.market_count = index.checked_add(1).ok_or(ErrorCode::Overflow)?;
+    ctx.accounts.market.category = Some(category_key);
+
+    emit_ts!(
+        ctx,
+        MarketCategorySetEvent {
+            market: ctx.accounts.market.key(),
+            category: category_key,
+            index: index,
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::SCHEMA_VERSION;
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, ParticipantsRestrictedSetEvent};
+use crate::state::OpportunityMarket;
+
+// Fixed once the market opens, so who may stake doesn't change under stakers' feet.
+#[derive(Accounts)]
+pub struct SetParticipantsRestricted<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::Unauthorized,
+        constraint = market.stake_end_timestamp.is_none() @ ErrorCode::MarketAlreadyOpen,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn set_participants_restricted(
+    ctx: Context<SetParticipantsRestricted>,
+    participants_restricted: bool,
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    market.participants_restricted = participants_restricted;
+
+    emit_ts!(
+        ctx,
+        ParticipantsRestrictedSetEvent {
+            market: market.key(),
+            participants_restricted: participants_restricted,
+        }
+    );
+
+    Ok(())
+}
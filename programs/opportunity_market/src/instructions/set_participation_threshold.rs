@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::SCHEMA_VERSION;
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, ParticipationThresholdSetEvent};
+use crate::state::OpportunityMarket;
+
+// Fixed once the market opens. A market below either minimum at stake end is void.
+#[derive(Accounts)]
+pub struct SetParticipationThreshold<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::Unauthorized,
+        constraint = market.stake_end_timestamp.is_none() @ ErrorCode::MarketAlreadyOpen,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn set_participation_threshold(
+    ctx: Context<SetParticipationThreshold>,
+    min_total_stake: u64,
+    min_participants: u32,
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    market.min_total_stake = min_total_stake;
+    market.min_participants = min_participants;
+
+    emit_ts!(
+        ctx,
+        ParticipationThresholdSetEvent {
+            market: market.key(),
+            min_total_stake: min_total_stake,
+            min_participants: min_participants,
+        }
+    );
+
+    Ok(())
+}
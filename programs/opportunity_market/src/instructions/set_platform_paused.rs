@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::SCHEMA_VERSION;
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, PlatformPausedEvent};
+use crate::state::PlatformConfig;
+
+#[derive(Accounts)]
+pub struct SetPlatformPaused<'info> {
+    pub update_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = update_authority @ ErrorCode::Unauthorized,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn set_platform_paused(ctx: Context<SetPlatformPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.platform_config.paused = paused;
+
+    emit_ts!(
+        ctx,
+        PlatformPausedEvent {
+            platform_config: ctx.accounts.platform_config.key(),
+            paused: paused,
+        }
+    );
+
+    Ok(())
+}
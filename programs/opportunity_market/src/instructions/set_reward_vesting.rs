@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_REWARD_TRANCHES, SCHEMA_VERSION};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, RewardVestingSetEvent};
+use crate::state::OpportunityMarket;
+
+// Vesting is fixed once the market opens, so stakers know the payout schedule up front.
+#[derive(Accounts)]
+pub struct SetRewardVesting<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::Unauthorized,
+        constraint = market.stake_end_timestamp.is_none() @ ErrorCode::MarketAlreadyOpen,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn set_reward_vesting(
+    ctx: Context<SetRewardVesting>,
+    reward_tranches: u8,
+    reward_tranche_interval_seconds: u64,
+) -> Result<()> {
+    require!(
+        (1..=MAX_REWARD_TRANCHES).contains(&reward_tranches),
+        ErrorCode::InvalidParameters
+    );
+    require!(
+        reward_tranches == 1 || reward_tranche_interval_seconds > 0,
+        ErrorCode::InvalidParameters
+    );
+
+    let market = &mut ctx.accounts.market;
+    market.reward_tranches = reward_tranches;
+    market.reward_tranche_interval_seconds = reward_tranche_interval_seconds;
+
+    emit_ts!(
+        ctx,
+        RewardVestingSetEvent {
+            market: market.key(),
+            reward_tranches: reward_tranches,
+            reward_tranche_interval_seconds: reward_tranche_interval_seconds,
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::SCHEMA_VERSION;
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, SuccessorMarketSetEvent};
+use crate::state::OpportunityMarket;
+
+// Points stakers of a superseded market at its replacement, which they can move to with
+// migrate_stake while this market is unresolved.
+#[derive(Accounts)]
+pub struct SetSuccessorMarket<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::Unauthorized,
+        constraint = market.resolved_at_timestamp.is_none() @ ErrorCode::WinnerAlreadySelected,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    #[account(
+        constraint = successor_market.key() != market.key() @ ErrorCode::InvalidParameters,
+        constraint = successor_market.mint == market.mint @ ErrorCode::InvalidParameters,
+        constraint = successor_market.platform == market.platform @ ErrorCode::InvalidParameters,
+        constraint = successor_market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub successor_market: Box<Account<'info, OpportunityMarket>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn set_successor_market(ctx: Context<SetSuccessorMarket>) -> Result<()> {
+    let successor_market = ctx.accounts.successor_market.key();
+    let market = &mut ctx.accounts.market;
+    market.successor_market = Some(successor_market);
+
+    emit_ts!(
+        ctx,
+        SuccessorMarketSetEvent {
+            market: market.key(),
+            successor_market: successor_market,
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_TRANSFER_HOOK_PROGRAMS, SCHEMA_VERSION};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, TransferHookProgramsChangedEvent};
+use crate::state::PlatformConfig;
+
+// Replaces the allowlist. Mints already allowed keep their hook program if it is removed.
+#[derive(Accounts)]
+pub struct SetTransferHookPrograms<'info> {
+    pub update_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = update_authority @ ErrorCode::Unauthorized,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn set_transfer_hook_programs(
+    ctx: Context<SetTransferHookPrograms>,
+    transfer_hook_programs: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        transfer_hook_programs.len() <= MAX_TRANSFER_HOOK_PROGRAMS,
+        ErrorCode::InvalidParameters
+    );
+    ctx.accounts.platform_config.transfer_hook_programs = transfer_hook_programs.clone();
+
+    emit_ts!(
+        ctx,
+        TransferHookProgramsChangedEvent {
+            platform_config: ctx.accounts.platform_config.key(),
+            transfer_hook_programs: transfer_hook_programs,
+        }
+    );
+
+    Ok(())
+}
@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::SCHEMA_VERSION;
 use crate::error::ErrorCode;
-use crate::events::{emit_ts, UpdateAuthorityChangedEvent};
+use crate::events::{emit_ts, UpdateAuthorityChangedEvent, UpdateAuthorityProposedEvent};
 use crate::state::PlatformConfig;
 
+// Two-step transfer so a mistyped address can't lock the platform. The new authority may be
+// a PDA (e.g. a multisig vault), in which case it accepts by signing through CPI.
 #[derive(Accounts)]
 pub struct SetUpdateAuthority<'info> {
     pub update_authority: Signer<'info>,
@@ -11,23 +14,221 @@ pub struct SetUpdateAuthority<'info> {
     #[account(
         mut,
         has_one = update_authority @ ErrorCode::Unauthorized,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
-    pub platform_config: Account<'info, PlatformConfig>,
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
 
-    /// CHECK: Address-only; becomes the new update authority.
+    /// CHECK: Address-only; nominated as the new update authority.
     pub new_authority: UncheckedAccount<'info>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
 pub fn set_update_authority(ctx: Context<SetUpdateAuthority>) -> Result<()> {
+    let pending = ctx.accounts.new_authority.key();
+    ctx.accounts.platform_config.pending_update_authority = Some(pending);
+
+    emit_ts!(
+        ctx,
+        UpdateAuthorityProposedEvent {
+            platform_config: ctx.accounts.platform_config.key(),
+            update_authority: ctx.accounts.update_authority.key(),
+            pending_update_authority: pending,
+        }
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptUpdateAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = platform_config.pending_update_authority.is_some() @ ErrorCode::NoPendingUpdateAuthority,
+        constraint = platform_config.pending_update_authority == Some(new_authority.key()) @ ErrorCode::Unauthorized,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn accept_update_authority(ctx: Context<AcceptUpdateAuthority>) -> Result<()> {
     let old_value = ctx.accounts.platform_config.update_authority;
     let new_value = ctx.accounts.new_authority.key();
     ctx.accounts.platform_config.update_authority = new_value;
+    ctx.accounts.platform_config.pending_update_authority = None;
 
-    emit_ts!(UpdateAuthorityChangedEvent {
-        platform_config: ctx.accounts.platform_config.key(),
-        old_value: old_value,
-        new_value: new_value,
-    });
+    emit_ts!(
+        ctx,
+        UpdateAuthorityChangedEvent {
+            platform_config: ctx.accounts.platform_config.key(),
+            old_value: old_value,
+            new_value: new_value,
+        }
+    );
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::FeeRates;
+    use std::collections::BTreeSet;
+
+    // A PDA admin (e.g. a multisig vault) signs through CPI with invoke_signed. The runtime then
+    // passes its off-curve address to this program as a signer owned by the calling program,
+    // which is what these accounts stand in for. Handlers emit events through CPI and read the
+    // clock, so only account validation runs here.
+
+    fn account(key: Pubkey, is_signer: bool, owner: Pubkey, data: Vec<u8>) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            is_signer,
+            true,
+            Box::leak(Box::new(1_000_000)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            key == crate::ID,
+        )
+    }
+
+    fn platform_config(update_authority: Pubkey, pending: Option<Pubkey>) -> Vec<u8> {
+        let config = PlatformConfig {
+            schema_version: 1,
+            bump: 255,
+            name: "platform".to_string(),
+            update_authority,
+            fee_claim_authority: update_authority,
+            fee_rates: FeeRates::new(0, 0, 0).unwrap(),
+            market_resolution_deadline_seconds: 0,
+            min_time_to_stake_seconds: 0,
+            reveal_authority: update_authority,
+            reveal_period_seconds: 0,
+            paused: false,
+            pending_update_authority: pending,
+            default_cu_price_micro: 0,
+            config_change_delay_seconds: 0,
+            transfer_hook_programs: vec![],
+        };
+        let mut data = Vec::new();
+        config.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    fn multisig_vault() -> Pubkey {
+        let (vault, _) = Pubkey::find_program_address(&[b"vault"], &Pubkey::new_unique());
+        assert!(!vault.is_on_curve());
+        vault
+    }
+
+    fn accounts(
+        signer: AccountInfo<'static>,
+        config: Vec<u8>,
+        extra: Option<Pubkey>,
+    ) -> &'static [AccountInfo<'static>] {
+        let mut infos = vec![
+            signer,
+            account(Pubkey::new_unique(), false, crate::ID, config),
+        ];
+        if let Some(new_authority) = extra {
+            infos.push(account(new_authority, false, Pubkey::default(), vec![]));
+        }
+        infos.push(account(
+            crate::EVENT_AUTHORITY_AND_BUMP.0,
+            false,
+            Pubkey::default(),
+            vec![],
+        ));
+        infos.push(account(crate::ID, false, Pubkey::default(), vec![]));
+        Box::leak(infos.into_boxed_slice())
+    }
+
+    fn set_update_authority(signer: AccountInfo<'static>, config: Vec<u8>) -> Result<()> {
+        let mut infos = accounts(signer, config, Some(Pubkey::new_unique()));
+        SetUpdateAuthority::try_accounts(
+            &crate::ID,
+            &mut infos,
+            &[],
+            &mut Default::default(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    fn accept_update_authority(signer: AccountInfo<'static>, config: Vec<u8>) -> Result<()> {
+        let mut infos = accounts(signer, config, None);
+        AcceptUpdateAuthority::try_accounts(
+            &crate::ID,
+            &mut infos,
+            &[],
+            &mut Default::default(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn pda_admin_can_nominate_by_signing_through_cpi() {
+        let vault = multisig_vault();
+        let signer = account(vault, true, Pubkey::new_unique(), vec![]);
+
+        assert!(set_update_authority(signer, platform_config(vault, None)).is_ok());
+    }
+
+    #[test]
+    fn pda_admin_must_sign() {
+        let vault = multisig_vault();
+        let signer = account(vault, false, Pubkey::new_unique(), vec![]);
+
+        let err = set_update_authority(signer, platform_config(vault, None)).unwrap_err();
+        assert_eq!(err, anchor_lang::error::ErrorCode::AccountNotSigner.into());
+    }
+
+    #[test]
+    fn only_the_update_authority_can_nominate() {
+        let signer = account(multisig_vault(), true, Pubkey::new_unique(), vec![]);
+
+        let err =
+            set_update_authority(signer, platform_config(multisig_vault(), None)).unwrap_err();
+        assert_eq!(err, ErrorCode::Unauthorized.into());
+    }
+
+    #[test]
+    fn pda_nominee_can_accept_by_signing_through_cpi() {
+        let vault = multisig_vault();
+        let signer = account(vault, true, Pubkey::new_unique(), vec![]);
+        let config = platform_config(Pubkey::new_unique(), Some(vault));
+
+        assert!(accept_update_authority(signer, config).is_ok());
+    }
+
+    #[test]
+    fn only_the_nominee_can_accept() {
+        let signer = account(multisig_vault(), true, Pubkey::new_unique(), vec![]);
+        let config = platform_config(Pubkey::new_unique(), Some(multisig_vault()));
+
+        let err = accept_update_authority(signer, config).unwrap_err();
+        assert_eq!(err, ErrorCode::Unauthorized.into());
+    }
+
+    #[test]
+    fn accept_requires_a_nomination() {
+        let vault = multisig_vault();
+        let signer = account(vault, true, Pubkey::new_unique(), vec![]);
+
+        let err = accept_update_authority(signer, platform_config(vault, None)).unwrap_err();
+        assert_eq!(err, ErrorCode::NoPendingUpdateAuthority.into());
+    }
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::OPTION_SEED;
+use crate::constants::{OPTION_SEED, SCHEMA_VERSION};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, WinningOptionSetEvent};
 use crate::state::{OpportunityMarket, OpportunityMarketOption};
@@ -13,6 +13,7 @@ pub struct SetWinningOption<'info> {
     #[account(
         mut,
         has_one = market_authority @ ErrorCode::Unauthorized,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
     pub market: Box<Account<'info, OpportunityMarket>>,
 
@@ -22,6 +23,12 @@ pub struct SetWinningOption<'info> {
         bump = option.bump,
     )]
     pub option: Box<Account<'info, OpportunityMarketOption>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
 pub fn set_winning_option(
@@ -47,6 +54,10 @@ pub fn set_winning_option(
         current_timestamp >= stake_end,
         ErrorCode::TimeWindowMismatch,
     );
+    require!(
+        !ctx.accounts.market.is_void(current_timestamp),
+        ErrorCode::MarketVoid
+    );
 
     let select_deadline = stake_end
         .checked_add(ctx.accounts.market.market_resolution_deadline_seconds)
@@ -70,14 +81,17 @@ pub fn set_winning_option(
     ctx.accounts.option.reward_bp = Some(reward_bp);
     ctx.accounts.market.winning_option_allocation = new_alloc;
 
-    emit_ts!(WinningOptionSetEvent {
-        market: ctx.accounts.market.key(),
-        market_authority: ctx.accounts.market_authority.key(),
-        option: ctx.accounts.option.key(),
-        option_id: ctx.accounts.option.id,
-        reward_bp: reward_bp,
-        winning_option_allocation: new_alloc,
-    });
+    emit_ts!(
+        ctx,
+        WinningOptionSetEvent {
+            market: ctx.accounts.market.key(),
+            market_authority: ctx.accounts.market_authority.key(),
+            option: ctx.accounts.option.key(),
+            option_id: ctx.accounts.option.id,
+            reward_bp: reward_bp,
+            winning_option_allocation: new_alloc,
+        }
+    );
 
     Ok(())
 }
@@ -1,104 +1,143 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
 use arcium_anchor::prelude::*;
 use arcium_client::idl::arcium::types::CallbackAccount;
 
-use crate::constants::STAKE_ACCOUNT_SEED;
+use crate::arcium_accounts::arcium_queue_accounts;
+use crate::computation::queued_mpc_fee;
+use crate::constants::{
+    DEPOSIT_RECEIPT_SEED, PARTICIPANT_PASS_SEED, PROGRAM_METRICS_SEED, SCHEMA_VERSION,
+    STAKE_ACCOUNT_SEED, USER_PORTFOLIO_SEED,
+};
 use crate::error::ErrorCode;
-use crate::events::{emit_ts, StakedEvent};
-use crate::state::{CollectedFees, OpportunityMarket, StakeAccount};
+use crate::events::{emit_ts, DepositReceiptRecordedEvent, StakedEvent};
+use crate::state::{
+    AuditInstruction, AuditLog, CollectedFees, DepositReceipt, OpportunityMarket, ParticipantPass,
+    PlatformConfig, ProgramMetrics, ReceiptKind, StakeAccount, UserPortfolio,
+};
+use crate::transfer::transfer_checked;
 use crate::COMP_DEF_OFFSET_STAKE;
 use crate::{ArciumSignerAccount, ID, ID_CONST};
 
-#[queue_computation_accounts("stake", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64, stake_account_id: u32)]
-pub struct Stake<'info> {
-    #[account(
-        constraint = signer.key() == stake_account.owner @ ErrorCode::Unauthorized,
+arcium_queue_accounts!(
+    "stake",
+    payer,
+    COMP_DEF_OFFSET_STAKE,
+    computation_offset,
+    #[instruction(
+        computation_offset: u64,
+        stake_account_id: u32,
+        amount: u64,
+        selected_option_ciphertext: [u8; 32],
+        input_nonce: u128,
+        authorized_reader_nonce: u128,
+        user_pubkey: [u8; 32],
+        state_nonce: u128,
+        client_id: [u8; 32],
     )]
-    pub signer: Signer<'info>,
+    pub struct Stake<'info> {
+        #[account(
+            constraint = signer.key() == stake_account.owner @ ErrorCode::Unauthorized,
+        )]
+        pub signer: Signer<'info>,
 
-    #[account(mut)]
-    pub payer: Signer<'info>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
 
-    #[account(
-        mut,
-        constraint = market.stake_end_timestamp.is_some() @ ErrorCode::MarketNotOpen,
-        constraint = market.resolved_at_timestamp.is_none() @ ErrorCode::WinnerAlreadySelected,
-    )]
-    pub market: Box<Account<'info, OpportunityMarket>>,
+        #[account(
+            mut,
+            constraint = market.stake_end_timestamp.is_some() @ ErrorCode::MarketNotOpen,
+            constraint = market.resolved_at_timestamp.is_none() @ ErrorCode::WinnerAlreadySelected,
+            constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub market: Box<Account<'info, OpportunityMarket>>,
 
-    #[account(
-        mut,
-        seeds = [STAKE_ACCOUNT_SEED, stake_account.owner.as_ref(), market.key().as_ref(), &stake_account_id.to_le_bytes()],
-        bump = stake_account.bump,
-        constraint = stake_account.staked_at_timestamp.is_none() @ ErrorCode::AlreadyStaked,
-        constraint = stake_account.unstaked_at_timestamp.is_none() @ ErrorCode::AlreadyUnstaked,
-        constraint = stake_account.pending_stake_computation.is_none() @ ErrorCode::Locked,
-    )]
-    pub stake_account: Box<Account<'info, StakeAccount>>,
+        #[account(
+            address = market.platform @ ErrorCode::Unauthorized,
+            constraint = !platform_config.paused @ ErrorCode::PlatformPaused,
+            constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub platform_config: Box<Account<'info, PlatformConfig>>,
 
-    // SPL token accounts
-    #[account(address = market.mint)]
-    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+        #[account(
+            mut,
+            seeds = [STAKE_ACCOUNT_SEED, stake_account.owner.as_ref(), market.key().as_ref(), &stake_account_id.to_le_bytes()],
+            bump = stake_account.bump,
+            constraint = stake_account.staked_at_timestamp.is_none() @ ErrorCode::AlreadyStaked,
+            constraint = stake_account.unstaked_at_timestamp.is_none() @ ErrorCode::AlreadyUnstaked,
+            constraint = stake_account.pending_stake_computation.is_none() @ ErrorCode::Locked,
+            constraint = stake_account.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub stake_account: Box<Account<'info, StakeAccount>>,
 
-    /// Funds the stake.
-    #[account(
-        mut,
-        token::mint = token_mint,
-        token::authority = signer,
-        token::token_program = token_program,
-    )]
-    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+        /// Optional receipt for integrators reconciling against their own ledger.
+        #[account(
+            init,
+            payer = payer,
+            space = 8 + DepositReceipt::INIT_SPACE,
+            seeds = [DEPOSIT_RECEIPT_SEED, signer.key().as_ref(), &client_id],
+            bump,
+        )]
+        pub deposit_receipt: Option<Box<Account<'info, DepositReceipt>>>,
 
-    #[account(
-        mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = market,
-        associated_token::token_program = token_program,
-    )]
-    pub market_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+        /// Optional position summary for the owner's wallet.
+        #[account(
+            mut,
+            seeds = [USER_PORTFOLIO_SEED, signer.key().as_ref()],
+            bump = user_portfolio.bump,
+        )]
+        pub user_portfolio: Option<Box<Account<'info, UserPortfolio>>>,
 
-    pub token_program: Interface<'info, TokenInterface>,
+        /// Required when the market restricts participants.
+        #[account(
+            seeds = [PARTICIPANT_PASS_SEED, market.key().as_ref(), signer.key().as_ref()],
+            bump = participant_pass.bump,
+        )]
+        pub participant_pass: Option<Box<Account<'info, ParticipantPass>>>,
 
-    // Arcium accounts
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
-    #[account(address = derive_mxe_pda!())]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut, address = derive_mempool_pda!(mxe_account))]
-    /// CHECK: mempool_account
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(mut, address = derive_execpool_pda!(mxe_account))]
-    /// CHECK: executing_pool
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account))]
-    /// CHECK: computation_account
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_STAKE))]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(mut, address = derive_cluster_pda!(mxe_account))]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
+        /// Required when the market is gated by a mint.
+        pub gate_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+        // SPL token accounts
+        #[account(address = market.mint)]
+        pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+        /// Funds the stake.
+        #[account(
+            mut,
+            token::mint = token_mint,
+            token::authority = signer,
+            token::token_program = token_program,
+        )]
+        pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+        #[account(
+            mut,
+            associated_token::mint = token_mint,
+            associated_token::authority = market,
+            associated_token::token_program = token_program,
+        )]
+        pub market_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+        pub token_program: Interface<'info, TokenInterface>,
+
+        // Event CPI accounts
+        /// CHECK: event authority PDA, checked by address.
+        #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+        pub event_authority: UncheckedAccount<'info>,
+        pub program: Program<'info, crate::program::OpportunityMarket>,
+
+        /// Required when the market keeps an audit log.
+        #[account(mut)]
+        pub audit_log: Option<AccountLoader<'info, AuditLog>>,
 
-pub fn stake(
-    ctx: Context<Stake>,
+        #[account(mut, seeds = [PROGRAM_METRICS_SEED], bump = program_metrics.bump)]
+        pub program_metrics: Box<Account<'info, ProgramMetrics>>,
+    }
+);
+
+pub fn stake<'info>(
+    ctx: Context<'info, Stake<'info>>,
     computation_offset: u64,
     _stake_account_id: u32,
     amount: u64,
@@ -107,8 +146,19 @@ pub fn stake(
     authorized_reader_nonce: u128,
     user_pubkey: [u8; 32],
     state_nonce: u128,
+    client_id: [u8; 32],
+    sequence: u64,
+    cu_price_micro: Option<u64>,
 ) -> Result<()> {
     require!(amount > 0, ErrorCode::InsufficientBalance);
+    require!(
+        !ctx.accounts.market.participants_restricted || ctx.accounts.participant_pass.is_some(),
+        ErrorCode::ParticipantPassRequired
+    );
+    ctx.accounts.market.check_gate(
+        ctx.accounts.gate_token_account.as_deref().map(|a| &**a),
+        &ctx.accounts.signer.key(),
+    )?;
     require!(
         amount >= ctx.accounts.market.min_stake_amount,
         ErrorCode::StakeBelowMinimum
@@ -125,6 +175,7 @@ pub fn stake(
         current_timestamp <= stake_end,
         ErrorCode::TimeWindowMismatch
     );
+    require!(!market.is_void(current_timestamp), ErrorCode::MarketVoid);
 
     let collected_fees = market.calculate_fees(amount)?;
     let net_amount = amount
@@ -140,7 +191,8 @@ pub fn stake(
                 to: ctx.accounts.market_token_ata.to_account_info(),
                 authority: ctx.accounts.signer.to_account_info(),
             },
-        ),
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         amount,
         ctx.accounts.token_mint.decimals,
     )?;
@@ -148,15 +200,59 @@ pub fn stake(
     // Set stake account fields
     ctx.accounts.stake_account.staked_at_timestamp = Some(current_timestamp);
     ctx.accounts.stake_account.amount = net_amount;
+    ctx.accounts.market.record_participant(net_amount);
     ctx.accounts.stake_account.collected_fees = collected_fees;
     ctx.accounts.stake_account.user_pubkey = user_pubkey;
     ctx.accounts.stake_account.state_nonce = state_nonce;
     ctx.accounts.stake_account.pending_stake_computation =
         Some(ctx.accounts.computation_account.key());
-
     let stake_account_key = ctx.accounts.stake_account.key();
+    ctx.accounts
+        .stake_account
+        .consume_computation_offset(&stake_account_key, computation_offset)?;
+    ctx.accounts
+        .stake_account
+        .queue_computation_offset(computation_offset);
+
+    ctx.accounts.stake_account.open_in_portfolio(
+        ctx.accounts.user_portfolio.as_deref_mut().map(|p| &mut **p),
+        clock.slot,
+    );
+
     let market_key = ctx.accounts.market.key();
 
+    if let Some(receipt) = ctx.accounts.deposit_receipt.as_mut() {
+        receipt.bump = ctx
+            .bumps
+            .deposit_receipt
+            .ok_or(ErrorCode::InvalidAccountState)?;
+        receipt.schema_version = SCHEMA_VERSION;
+        receipt.owner = ctx.accounts.signer.key();
+        receipt.market = market_key;
+        receipt.stake_account = stake_account_key;
+        receipt.stake_account_id = ctx.accounts.stake_account.id;
+        receipt.kind = ReceiptKind::Deposit;
+        receipt.client_id = client_id;
+        receipt.sequence = sequence;
+        receipt.amount = amount;
+        receipt.created_at = current_timestamp;
+
+        emit_ts!(
+            ctx,
+            DepositReceiptRecordedEvent {
+                receipt: receipt.key(),
+                owner: receipt.owner,
+                market: market_key,
+                stake_account: stake_account_key,
+                stake_account_id: receipt.stake_account_id,
+                kind: ReceiptKind::Deposit,
+                client_id: client_id,
+                sequence: sequence,
+                amount: amount,
+            }
+        );
+    }
+
     // Build args for encrypted computation
     let args = ArgBuilder::new()
         // User's option input (Enc<Shared, SelectedOption>)
@@ -171,8 +267,22 @@ pub fn stake(
         .plaintext_u128(state_nonce)
         .build();
 
+    ctx.accounts.market.record_audit(
+        market_key,
+        ctx.accounts.audit_log.as_ref(),
+        ctx.accounts.signer.key(),
+        AuditInstruction::Stake,
+        computation_offset,
+    )?;
+
+    let cu_price_micro = ctx
+        .accounts
+        .platform_config
+        .cu_price_micro(cu_price_micro)?;
+    ctx.accounts.program_metrics.record_queued();
+    ctx.accounts.program_metrics.stakes = ctx.accounts.program_metrics.stakes.saturating_add(1);
+    let payer_lamports = ctx.accounts.payer.lamports();
     // Queue computation with callback
-    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
     queue_computation(
         ctx.accounts,
         computation_offset,
@@ -189,12 +299,32 @@ pub fn stake(
                     pubkey: market_key,
                     is_writable: true,
                 },
+                CallbackAccount {
+                    pubkey: ctx.accounts.program_metrics.key(),
+                    is_writable: true,
+                },
+                // #[event_cpi] accounts
+                CallbackAccount {
+                    pubkey: crate::EVENT_AUTHORITY_AND_BUMP.0,
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: crate::ID,
+                    is_writable: false,
+                },
             ],
         )?],
         1,
-        0,
+        cu_price_micro,
     )?;
 
+    let mpc_fee = queued_mpc_fee(
+        payer_lamports,
+        ctx.accounts.payer.lamports(),
+        ctx.accounts.computation_account.lamports(),
+    );
+    ctx.accounts.program_metrics.record_mpc_fee(mpc_fee);
+
     Ok(())
 }
 
@@ -215,18 +345,37 @@ pub struct StakeCallback<'info> {
     pub instructions_sysvar: UncheckedAccount<'info>,
 
     // Callback accounts
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = stake_account.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
     pub stake_account: Box<Account<'info, StakeAccount>>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
     pub market: Box<Account<'info, OpportunityMarket>>,
+    #[account(mut, seeds = [PROGRAM_METRICS_SEED], bump = program_metrics.bump)]
+    pub program_metrics: Box<Account<'info, ProgramMetrics>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
 pub fn stake_callback(
     ctx: Context<StakeCallback>,
     output: SignedComputationOutputs<StakeOutput>,
 ) -> Result<()> {
-    // On failure, revert so the account stays stuck.
+    // On abort, count it and leave the account stuck.
     // The owner can recover via close_stuck_stake_account.
+    if let SignedComputationOutputs::Failure(_) = output {
+        ctx.accounts.program_metrics.record_callback(false);
+        return Ok(());
+    }
+
     let res = match output.verify_output(
         &ctx.accounts.cluster_account,
         &ctx.accounts.computation_account,
@@ -246,6 +395,11 @@ pub fn stake_callback(
         ErrorCode::InvalidAccountState
     );
 
+    let computation_account = ctx.accounts.computation_account.key();
+    ctx.accounts
+        .stake_account
+        .apply_callback(computation_account, &ctx.accounts.mxe_account)?;
+
     // Unlock
     ctx.accounts.stake_account.pending_stake_computation = None;
 
@@ -288,17 +442,22 @@ pub fn stake_callback(
             .ok_or(ErrorCode::Overflow)?;
     }
 
-    emit_ts!(StakedEvent {
-        user: ctx.accounts.stake_account.owner,
-        market: ctx.accounts.stake_account.market,
-        stake_account: ctx.accounts.stake_account.key(),
-        stake_account_id: ctx.accounts.stake_account.id,
-        stake_encrypted_option: stake_data_mxe.ciphertexts[0],
-        stake_state_nonce: stake_data_mxe.nonce,
-        stake_encrypted_option_disclosure: stake_data_shared.ciphertexts[0],
-        stake_state_disclosure_nonce: stake_data_shared.nonce,
-        amount: ctx.accounts.stake_account.amount,
-    });
+    ctx.accounts.program_metrics.record_callback(true);
+
+    emit_ts!(
+        ctx,
+        StakedEvent {
+            user: ctx.accounts.stake_account.owner,
+            market: ctx.accounts.stake_account.market,
+            stake_account: ctx.accounts.stake_account.key(),
+            stake_account_id: ctx.accounts.stake_account.id,
+            stake_encrypted_option: stake_data_mxe.ciphertexts[0],
+            stake_state_nonce: stake_data_mxe.nonce,
+            stake_encrypted_option_disclosure: stake_data_shared.ciphertexts[0],
+            stake_state_disclosure_nonce: stake_data_shared.nonce,
+            amount: ctx.accounts.stake_account.amount,
+        }
+    );
 
     Ok(())
 }
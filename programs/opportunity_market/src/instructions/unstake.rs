@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
 
-use crate::constants::{OPPORTUNITY_MARKET_SEED, STAKE_ACCOUNT_SEED};
+use crate::constants::{
+    OPPORTUNITY_MARKET_SEED, SCHEMA_VERSION, STAKE_ACCOUNT_SEED, USER_PORTFOLIO_SEED,
+};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, UnstakedEvent};
-use crate::state::{OpportunityMarket, StakeAccount};
+use crate::state::{
+    AuditInstruction, AuditLog, OpportunityMarket, PlatformConfig, StakeAccount, UserPortfolio,
+};
+use crate::transfer::transfer_checked;
 
 #[derive(Accounts)]
 #[instruction(stake_account_id: u32)]
@@ -18,21 +21,39 @@ pub struct Unstake<'info> {
     pub owner: UncheckedAccount<'info>,
 
     #[account(
+        mut,
         seeds = [OPPORTUNITY_MARKET_SEED, market.platform.as_ref(), market.creator.as_ref(), &market.index.to_le_bytes()],
         bump = market.bump,
         constraint = market.stake_end_timestamp.is_some() @ ErrorCode::MarketNotOpen,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
     pub market: Box<Account<'info, OpportunityMarket>>,
 
+    #[account(
+        address = market.platform @ ErrorCode::Unauthorized,
+        constraint = !platform_config.paused @ ErrorCode::PlatformPaused,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
     #[account(
         mut,
         seeds = [STAKE_ACCOUNT_SEED, owner.key().as_ref(), market.key().as_ref(), &stake_account_id.to_le_bytes()],
         bump = stake_account.bump,
         constraint = stake_account.unstaked_at_timestamp.is_none() @ ErrorCode::AlreadyUnstaked,
         constraint = stake_account.staked_at_timestamp.is_some() @ ErrorCode::NoStake,
+        constraint = stake_account.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
     pub stake_account: Box<Account<'info, StakeAccount>>,
 
+    /// Optional position summary for the owner's wallet.
+    #[account(
+        mut,
+        seeds = [USER_PORTFOLIO_SEED, owner.key().as_ref()],
+        bump = user_portfolio.bump,
+    )]
+    pub user_portfolio: Option<Box<Account<'info, UserPortfolio>>>,
+
     // SPL token accounts
     #[account(address = market.mint)]
     pub token_mint: Box<InterfaceAccount<'info, Mint>>,
@@ -56,23 +77,42 @@ pub struct Unstake<'info> {
 
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+
+    /// Required when the market keeps an audit log.
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
-pub fn unstake(ctx: Context<Unstake>, _stake_account_id: u32) -> Result<()> {
+pub fn unstake<'info>(ctx: Context<'info, Unstake<'info>>, _stake_account_id: u32) -> Result<()> {
     let market = &ctx.accounts.market;
 
     let stake_end = market.stake_end_timestamp.ok_or(ErrorCode::MarketNotOpen)?;
-    let current_timestamp = Clock::get()?.unix_timestamp as u64;
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp as u64;
+
+    let amount = ctx.accounts.stake_account.amount;
 
     if current_timestamp < stake_end {
         require!(market.allow_unstaking_early, ErrorCode::TimeWindowMismatch);
         require!(ctx.accounts.owner.is_signer, ErrorCode::Unauthorized);
         ctx.accounts.stake_account.unstaked_at_timestamp = Some(current_timestamp);
+        ctx.accounts.market.remove_participant(amount);
     } else {
         ctx.accounts.stake_account.unstaked_at_timestamp = Some(stake_end);
     }
 
-    let amount = ctx.accounts.stake_account.amount;
+    // The stake account stays open until claimed, so this only counts as activity.
+    if let Some(user_portfolio) = ctx.accounts.user_portfolio.as_mut() {
+        user_portfolio.last_activity_slot = clock.slot;
+    }
+
+    let market = &ctx.accounts.market;
 
     if amount > 0 {
         let platform = market.platform;
@@ -97,19 +137,31 @@ pub fn unstake(ctx: Context<Unstake>, _stake_account_id: u32) -> Result<()> {
                     authority: ctx.accounts.market.to_account_info(),
                 },
                 market_seeds,
-            ),
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
             amount,
             ctx.accounts.token_mint.decimals,
         )?;
     }
 
-    emit_ts!(UnstakedEvent {
-        owner: ctx.accounts.stake_account.owner,
-        market: market.key(),
-        stake_account: ctx.accounts.stake_account.key(),
-        stake_account_id: ctx.accounts.stake_account.id,
-        amount: amount,
-    });
+    ctx.accounts.market.record_audit(
+        ctx.accounts.market.key(),
+        ctx.accounts.audit_log.as_ref(),
+        ctx.accounts.signer.key(),
+        AuditInstruction::Unstake,
+        0,
+    )?;
+
+    emit_ts!(
+        ctx,
+        UnstakedEvent {
+            owner: ctx.accounts.stake_account.owner,
+            market: market.key(),
+            stake_account: ctx.accounts.stake_account.key(),
+            stake_account_id: ctx.accounts.stake_account.id,
+            amount: amount,
+        }
+    );
 
     Ok(())
 }
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_EARLINESS_MULTIPLIER, SCHEMA_VERSION};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, MarketTimingUpdatedEvent};
+use crate::score::PRECISION;
+use crate::state::OpportunityMarket;
+
+// Lets the creator fix timing parameters set at creation, as long as nobody could have staked
+// under the old values. time_to_stake itself is chosen at open_market.
+#[derive(Accounts)]
+pub struct UpdateMarketTiming<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::Unauthorized,
+        constraint = market.stake_end_timestamp.is_none() @ ErrorCode::MarketAlreadyOpen,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn update_market_timing(
+    ctx: Context<UpdateMarketTiming>,
+    earliness_cutoff_seconds: u64,
+    earliness_multiplier: u16,
+) -> Result<()> {
+    require!(
+        (earliness_multiplier as u64) >= PRECISION
+            && earliness_multiplier <= MAX_EARLINESS_MULTIPLIER,
+        ErrorCode::InvalidParameters
+    );
+
+    let market = &mut ctx.accounts.market;
+    market.earliness_cutoff_seconds = earliness_cutoff_seconds;
+    market.earliness_multiplier = earliness_multiplier;
+
+    emit_ts!(
+        ctx,
+        MarketTimingUpdatedEvent {
+            market: market.key(),
+            earliness_cutoff_seconds: earliness_cutoff_seconds,
+            earliness_multiplier: earliness_multiplier,
+        }
+    );
+
+    Ok(())
+}
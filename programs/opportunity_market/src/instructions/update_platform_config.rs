@@ -1,20 +1,44 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{
+    MAX_CONFIG_CHANGE_DELAY_SECONDS, MAX_CU_PRICE_MICRO, MAX_REVEAL_PERIOD_SECONDS,
+    MIN_REVEAL_PERIOD_SECONDS, PENDING_CONFIG_CHANGE_SEED, SCHEMA_VERSION,
+};
 #[cfg(feature = "production-settings")]
-use crate::constants::MIN_MARKET_RESOLUTION_DEADLINE_SECONDS;
-use crate::constants::{MAX_REVEAL_PERIOD_SECONDS, MIN_REVEAL_PERIOD_SECONDS};
+use crate::constants::{MIN_MARKET_RESOLUTION_DEADLINE_SECONDS, SCHEMA_VERSION};
 use crate::error::ErrorCode;
-use crate::state::{FeeRates, PlatformConfig};
+use crate::events::{emit_ts, ConfigChangeCancelledEvent, ConfigChangeQueuedEvent};
+use crate::state::{FeeRates, PendingConfigChange, PlatformConfig, PlatformConfigChange};
 
+// Changes are queued rather than applied, so integrators get config_change_delay_seconds of
+// notice before fees or authorities change. execute_config_change applies them.
 #[derive(Accounts)]
 pub struct UpdatePlatformConfig<'info> {
+    #[account(mut)]
     pub update_authority: Signer<'info>,
 
     #[account(
-        mut,
         has_one = update_authority @ ErrorCode::Unauthorized,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
-    pub platform_config: Account<'info, PlatformConfig>,
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        init,
+        payer = update_authority,
+        space = 8 + PendingConfigChange::INIT_SPACE,
+        seeds = [PENDING_CONFIG_CHANGE_SEED, platform_config.key().as_ref()],
+        bump,
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChange>>,
+
+    pub system_program: Program<'info, System>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
 pub fn update_platform_config(
@@ -26,6 +50,8 @@ pub fn update_platform_config(
     min_time_to_stake_seconds: u64,
     reveal_period_seconds: u64,
     market_resolution_deadline_seconds: u64,
+    default_cu_price_micro: u64,
+    config_change_delay_seconds: u64,
 ) -> Result<()> {
     #[cfg(feature = "production-settings")]
     require!(
@@ -37,11 +63,85 @@ pub fn update_platform_config(
         ErrorCode::InvalidParameters
     );
 
-    let platform_config = &mut ctx.accounts.platform_config;
-    platform_config.fee_rates = FeeRates::new(platform_fee_bp, reward_pool_fee_bp, creator_fee_bp)?;
-    platform_config.reveal_authority = reveal_authority;
-    platform_config.min_time_to_stake_seconds = min_time_to_stake_seconds;
-    platform_config.reveal_period_seconds = reveal_period_seconds;
-    platform_config.market_resolution_deadline_seconds = market_resolution_deadline_seconds;
+    require!(
+        default_cu_price_micro <= MAX_CU_PRICE_MICRO,
+        ErrorCode::InvalidParameters
+    );
+    require!(
+        config_change_delay_seconds <= MAX_CONFIG_CHANGE_DELAY_SECONDS,
+        ErrorCode::InvalidParameters
+    );
+
+    let change = PlatformConfigChange {
+        fee_rates: FeeRates::new(platform_fee_bp, reward_pool_fee_bp, creator_fee_bp)?,
+        reveal_authority,
+        min_time_to_stake_seconds,
+        reveal_period_seconds,
+        market_resolution_deadline_seconds,
+        default_cu_price_micro,
+        config_change_delay_seconds,
+    };
+
+    // The current delay applies, so shortening it is itself subject to the old delay.
+    let now = Clock::get()?.unix_timestamp as u64;
+    let executable_at = now
+        .checked_add(ctx.accounts.platform_config.config_change_delay_seconds)
+        .ok_or(ErrorCode::Overflow)?;
+
+    let pending = &mut ctx.accounts.pending_config_change;
+    pending.bump = ctx.bumps.pending_config_change;
+    pending.schema_version = SCHEMA_VERSION;
+    pending.platform = ctx.accounts.platform_config.key();
+    pending.queued_at = now;
+    pending.executable_at = executable_at;
+    pending.change = change;
+
+    emit_ts!(
+        ctx,
+        ConfigChangeQueuedEvent {
+            platform_config: ctx.accounts.platform_config.key(),
+            pending_config_change: ctx.accounts.pending_config_change.key(),
+            executable_at: executable_at,
+            change: change,
+        }
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    #[account(mut)]
+    pub update_authority: Signer<'info>,
+
+    #[account(
+        has_one = update_authority @ ErrorCode::Unauthorized,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        close = update_authority,
+        seeds = [PENDING_CONFIG_CHANGE_SEED, platform_config.key().as_ref()],
+        bump = pending_config_change.bump,
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChange>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+    emit_ts!(
+        ctx,
+        ConfigChangeCancelledEvent {
+            platform_config: ctx.accounts.platform_config.key(),
+        }
+    );
+
     Ok(())
 }
@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
+
+use crate::arcium_accounts::arcium_queue_accounts;
+use crate::computation::queued_mpc_fee;
+use crate::constants::{
+    ENCRYPTED_OPTION_LEN, PROGRAM_METRICS_SEED, SCHEMA_VERSION,
+    STAKE_ACCOUNT_ENCRYPTED_OPTION_OFFSET, STAKE_ACCOUNT_SEED,
+};
+use crate::error::ErrorCode;
+use crate::events::{emit_ts, StakeDisclosureVerifiedEvent};
+use crate::state::{OpportunityMarket, PlatformConfig, ProgramMetrics, StakeAccount};
+use crate::COMP_DEF_OFFSET_VERIFY_STAKE_DISCLOSURE;
+use crate::{ArciumSignerAccount, ID, ID_CONST};
+
+arcium_queue_accounts!(
+    "verify_stake_disclosure",
+    signer,
+    COMP_DEF_OFFSET_VERIFY_STAKE_DISCLOSURE,
+    computation_offset,
+    #[instruction(computation_offset: u64, stake_account_id: u32)]
+    pub struct VerifyStakeDisclosure<'info> {
+        // Only the owner may check claims: an open equality oracle would let anyone
+        // brute-force the option ID before the market is resolved.
+        #[account(mut)]
+        pub signer: Signer<'info>,
+
+        #[account(
+            constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub market: Box<Account<'info, OpportunityMarket>>,
+
+        #[account(
+            address = market.platform @ ErrorCode::Unauthorized,
+            constraint = !platform_config.paused @ ErrorCode::PlatformPaused,
+            constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+        #[account(
+            mut,
+            seeds = [STAKE_ACCOUNT_SEED, signer.key().as_ref(), market.key().as_ref(), &stake_account_id.to_le_bytes()],
+            bump = stake_account.bump,
+            constraint = stake_account.staked_at_timestamp.is_some() @ ErrorCode::NoStake,
+            constraint = stake_account.pending_stake_computation.is_none() @ ErrorCode::Locked,
+            constraint = stake_account.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+        )]
+        pub stake_account: Box<Account<'info, StakeAccount>>,
+
+        #[account(mut, seeds = [PROGRAM_METRICS_SEED], bump = program_metrics.bump)]
+        pub program_metrics: Box<Account<'info, ProgramMetrics>>,
+    }
+);
+
+// Lets a staker back up a voluntary disclosure of their option with a check
+// against the ciphertext stored on their stake account.
+pub fn verify_stake_disclosure(
+    ctx: Context<VerifyStakeDisclosure>,
+    computation_offset: u64,
+    _stake_account_id: u32,
+    claimed_option: u64,
+    cu_price_micro: Option<u64>,
+) -> Result<()> {
+    let stake_account_key = ctx.accounts.stake_account.key();
+    let stake_account_nonce = ctx.accounts.stake_account.state_nonce;
+    let user_pubkey = ctx.accounts.stake_account.user_pubkey;
+    ctx.accounts
+        .stake_account
+        .consume_computation_offset(&stake_account_key, computation_offset)?;
+
+    let args = ArgBuilder::new()
+        // Stake account encrypted option (Enc<Shared, SelectedOption>)
+        .x25519_pubkey(user_pubkey)
+        .plaintext_u128(stake_account_nonce)
+        .account(
+            stake_account_key,
+            STAKE_ACCOUNT_ENCRYPTED_OPTION_OFFSET,
+            ENCRYPTED_OPTION_LEN,
+        )
+        // Claimed option (plaintext)
+        .plaintext_u64(claimed_option)
+        .build();
+
+    let cu_price_micro = ctx
+        .accounts
+        .platform_config
+        .cu_price_micro(cu_price_micro)?;
+    ctx.accounts.program_metrics.record_queued();
+    ctx.accounts.program_metrics.disclosure_verifications = ctx
+        .accounts
+        .program_metrics
+        .disclosure_verifications
+        .saturating_add(1);
+    let payer_lamports = ctx.accounts.signer.lamports();
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![VerifyStakeDisclosureCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: stake_account_key,
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.program_metrics.key(),
+                    is_writable: true,
+                },
+                // #[event_cpi] accounts
+                CallbackAccount {
+                    pubkey: crate::EVENT_AUTHORITY_AND_BUMP.0,
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: crate::ID,
+                    is_writable: false,
+                },
+            ],
+        )?],
+        1,
+        cu_price_micro,
+    )?;
+
+    let mpc_fee = queued_mpc_fee(
+        payer_lamports,
+        ctx.accounts.signer.lamports(),
+        ctx.accounts.computation_account.lamports(),
+    );
+    ctx.accounts.program_metrics.record_mpc_fee(mpc_fee);
+
+    Ok(())
+}
+
+#[callback_accounts("verify_stake_disclosure")]
+#[derive(Accounts)]
+pub struct VerifyStakeDisclosureCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_VERIFY_STAKE_DISCLOSURE))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account))]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::arcium_anchor::solana_instructions_sysvar::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = stake_account.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    // Callback accounts
+    pub stake_account: Box<Account<'info, StakeAccount>>,
+    #[account(mut, seeds = [PROGRAM_METRICS_SEED], bump = program_metrics.bump)]
+    pub program_metrics: Box<Account<'info, ProgramMetrics>>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
+}
+
+pub fn verify_stake_disclosure_callback(
+    ctx: Context<VerifyStakeDisclosureCallback>,
+    output: SignedComputationOutputs<VerifyStakeDisclosureOutput>,
+) -> Result<()> {
+    // An aborted check changes no state, so only count it.
+    if let SignedComputationOutputs::Failure(_) = output {
+        ctx.accounts.program_metrics.record_callback(false);
+        return Ok(());
+    }
+
+    let res = match output.verify_output(
+        &ctx.accounts.cluster_account,
+        &ctx.accounts.computation_account,
+    ) {
+        Ok(VerifyStakeDisclosureOutput { field_0 }) => field_0,
+        Err(e) => return Err(e),
+    };
+
+    ctx.accounts.program_metrics.record_callback(true);
+
+    emit_ts!(
+        ctx,
+        StakeDisclosureVerifiedEvent {
+            owner: ctx.accounts.stake_account.owner,
+            market: ctx.accounts.stake_account.market,
+            stake_account: ctx.accounts.stake_account.key(),
+            stake_account_id: ctx.accounts.stake_account.id,
+            claimed_option: res.field_0,
+            matches: res.field_1,
+        }
+    );
+
+    Ok(())
+}
@@ -1,12 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
 
-use crate::constants::{OPPORTUNITY_MARKET_SEED, SPONSOR_SEED};
+use crate::constants::{OPPORTUNITY_MARKET_SEED, SCHEMA_VERSION, SPONSOR_SEED};
 use crate::error::ErrorCode;
 use crate::events::{emit_ts, RewardWithdrawnEvent};
-use crate::state::{OpportunityMarket, OpportunityMarketSponsor};
+use crate::state::{OpportunityMarket, OpportunityMarketSponsor, PlatformConfig};
+use crate::transfer::transfer_checked;
 
 #[derive(Accounts)]
 pub struct WithdrawReward<'info> {
@@ -17,8 +16,16 @@ pub struct WithdrawReward<'info> {
         mut,
         seeds = [OPPORTUNITY_MARKET_SEED, market.platform.as_ref(), market.creator.as_ref(), &market.index.to_le_bytes()],
         bump = market.bump,
+        constraint = market.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
     )]
-    pub market: Account<'info, OpportunityMarket>,
+    pub market: Box<Account<'info, OpportunityMarket>>,
+
+    #[account(
+        address = market.platform @ ErrorCode::Unauthorized,
+        constraint = !platform_config.paused @ ErrorCode::PlatformPaused,
+        constraint = platform_config.schema_version == SCHEMA_VERSION @ ErrorCode::SchemaVersionMismatch,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
 
     #[account(
         mut,
@@ -26,10 +33,10 @@ pub struct WithdrawReward<'info> {
         bump = sponsor_account.bump,
         close = sponsor,
     )]
-    pub sponsor_account: Account<'info, OpportunityMarketSponsor>,
+    pub sponsor_account: Box<Account<'info, OpportunityMarketSponsor>>,
 
     #[account(address = market.mint)]
-    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
@@ -37,7 +44,7 @@ pub struct WithdrawReward<'info> {
         associated_token::authority = market,
         associated_token::token_program = token_program,
     )]
-    pub market_token_ata: InterfaceAccount<'info, TokenAccount>,
+    pub market_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// Sponsor's destination for refunded reward tokens
     #[account(
@@ -45,12 +52,18 @@ pub struct WithdrawReward<'info> {
         token::mint = token_mint,
         token::token_program = token_program,
     )]
-    pub refund_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub refund_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    // Event CPI accounts
+    /// CHECK: event authority PDA, checked by address.
+    #[account(address = crate::EVENT_AUTHORITY_AND_BUMP.0)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program: Program<'info, crate::program::OpportunityMarket>,
 }
 
-pub fn withdraw_reward(ctx: Context<WithdrawReward>) -> Result<()> {
+pub fn withdraw_reward<'info>(ctx: Context<'info, WithdrawReward<'info>>) -> Result<()> {
     let sponsor_account = &ctx.accounts.sponsor_account;
     let market = &ctx.accounts.market;
 
@@ -60,14 +73,15 @@ pub fn withdraw_reward(ctx: Context<WithdrawReward>) -> Result<()> {
             .checked_add(market.market_resolution_deadline_seconds)
             .ok_or(ErrorCode::Overflow)?;
 
-        // If market expired without resolution, even locked reward can be withdrawn.
-        let market_expired =
-            current_timestamp >= expired_at && market.resolved_at_timestamp.is_none();
+        // If market expired without resolution or is void, even locked reward can be withdrawn.
+        let market_expired = (current_timestamp >= expired_at
+            && market.resolved_at_timestamp.is_none())
+            || market.is_void(current_timestamp);
         if !market_expired {
             require!(current_timestamp < stake_end, ErrorCode::TimeWindowMismatch);
             require!(!sponsor_account.reward_locked, ErrorCode::Unauthorized);
         }
-    } else {
+    } else if !market.emergency_voided {
         require!(!sponsor_account.reward_locked, ErrorCode::Unauthorized);
     }
 
@@ -96,7 +110,8 @@ pub fn withdraw_reward(ctx: Context<WithdrawReward>) -> Result<()> {
                     authority: ctx.accounts.market.to_account_info(),
                 },
                 market_seeds,
-            ),
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
             reward_amount,
             ctx.accounts.token_mint.decimals,
         )?;
@@ -108,12 +123,15 @@ pub fn withdraw_reward(ctx: Context<WithdrawReward>) -> Result<()> {
         .checked_sub(reward_amount)
         .ok_or(ErrorCode::Overflow)?;
 
-    emit_ts!(RewardWithdrawnEvent {
-        market: market.key(),
-        sponsor: ctx.accounts.sponsor.key(),
-        reward_amount: reward_amount,
-        refund_token_account: ctx.accounts.refund_token_account.key(),
-    });
+    emit_ts!(
+        ctx,
+        RewardWithdrawnEvent {
+            market: market.key(),
+            sponsor: ctx.accounts.sponsor.key(),
+            reward_amount: reward_amount,
+            refund_token_account: ctx.accounts.refund_token_account.key(),
+        }
+    );
 
     Ok(())
 }
@@ -3,12 +3,15 @@
 use anchor_lang::prelude::*;
 use arcium_anchor::prelude::*;
 
+mod arcium_accounts;
+pub mod computation;
 pub mod constants;
 pub mod error;
 pub mod events;
 pub mod instructions;
 pub mod score;
 pub mod state;
+pub mod transfer;
 
 pub use error::ErrorCode;
 pub use instructions::*;
@@ -16,6 +19,8 @@ pub use state::*;
 
 pub const COMP_DEF_OFFSET_STAKE: u32 = comp_def_offset("stake");
 pub const COMP_DEF_OFFSET_REVEAL_STAKE: u32 = comp_def_offset("reveal_stake");
+pub const COMP_DEF_OFFSET_REVEAL_STAKE_BATCH: u32 = comp_def_offset("reveal_stake_batch");
+pub const COMP_DEF_OFFSET_VERIFY_STAKE_DISCLOSURE: u32 = comp_def_offset("verify_stake_disclosure");
 
 declare_id!("B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg");
 
@@ -23,6 +28,10 @@ declare_id!("B3NCHsGBkdZrPYPJY2rjg4UwmyRotMmFWhxa5hMHwLeg");
 pub mod opportunity_market {
     use super::*;
 
+    pub fn initialize_comp_defs(ctx: Context<InitializeCompDefs>) -> Result<()> {
+        instructions::initialize_comp_defs(ctx)
+    }
+
     pub fn reveal_stake_comp_def(ctx: Context<RevealStakeCompDef>) -> Result<()> {
         instructions::reveal_stake_comp_def(ctx)
     }
@@ -62,6 +71,8 @@ pub mod opportunity_market {
         min_time_to_stake_seconds: u64,
         reveal_period_seconds: u64,
         market_resolution_deadline_seconds: u64,
+        default_cu_price_micro: u64,
+        config_change_delay_seconds: u64,
     ) -> Result<()> {
         instructions::update_platform_config(
             ctx,
@@ -72,21 +83,50 @@ pub mod opportunity_market {
             min_time_to_stake_seconds,
             reveal_period_seconds,
             market_resolution_deadline_seconds,
+            default_cu_price_micro,
+            config_change_delay_seconds,
         )
     }
 
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+        instructions::execute_config_change(ctx)
+    }
+
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        instructions::cancel_config_change(ctx)
+    }
+
     pub fn set_update_authority(ctx: Context<SetUpdateAuthority>) -> Result<()> {
         instructions::set_update_authority(ctx)
     }
 
+    pub fn accept_update_authority(ctx: Context<AcceptUpdateAuthority>) -> Result<()> {
+        instructions::accept_update_authority(ctx)
+    }
+
     pub fn set_fee_claim_authority(ctx: Context<SetFeeClaimAuthority>) -> Result<()> {
         instructions::set_fee_claim_authority(ctx)
     }
 
+    pub fn set_platform_paused(ctx: Context<SetPlatformPaused>, paused: bool) -> Result<()> {
+        instructions::set_platform_paused(ctx, paused)
+    }
+
+    pub fn set_transfer_hook_programs(
+        ctx: Context<SetTransferHookPrograms>,
+        transfer_hook_programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_transfer_hook_programs(ctx, transfer_hook_programs)
+    }
+
     pub fn init_allowed_mint(ctx: Context<InitAllowedMint>) -> Result<()> {
         instructions::init_allowed_mint(ctx)
     }
 
+    pub fn create_category(ctx: Context<CreateCategory>, id: u32, name: String) -> Result<()> {
+        instructions::create_category(ctx, id, name)
+    }
+
     pub fn create_market(
         ctx: Context<CreateMarket>,
         market_index: u64,
@@ -111,10 +151,107 @@ pub mod opportunity_market {
         )
     }
 
+    pub fn create_market_with_options<'info>(
+        ctx: Context<'info, CreateMarket<'info>>,
+        market_index: u64,
+        market_authority: Pubkey,
+        allow_unstaking_early: bool,
+        authorized_reader_pubkey: [u8; 32],
+        earliness_cutoff_seconds: u64,
+        earliness_multiplier: u16,
+        min_stake_amount: u64,
+        creator_fee_claimer: Pubkey,
+        option_ids: Vec<u64>,
+    ) -> Result<()> {
+        instructions::create_market_with_options(
+            ctx,
+            market_index,
+            market_authority,
+            allow_unstaking_early,
+            authorized_reader_pubkey,
+            earliness_cutoff_seconds,
+            earliness_multiplier,
+            min_stake_amount,
+            creator_fee_claimer,
+            option_ids,
+        )
+    }
+
     pub fn add_market_option(ctx: Context<AddMarketOption>, option_id: u64) -> Result<()> {
         instructions::add_market_option(ctx, option_id)
     }
 
+    pub fn remove_market_option(ctx: Context<RemoveMarketOption>, option_id: u64) -> Result<()> {
+        instructions::remove_market_option(ctx, option_id)
+    }
+
+    pub fn update_market_timing(
+        ctx: Context<UpdateMarketTiming>,
+        earliness_cutoff_seconds: u64,
+        earliness_multiplier: u16,
+    ) -> Result<()> {
+        instructions::update_market_timing(ctx, earliness_cutoff_seconds, earliness_multiplier)
+    }
+
+    pub fn set_loser_rebate(ctx: Context<SetLoserRebate>, loser_rebate_bp: u16) -> Result<()> {
+        instructions::set_loser_rebate(ctx, loser_rebate_bp)
+    }
+
+    pub fn set_reward_vesting(
+        ctx: Context<SetRewardVesting>,
+        reward_tranches: u8,
+        reward_tranche_interval_seconds: u64,
+    ) -> Result<()> {
+        instructions::set_reward_vesting(ctx, reward_tranches, reward_tranche_interval_seconds)
+    }
+
+    pub fn set_successor_market(ctx: Context<SetSuccessorMarket>) -> Result<()> {
+        instructions::set_successor_market(ctx)
+    }
+
+    pub fn set_participants_restricted(
+        ctx: Context<SetParticipantsRestricted>,
+        participants_restricted: bool,
+    ) -> Result<()> {
+        instructions::set_participants_restricted(ctx, participants_restricted)
+    }
+
+    pub fn init_audit_log(ctx: Context<InitAuditLog>) -> Result<()> {
+        instructions::init_audit_log(ctx)
+    }
+
+    pub fn get_market_phase(ctx: Context<GetMarketPhase>) -> Result<MarketPhaseInfo> {
+        instructions::get_market_phase(ctx)
+    }
+
+    pub fn get_claimable(ctx: Context<GetClaimable>) -> Result<ClaimableEstimate> {
+        instructions::get_claimable(ctx)
+    }
+
+    pub fn set_market_category(ctx: Context<SetMarketCategory>) -> Result<()> {
+        instructions::set_market_category(ctx)
+    }
+
+    pub fn set_participation_threshold(
+        ctx: Context<SetParticipationThreshold>,
+        min_total_stake: u64,
+        min_participants: u32,
+    ) -> Result<()> {
+        instructions::set_participation_threshold(ctx, min_total_stake, min_participants)
+    }
+
+    pub fn set_gate_mint(ctx: Context<SetGateMint>) -> Result<()> {
+        instructions::set_gate_mint(ctx)
+    }
+
+    pub fn issue_participant_pass(ctx: Context<IssueParticipantPass>) -> Result<()> {
+        instructions::issue_participant_pass(ctx)
+    }
+
+    pub fn revoke_participant_pass(ctx: Context<RevokeParticipantPass>) -> Result<()> {
+        instructions::revoke_participant_pass(ctx)
+    }
+
     pub fn open_market(ctx: Context<OpenMarket>, time_to_stake: u64) -> Result<()> {
         instructions::open_market(ctx, time_to_stake)
     }
@@ -127,19 +264,30 @@ pub mod opportunity_market {
         instructions::set_winning_option(ctx, option_id, reward_bp)
     }
 
-    pub fn resolve_market(ctx: Context<ResolveMarket>) -> Result<()> {
+    pub fn resolve_market<'info>(ctx: Context<'info, ResolveMarket<'info>>) -> Result<()> {
         instructions::resolve_market(ctx)
     }
 
-    pub fn withdraw_reward(ctx: Context<WithdrawReward>) -> Result<()> {
+    pub fn emergency_resolve(
+        ctx: Context<EmergencyResolve>,
+        justification_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::emergency_resolve(ctx, justification_hash)
+    }
+
+    pub fn withdraw_reward<'info>(ctx: Context<'info, WithdrawReward<'info>>) -> Result<()> {
         instructions::withdraw_reward(ctx)
     }
 
-    pub fn end_reveal_period(ctx: Context<EndRevealPeriod>) -> Result<()> {
+    pub fn end_reveal_period<'info>(ctx: Context<'info, EndRevealPeriod<'info>>) -> Result<()> {
         instructions::end_reveal_period(ctx)
     }
 
-    pub fn add_reward(ctx: Context<AddReward>, amount: u64, lock: bool) -> Result<()> {
+    pub fn add_reward<'info>(
+        ctx: Context<'info, AddReward<'info>>,
+        amount: u64,
+        lock: bool,
+    ) -> Result<()> {
         instructions::add_reward(ctx, amount, lock)
     }
 
@@ -151,16 +299,44 @@ pub mod opportunity_market {
         instructions::finalize_reveal_stake(ctx, option_id, stake_account_id)
     }
 
+    pub fn finalize_reveal_stakes<'info>(
+        ctx: Context<'info, FinalizeRevealStakes<'info>>,
+    ) -> Result<()> {
+        instructions::finalize_reveal_stakes(ctx)
+    }
+
+    pub fn fund_crank_budget(
+        ctx: Context<FundCrankBudget>,
+        lamports: u64,
+        crank_bounty_lamports: u64,
+    ) -> Result<()> {
+        instructions::fund_crank_budget(ctx, lamports, crank_bounty_lamports)
+    }
+
+    pub fn withdraw_crank_budget(ctx: Context<WithdrawCrankBudget>) -> Result<()> {
+        instructions::withdraw_crank_budget(ctx)
+    }
+
     pub fn close_stake_account<'info>(
         ctx: Context<'info, CloseStakeAccount<'info>>,
         option_id: u64,
         stake_account_id: u32,
+        client_id: Option<[u8; 32]>,
+        sequence: Option<u64>,
+    ) -> Result<()> {
+        instructions::close_stake_account(ctx, option_id, stake_account_id, client_id, sequence)
+    }
+
+    pub fn claim_reward_tranche<'info>(
+        ctx: Context<'info, ClaimRewardTranche<'info>>,
+        option_id: u64,
+        stake_account_id: u32,
     ) -> Result<()> {
-        instructions::close_stake_account(ctx, option_id, stake_account_id)
+        instructions::claim_reward_tranche(ctx, option_id, stake_account_id)
     }
 
-    pub fn close_stuck_stake_account(
-        ctx: Context<CloseStuckStakeAccount>,
+    pub fn close_stuck_stake_account<'info>(
+        ctx: Context<'info, CloseStuckStakeAccount<'info>>,
         stake_account_id: u32,
     ) -> Result<()> {
         instructions::close_stuck_stake_account(ctx, stake_account_id)
@@ -170,15 +346,18 @@ pub mod opportunity_market {
         instructions::close_option_account(ctx, option_id)
     }
 
-    pub fn unstake(ctx: Context<Unstake>, stake_account_id: u32) -> Result<()> {
+    pub fn unstake<'info>(
+        ctx: Context<'info, Unstake<'info>>,
+        stake_account_id: u32,
+    ) -> Result<()> {
         instructions::unstake(ctx, stake_account_id)
     }
 
-    pub fn claim_fees(ctx: Context<ClaimFees>) -> Result<()> {
+    pub fn claim_fees<'info>(ctx: Context<'info, ClaimFees<'info>>) -> Result<()> {
         instructions::claim_fees(ctx)
     }
 
-    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+    pub fn claim_creator_fees<'info>(ctx: Context<'info, ClaimCreatorFees<'info>>) -> Result<()> {
         instructions::claim_creator_fees(ctx)
     }
 
@@ -186,12 +365,16 @@ pub mod opportunity_market {
         instructions::init_stake_account(ctx, stake_account_id)
     }
 
+    pub fn init_user_portfolio(ctx: Context<InitUserPortfolio>) -> Result<()> {
+        instructions::init_user_portfolio(ctx)
+    }
+
     pub fn stake_comp_def(ctx: Context<StakeCompDef>) -> Result<()> {
         instructions::stake_comp_def(ctx)
     }
 
-    pub fn stake(
-        ctx: Context<Stake>,
+    pub fn stake<'info>(
+        ctx: Context<'info, Stake<'info>>,
         computation_offset: u64,
         stake_account_id: u32,
         amount: u64,
@@ -200,6 +383,9 @@ pub mod opportunity_market {
         authorized_reader_nonce: u128,
         user_pubkey: [u8; 32],
         state_nonce: u128,
+        client_id: [u8; 32],
+        sequence: u64,
+        cu_price_micro: Option<u64>,
     ) -> Result<()> {
         instructions::stake(
             ctx,
@@ -211,6 +397,9 @@ pub mod opportunity_market {
             authorized_reader_nonce,
             user_pubkey,
             state_nonce,
+            client_id,
+            sequence,
+            cu_price_micro,
         )
     }
 
@@ -222,12 +411,33 @@ pub mod opportunity_market {
         instructions::stake_callback(ctx, output)
     }
 
+    pub fn migrate_stake<'info>(
+        ctx: Context<'info, MigrateStake<'info>>,
+        computation_offset: u64,
+        from_stake_account_id: u32,
+        to_stake_account_id: u32,
+        authorized_reader_nonce: u128,
+        state_nonce: u128,
+        cu_price_micro: Option<u64>,
+    ) -> Result<()> {
+        instructions::migrate_stake(
+            ctx,
+            computation_offset,
+            from_stake_account_id,
+            to_stake_account_id,
+            authorized_reader_nonce,
+            state_nonce,
+            cu_price_micro,
+        )
+    }
+
     pub fn reveal_stake(
         ctx: Context<RevealStake>,
         computation_offset: u64,
         stake_account_id: u32,
+        cu_price_micro: Option<u64>,
     ) -> Result<()> {
-        instructions::reveal_stake(ctx, computation_offset, stake_account_id)
+        instructions::reveal_stake(ctx, computation_offset, stake_account_id, cu_price_micro)
     }
 
     #[arcium_callback(encrypted_ix = "reveal_stake")]
@@ -237,4 +447,54 @@ pub mod opportunity_market {
     ) -> Result<()> {
         instructions::reveal_stake_callback(ctx, output)
     }
+
+    pub fn reveal_stake_batch_comp_def(ctx: Context<RevealStakeBatchCompDef>) -> Result<()> {
+        instructions::reveal_stake_batch_comp_def(ctx)
+    }
+
+    pub fn reveal_stake_batch<'info>(
+        ctx: Context<'info, RevealStakeBatch<'info>>,
+        computation_offset: u64,
+        cu_price_micro: Option<u64>,
+    ) -> Result<()> {
+        instructions::reveal_stake_batch(ctx, computation_offset, cu_price_micro)
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_stake_batch")]
+    pub fn reveal_stake_batch_callback(
+        ctx: Context<RevealStakeBatchCallback>,
+        output: SignedComputationOutputs<RevealStakeBatchOutput>,
+    ) -> Result<()> {
+        instructions::reveal_stake_batch_callback(ctx, output)
+    }
+
+    pub fn verify_stake_disclosure_comp_def(
+        ctx: Context<VerifyStakeDisclosureCompDef>,
+    ) -> Result<()> {
+        instructions::verify_stake_disclosure_comp_def(ctx)
+    }
+
+    pub fn verify_stake_disclosure(
+        ctx: Context<VerifyStakeDisclosure>,
+        computation_offset: u64,
+        stake_account_id: u32,
+        claimed_option: u64,
+        cu_price_micro: Option<u64>,
+    ) -> Result<()> {
+        instructions::verify_stake_disclosure(
+            ctx,
+            computation_offset,
+            stake_account_id,
+            claimed_option,
+            cu_price_micro,
+        )
+    }
+
+    #[arcium_callback(encrypted_ix = "verify_stake_disclosure")]
+    pub fn verify_stake_disclosure_callback(
+        ctx: Context<VerifyStakeDisclosureCallback>,
+        output: SignedComputationOutputs<VerifyStakeDisclosureOutput>,
+    ) -> Result<()> {
+        instructions::verify_stake_disclosure_callback(ctx, output)
+    }
 }
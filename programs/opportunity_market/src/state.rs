@@ -1,13 +1,19 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use arcium_anchor::prelude::*;
 
+use crate::computation::derive_computation_offset;
 use crate::constants::{
-    MAX_CREATOR_FEE_BP, MAX_PLATFORM_FEE_BP, MAX_REWARD_POOL_FEE_BP, MAX_TOTAL_FEE_BP,
+    AUDIT_LOG_CAPACITY, MAX_CATEGORY_NAME_LEN, MAX_CREATOR_FEE_BP, MAX_CU_PRICE_MICRO,
+    MAX_PLATFORM_FEE_BP, MAX_RESULT_OPTIONS, MAX_REWARD_POOL_FEE_BP, MAX_TOTAL_FEE_BP,
+    MAX_TRANSFER_HOOK_PROGRAMS, OPTION_SEED,
 };
 use crate::error::ErrorCode;
 
 #[account]
 #[derive(InitSpace)]
 pub struct PlatformConfig {
+    pub schema_version: u8,
     pub bump: u8,
 
     // Human-readable platform name
@@ -31,20 +37,88 @@ pub struct PlatformConfig {
 
     // After this duration from resolution, end_reveal_period becomes permissionless.
     pub reveal_period_seconds: u64,
+
+    // Kill switch: blocks market creation, deposits, withdrawals and new computations.
+    // Callbacks are not blocked so in-flight computations can land.
+    pub paused: bool,
+
+    // Nominated by set_update_authority; becomes update_authority once it signs accept_update_authority.
+    pub pending_update_authority: Option<Pubkey>,
+
+    // Priority fee used when queueing computations if the caller doesn't pass one.
+    pub default_cu_price_micro: u64,
+
+    // Delay between update_platform_config queueing a change and execute_config_change applying it.
+    pub config_change_delay_seconds: u64,
+
+    // Transfer hook programs that allowed mints may use; see init_allowed_mint.
+    #[max_len(MAX_TRANSFER_HOOK_PROGRAMS)]
+    pub transfer_hook_programs: Vec<Pubkey>,
+}
+
+impl PlatformConfig {
+    /// Resolves the priority fee for a queued computation, falling back to the platform default.
+    pub fn cu_price_micro(&self, requested: Option<u64>) -> Result<u64> {
+        let cu_price_micro = requested.unwrap_or(self.default_cu_price_micro);
+        require!(
+            cu_price_micro <= MAX_CU_PRICE_MICRO,
+            ErrorCode::InvalidParameters
+        );
+        Ok(cu_price_micro)
+    }
+
+    pub fn apply_change(&mut self, change: &PlatformConfigChange) {
+        self.fee_rates = change.fee_rates;
+        self.reveal_authority = change.reveal_authority;
+        self.min_time_to_stake_seconds = change.min_time_to_stake_seconds;
+        self.reveal_period_seconds = change.reveal_period_seconds;
+        self.market_resolution_deadline_seconds = change.market_resolution_deadline_seconds;
+        self.default_cu_price_micro = change.default_cu_price_micro;
+        self.config_change_delay_seconds = change.config_change_delay_seconds;
+    }
+}
+
+/// Parameters set by update_platform_config, validated when queued.
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct PlatformConfigChange {
+    pub fee_rates: FeeRates,
+    pub reveal_authority: Pubkey,
+    pub min_time_to_stake_seconds: u64,
+    pub reveal_period_seconds: u64,
+    pub market_resolution_deadline_seconds: u64,
+    pub default_cu_price_micro: u64,
+    pub config_change_delay_seconds: u64,
+}
+
+/// A queued platform config change; one per platform at a time.
+#[account]
+#[derive(InitSpace)]
+pub struct PendingConfigChange {
+    pub schema_version: u8,
+    pub bump: u8,
+    pub platform: Pubkey,
+    pub queued_at: u64,
+    pub executable_at: u64,
+    pub change: PlatformConfigChange,
 }
 
 /// Whitelisted token per platform
 #[account]
 #[derive(InitSpace)]
 pub struct AllowedMint {
+    pub schema_version: u8,
     pub bump: u8,
     pub platform: Pubkey,
     pub mint: Pubkey,
+    // Set if the mint has a transfer hook; token transfers must then pass the hook's
+    // extra accounts as remaining accounts.
+    pub transfer_hook_program: Option<Pubkey>,
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct OpportunityMarket {
+    pub schema_version: u8,
     pub bump: u8,
     pub creator: Pubkey, // part of PDA seed
     pub index: u64,      // part of PDA seed
@@ -98,6 +172,65 @@ pub struct OpportunityMarket {
 
     // Minimum stake amount (in SPL token base units) required for a stake.
     pub min_stake_amount: u64,
+
+    // Winners' rewards vest in this many equal tranches, the first at resolution and one more
+    // every reward_tranche_interval_seconds. 1 pays the whole reward at once.
+    pub reward_tranches: u8,
+    pub reward_tranche_interval_seconds: u64,
+
+    // Share of the reward pool, in basis points, paid pro-rata by stake to revealed losers.
+    pub loser_rebate_bp: u16,
+    // Stake finalized on non-winning options; the denominator for loser rebates.
+    pub losing_stake_total: u64,
+
+    // Replacement market stakers can move their position to via migrate_stake.
+    pub successor_market: Option<Pubkey>,
+
+    // When set, only wallets holding a ParticipantPass for this market can stake.
+    pub participants_restricted: bool,
+
+    // When set, stakers must hold a token of this mint, e.g. a soulbound identity token.
+    pub gate_mint: Option<Pubkey>,
+
+    // Participation the market needs by stake end to be valid; see is_void.
+    pub min_total_stake: u64,
+    pub min_participants: u32,
+    // Live stake, counted per stake account, excluding stakes withdrawn before stake end.
+    pub total_stake: u64,
+    pub participant_count: u32,
+
+    // Lamports held on this account for paying finalize_reveal_stake crankers, and the
+    // bounty paid per finalized stake.
+    pub crank_budget_lamports: u64,
+    pub crank_bounty_lamports: u64,
+    // MPC fees of reveal computations refunded to their payers out of the crank budget.
+    pub mpc_fees_reimbursed: u64,
+
+    // Category PDA this market is listed under, if any.
+    pub category: Option<Pubkey>,
+
+    // Set by the platform's emergency_resolve; the market is then void.
+    pub emergency_voided: bool,
+
+    // When set, audited instructions must be passed this market's AuditLog.
+    pub audit_log_enabled: bool,
+}
+
+/// Lifecycle phase of a market, as derived by `OpportunityMarket::phase`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, AnchorSerialize, AnchorDeserialize)]
+pub enum MarketPhase {
+    // Created but not opened yet.
+    Draft,
+    Staking,
+    // Staking ended; waiting for the market authority to resolve.
+    AwaitingResolution,
+    // Resolved; stakes can be revealed until the reveal period ends.
+    Revealing,
+    // Reveal period ended; stakes can be closed for their payout.
+    Settled,
+    // Not resolved by the resolution deadline; refunded like a void market.
+    Expired,
+    Void,
 }
 
 #[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, InitSpace)]
@@ -177,9 +310,170 @@ impl OpportunityMarket {
             .checked_add(fees.creator_fee)
             .ok_or(ErrorCode::Overflow.into())
     }
+
+    /// Appends an entry to this market's audit log. Once the market has one, leaving it out
+    /// fails, so entries can't be skipped.
+    pub fn record_audit(
+        &self,
+        market: Pubkey,
+        audit_log: Option<&AccountLoader<AuditLog>>,
+        actor: Pubkey,
+        instruction: AuditInstruction,
+        computation_offset: u64,
+    ) -> Result<()> {
+        let Some(audit_log) = audit_log else {
+            require!(!self.audit_log_enabled, ErrorCode::AuditLogRequired);
+            return Ok(());
+        };
+        let mut audit_log = audit_log.load_mut()?;
+        require_keys_eq!(audit_log.market, market, ErrorCode::InvalidAccountState);
+        audit_log.record(actor, instruction, Clock::get()?.slot, computation_offset);
+        Ok(())
+    }
+
+    /// The market's lifecycle phase at `now`, and when it ends if that is fixed.
+    pub fn phase(&self, now: u64) -> Result<(MarketPhase, Option<u64>)> {
+        if let Some(resolved_at) = self.resolved_at_timestamp {
+            if self.reveal_ended {
+                return Ok((MarketPhase::Settled, None));
+            }
+            let reveal_end = resolved_at
+                .checked_add(self.reveal_period_seconds)
+                .ok_or(ErrorCode::Overflow)?;
+            return Ok((MarketPhase::Revealing, Some(reveal_end)));
+        }
+        if self.is_void(now) {
+            return Ok((MarketPhase::Void, None));
+        }
+        let Some(stake_end) = self.stake_end_timestamp else {
+            return Ok((MarketPhase::Draft, None));
+        };
+        if now <= stake_end {
+            return Ok((MarketPhase::Staking, Some(stake_end)));
+        }
+        let select_deadline = stake_end
+            .checked_add(self.market_resolution_deadline_seconds)
+            .ok_or(ErrorCode::Overflow)?;
+        if now < select_deadline {
+            Ok((MarketPhase::AwaitingResolution, Some(select_deadline)))
+        } else {
+            Ok((MarketPhase::Expired, None))
+        }
+    }
+
+    /// Whether the staking period ended below the participation threshold, or the platform
+    /// voided the market. A void market can't be resolved and is refunded like an expired one.
+    /// Participation only changes while staking is open, so this can't change once it is over.
+    pub fn is_void(&self, now: u64) -> bool {
+        if self.emergency_voided {
+            return true;
+        }
+        let Some(stake_end) = self.stake_end_timestamp else {
+            return false;
+        };
+        self.resolved_at_timestamp.is_none()
+            && now > stake_end
+            && (self.total_stake < self.min_total_stake
+                || self.participant_count < self.min_participants)
+    }
+
+    /// Whether the creator can take back the crank budget: reveals are over, or the market is
+    /// void or expired and will never reach a reveal period.
+    pub fn crank_budget_releasable(&self, now: u64) -> Result<bool> {
+        Ok(matches!(
+            self.phase(now)?.0,
+            MarketPhase::Settled | MarketPhase::Void | MarketPhase::Expired
+        ))
+    }
+
+    /// Counts a stake toward the participation threshold when it is queued, so a callback
+    /// landing after the staking period can't change the count.
+    pub fn record_participant(&mut self, amount: u64) {
+        self.total_stake = self.total_stake.saturating_add(amount);
+        self.participant_count = self.participant_count.saturating_add(1);
+    }
+
+    /// Removes a stake withdrawn or refunded during the staking period from the participation
+    /// count.
+    pub fn remove_participant(&mut self, amount: u64) {
+        self.total_stake = self.total_stake.saturating_sub(amount);
+        self.participant_count = self.participant_count.saturating_sub(1);
+    }
+
+    /// Checks a staker's token account against the market's gate mint, if any.
+    pub fn check_gate(
+        &self,
+        gate_token_account: Option<&TokenAccount>,
+        staker: &Pubkey,
+    ) -> Result<()> {
+        let Some(gate_mint) = self.gate_mint else {
+            return Ok(());
+        };
+        let token_account = gate_token_account.ok_or(ErrorCode::GateTokenRequired)?;
+        require!(
+            token_account.mint == gate_mint
+                && token_account.owner == *staker
+                && token_account.amount > 0,
+            ErrorCode::GateTokenRequired
+        );
+        Ok(())
+    }
+
+    /// Part of the reward pool set aside for revealed losers; nothing if none revealed.
+    pub fn loser_rebate_pool(&self) -> Result<u64> {
+        if self.losing_stake_total == 0 {
+            return Ok(0);
+        }
+        let pool = (self.reward_amount as u128)
+            .checked_mul(self.loser_rebate_bp as u128)
+            .ok_or(ErrorCode::Overflow)?
+            / 10_000;
+        Ok(pool as u64)
+    }
+
+    /// Part of the reward pool shared among winning options.
+    pub fn winner_reward_pool(&self) -> Result<u64> {
+        self.reward_amount
+            .checked_sub(self.loser_rebate_pool()?)
+            .ok_or(ErrorCode::Overflow.into())
+    }
+
+    /// A revealed loser's share of the rebate pool.
+    pub fn loser_rebate(&self, stake_amount: u64) -> Result<u64> {
+        if self.losing_stake_total == 0 {
+            return Ok(0);
+        }
+        let rebate = (self.loser_rebate_pool()? as u128)
+            .checked_mul(stake_amount as u128)
+            .ok_or(ErrorCode::Overflow)?
+            / self.losing_stake_total as u128;
+        Ok(rebate as u64)
+    }
+
+    /// Portion of a winner's `reward` that has vested by `now`.
+    pub fn vested_reward(&self, reward: u64, now: u64) -> Result<u64> {
+        let tranches = self.reward_tranches.max(1) as u64;
+        if tranches == 1 {
+            return Ok(reward);
+        }
+        let resolved_at = self
+            .resolved_at_timestamp
+            .ok_or(ErrorCode::MarketNotResolved)?;
+        let unlocked = now
+            .saturating_sub(resolved_at)
+            .checked_div(self.reward_tranche_interval_seconds)
+            .ok_or(ErrorCode::Overflow)?
+            .saturating_add(1)
+            .min(tranches);
+        let vested = (reward as u128)
+            .checked_mul(unlocked as u128)
+            .ok_or(ErrorCode::Overflow)?
+            / tranches as u128;
+        Ok(vested as u64)
+    }
 }
 
-#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, InitSpace)]
+#[derive(Clone, Copy, Default, AnchorSerialize, AnchorDeserialize, InitSpace)]
 pub struct CollectedFees {
     pub platform_fee: u64,
     pub reward_pool_fee: u64,
@@ -198,8 +492,9 @@ impl CollectedFees {
     }
 }
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct StakeAccount {
+    pub schema_version: u8,
     pub encrypted_option: [u8; 32], // encrypted option ciphertext
     pub state_nonce: u128,
     pub bump: u8,
@@ -223,11 +518,106 @@ pub struct StakeAccount {
 
     // True while MPC reveal computation is in flight
     pub pending_reveal: bool,
+
+    // Offset of the computation queued against this account, consumed by its callback, so a
+    // re-submitted callback can't roll the state nonce back. Queued offsets are derived from
+    // next_computation_seq and never repeat.
+    pub pending_computation_offset: Option<u64>,
+
+    // Reward paid out so far through claim_reward_tranche.
+    pub reward_claimed: u64,
+
+    // Sequence the next computation offset is derived from. Starts at the creation slot, so an
+    // account re-created at the same address doesn't repeat its predecessor's offsets.
+    pub next_computation_seq: u64,
+
+    // Set when the position was counted on the owner's UserPortfolio, which must then be
+    // passed when the account is closed.
+    pub portfolio_tracked: bool,
+
+    // Set when the position moved to a successor market via migrate_stake. The option now
+    // belongs to the successor stake, so this account can't be revealed.
+    pub migrated: bool,
+}
+
+impl StakeAccount {
+    /// Offset the next computation queued against this account must use.
+    pub fn next_computation_offset(&self, account: &Pubkey) -> u64 {
+        derive_computation_offset(account, self.next_computation_seq)
+    }
+
+    /// Checks that `computation_offset` is this account's next derived offset, and advances
+    /// the sequence past it.
+    pub fn consume_computation_offset(
+        &mut self,
+        account: &Pubkey,
+        computation_offset: u64,
+    ) -> Result<()> {
+        require!(
+            computation_offset == self.next_computation_offset(account),
+            ErrorCode::UnexpectedComputationOffset
+        );
+        self.next_computation_seq = self
+            .next_computation_seq
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+        Ok(())
+    }
+
+    /// Counts this account as an open position on its owner's portfolio, if one is passed.
+    pub fn open_in_portfolio(&mut self, user_portfolio: Option<&mut UserPortfolio>, slot: u64) {
+        if let Some(user_portfolio) = user_portfolio {
+            user_portfolio.record_opened(slot);
+            self.portfolio_tracked = true;
+        }
+    }
+
+    /// Counts this account's position as closed on its owner's portfolio. The portfolio is
+    /// required if the position was counted there and ignored otherwise, so its open count
+    /// can't drift.
+    pub fn close_in_portfolio(
+        &self,
+        user_portfolio: Option<&mut UserPortfolio>,
+        slot: u64,
+    ) -> Result<()> {
+        if !self.portfolio_tracked {
+            return Ok(());
+        }
+        user_portfolio
+            .ok_or(ErrorCode::UserPortfolioRequired)?
+            .record_closed(slot);
+        Ok(())
+    }
+
+    /// Records the offset of a computation queued against this account.
+    pub fn queue_computation_offset(&mut self, computation_offset: u64) {
+        self.pending_computation_offset = Some(computation_offset);
+    }
+
+    /// Consumes the pending computation offset for a callback. Fails if the callback comes from
+    /// any computation other than the one queued last, or if that one was already applied.
+    pub fn apply_callback(
+        &mut self,
+        computation_account: Pubkey,
+        mxe_account: &MXEAccount,
+    ) -> Result<()> {
+        let computation_offset = self
+            .pending_computation_offset
+            .ok_or(ErrorCode::CallbackAlreadyApplied)?;
+        require_keys_eq!(
+            computation_account,
+            derive_comp_pda!(computation_offset, mxe_account),
+            ErrorCode::InvalidAccountState
+        );
+        self.pending_computation_offset = None;
+        Ok(())
+    }
 }
 
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct OpportunityMarketOption {
+    pub schema_version: u8,
     pub bump: u8,
     pub id: u64,
     pub creator: Pubkey,
@@ -240,12 +630,507 @@ pub struct OpportunityMarketOption {
     pub reward_bp: Option<u16>,
 }
 
+/// Creator-issued permission for one wallet to stake in a restricted market.
+#[account]
+#[derive(InitSpace)]
+pub struct ParticipantPass {
+    pub schema_version: u8,
+    pub bump: u8,
+    pub market: Pubkey,
+    pub participant: Pubkey,
+    pub issued_at: u64,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct OpportunityMarketSponsor {
+    pub schema_version: u8,
     pub bump: u8,
     pub sponsor: Pubkey,
     pub market: Pubkey,
     pub reward_deposited: u64,
     pub reward_locked: bool,
 }
+
+#[derive(Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum ReceiptKind {
+    Deposit,
+    Claim,
+}
+
+/// Integrator-supplied reference for a deposit or claim, keyed by the client's own ID.
+#[account]
+#[derive(InitSpace)]
+pub struct DepositReceipt {
+    pub schema_version: u8,
+    pub bump: u8,
+    pub owner: Pubkey,
+    pub market: Pubkey,
+    pub stake_account: Pubkey,
+    pub stake_account_id: u32,
+    pub kind: ReceiptKind,
+    pub client_id: [u8; 32], // part of PDA seed
+    // Opaque to the program: recorded as given, never checked for order or uniqueness.
+    pub sequence: u64,
+    pub amount: u64,
+    pub created_at: u64,
+}
+
+/// Singleton set by `initialize_comp_defs` once every computation definition exists.
+#[account]
+#[derive(InitSpace)]
+pub struct BootstrapState {
+    pub schema_version: u8,
+    pub bump: u8,
+    pub comp_defs_initialized: bool,
+}
+
+/// Singleton counters for monitoring MPC traffic, created by `initialize_comp_defs`.
+/// Counters saturate rather than fail the instruction that updates them.
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramMetrics {
+    pub schema_version: u8,
+    pub bump: u8,
+    pub computations_queued: u64,
+    pub callback_successes: u64,
+    pub callback_aborts: u64,
+
+    // Per-instruction volume of queued computations
+    pub stakes: u64,
+    pub reveals: u64,
+    pub disclosure_verifications: u64,
+
+    // Lamports charged to payers by the Arcium program for queueing computations, excluding
+    // the computation account rent that claim_computation_rent returns.
+    pub mpc_fees_charged: u64,
+}
+
+impl ProgramMetrics {
+    pub fn record_queued(&mut self) {
+        self.computations_queued = self.computations_queued.saturating_add(1);
+    }
+
+    pub fn record_mpc_fee(&mut self, lamports: u64) {
+        self.mpc_fees_charged = self.mpc_fees_charged.saturating_add(lamports);
+    }
+
+    pub fn record_callback(&mut self, succeeded: bool) {
+        if succeeded {
+            self.callback_successes = self.callback_successes.saturating_add(1);
+        } else {
+            self.callback_aborts = self.callback_aborts.saturating_add(1);
+        }
+    }
+}
+
+/// Outcome of a resolved market, written by `resolve_market` so other programs can read it
+/// over CPI instead of parsing events. Option totals are final once `reveal_ended` is set.
+#[account]
+#[derive(InitSpace)]
+pub struct MarketResult {
+    pub schema_version: u8,
+    pub bump: u8,
+    pub market: Pubkey,
+    pub resolver: Pubkey,
+    pub resolved_at_timestamp: u64,
+    pub resolved_slot: u64,
+    pub reveal_ended: bool,
+    #[max_len(MAX_RESULT_OPTIONS)]
+    pub winning_options: Vec<OptionResult>,
+}
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct OptionResult {
+    pub option_id: u64,
+    pub reward_bp: u16,
+    pub total_staked: u64,
+    pub total_score: u128,
+}
+
+impl OptionResult {
+    /// Reads a winning option of `market` from an account passed in remaining accounts.
+    pub fn from_option_account<'info>(
+        info: &'info AccountInfo<'info>,
+        market: &Pubkey,
+    ) -> Result<Self> {
+        let option = Account::<OpportunityMarketOption>::try_from(info)?;
+        let option_key = Pubkey::create_program_address(
+            &[
+                OPTION_SEED,
+                market.as_ref(),
+                &option.id.to_le_bytes(),
+                &[option.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| ErrorCode::InvalidParameters)?;
+        require_keys_eq!(info.key(), option_key, ErrorCode::InvalidParameters);
+
+        Ok(Self {
+            option_id: option.id,
+            reward_bp: option.reward_bp.ok_or(ErrorCode::InvalidParameters)?,
+            total_staked: option.total_staked,
+            total_score: option.total_score,
+        })
+    }
+}
+
+/// Platform-defined market vertical. Its markets are listed in CategoryMarket PDAs indexed
+/// 0..market_count, so explorers can page through them without an off-chain index.
+#[account]
+#[derive(InitSpace)]
+pub struct Category {
+    pub schema_version: u8,
+    pub bump: u8,
+    pub platform: Pubkey,
+    pub id: u32,
+    #[max_len(MAX_CATEGORY_NAME_LEN)]
+    pub name: String,
+    pub market_count: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CategoryMarket {
+    pub schema_version: u8,
+    pub bump: u8,
+    pub category: Pubkey,
+    pub index: u64,
+    pub market: Pubkey,
+}
+
+/// Per-wallet summary of stake positions for portfolio views, so a wallet needs one fetch
+/// instead of a program account scan. Only counts instructions that were passed the account.
+#[account]
+#[derive(InitSpace)]
+pub struct UserPortfolio {
+    pub schema_version: u8,
+    pub bump: u8,
+    pub owner: Pubkey,
+    pub positions_opened: u64,
+    pub open_positions: u64,
+    pub last_activity_slot: u64,
+}
+
+impl UserPortfolio {
+    pub fn record_opened(&mut self, slot: u64) {
+        self.positions_opened = self.positions_opened.saturating_add(1);
+        self.open_positions = self.open_positions.saturating_add(1);
+        self.last_activity_slot = slot;
+    }
+
+    pub fn record_closed(&mut self, slot: u64) {
+        self.open_positions = self.open_positions.saturating_sub(1);
+        self.last_activity_slot = slot;
+    }
+}
+
+/// Instruction codes stored in AuditEntry::instruction.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AuditInstruction {
+    OpenMarket,
+    Stake,
+    Unstake,
+    MigrateStake,
+    ResolveMarket,
+    RevealStake,
+    EndRevealPeriod,
+    CloseStakeAccount,
+    EmergencyResolve,
+}
+
+#[zero_copy]
+pub struct AuditEntry {
+    pub actor: Pubkey,
+    pub slot: u64,
+    // 0 for instructions that don't queue a computation.
+    pub computation_offset: u64,
+    pub instruction: u8,
+    pub _padding: [u8; 7],
+}
+
+/// Ring buffer of a market's last AUDIT_LOG_CAPACITY state transitions, for deployments that
+/// can't rely on event history retention. Entry `i` lives at `entries[i % AUDIT_LOG_CAPACITY]`;
+/// `entry_count` counts every entry ever written.
+#[account(zero_copy)]
+pub struct AuditLog {
+    pub schema_version: u8,
+    pub bump: u8,
+    pub _padding: [u8; 6],
+    pub market: Pubkey,
+    pub entry_count: u64,
+    pub entries: [AuditEntry; AUDIT_LOG_CAPACITY],
+}
+
+impl AuditLog {
+    pub fn record(
+        &mut self,
+        actor: Pubkey,
+        instruction: AuditInstruction,
+        slot: u64,
+        computation_offset: u64,
+    ) {
+        self.entries[(self.entry_count % AUDIT_LOG_CAPACITY as u64) as usize] = AuditEntry {
+            actor,
+            slot,
+            computation_offset,
+            instruction: instruction as u8,
+            _padding: [0; 7],
+        };
+        self.entry_count = self.entry_count.wrapping_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STAKE_END: u64 = 1_714_521_600;
+    const RESOLVED_AT: u64 = STAKE_END + 24 * 60 * 60;
+    const ONE_WEEK: u64 = 7 * 24 * 60 * 60;
+    const REWARD: u64 = 1_000_000_000_000;
+
+    fn market() -> OpportunityMarket {
+        OpportunityMarket {
+            schema_version: 1,
+            bump: 255,
+            creator: Pubkey::new_unique(),
+            index: 0,
+            total_options: 2,
+            platform: Pubkey::new_unique(),
+            stake_end_timestamp: Some(STAKE_END),
+            resolved_at_timestamp: None,
+            winning_option_allocation: 0,
+            reward_amount: REWARD,
+            market_authority: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            earliness_cutoff_seconds: 0,
+            earliness_multiplier: 10_000,
+            allow_unstaking_early: true,
+            authorized_reader_pubkey: [0; 32],
+            fee_rates: FeeRates::new(0, 0, 0).unwrap(),
+            collected_platform_fees: 0,
+            collected_creator_fees: 0,
+            creator_fee_claimer: Pubkey::new_unique(),
+            market_resolution_deadline_seconds: ONE_WEEK,
+            reveal_period_seconds: ONE_WEEK,
+            reveal_ended: false,
+            min_stake_amount: 0,
+            reward_tranches: 1,
+            reward_tranche_interval_seconds: 0,
+            loser_rebate_bp: 0,
+            losing_stake_total: 0,
+            successor_market: None,
+            participants_restricted: false,
+            gate_mint: None,
+            min_total_stake: 0,
+            min_participants: 0,
+            total_stake: 0,
+            participant_count: 0,
+            crank_budget_lamports: 0,
+            crank_bounty_lamports: 0,
+            mpc_fees_reimbursed: 0,
+            category: None,
+            emergency_voided: false,
+            audit_log_enabled: false,
+        }
+    }
+
+    fn vesting_market(tranches: u8) -> OpportunityMarket {
+        OpportunityMarket {
+            resolved_at_timestamp: Some(RESOLVED_AT),
+            reward_tranches: tranches,
+            reward_tranche_interval_seconds: ONE_WEEK,
+            ..market()
+        }
+    }
+
+    #[test]
+    fn single_tranche_vests_immediately() {
+        let m = OpportunityMarket {
+            resolved_at_timestamp: None,
+            ..vesting_market(1)
+        };
+        // No resolution timestamp needed when nothing vests over time.
+        assert_eq!(m.vested_reward(REWARD, 0).unwrap(), REWARD);
+    }
+
+    #[test]
+    fn tranches_unlock_at_interval_boundaries() {
+        let m = vesting_market(4);
+        let quarter = REWARD / 4;
+        assert_eq!(m.vested_reward(REWARD, RESOLVED_AT).unwrap(), quarter);
+        assert_eq!(
+            m.vested_reward(REWARD, RESOLVED_AT + ONE_WEEK - 1).unwrap(),
+            quarter
+        );
+        assert_eq!(
+            m.vested_reward(REWARD, RESOLVED_AT + ONE_WEEK).unwrap(),
+            2 * quarter
+        );
+        assert_eq!(
+            m.vested_reward(REWARD, RESOLVED_AT + 3 * ONE_WEEK).unwrap(),
+            REWARD
+        );
+        assert_eq!(
+            m.vested_reward(REWARD, RESOLVED_AT + 100 * ONE_WEEK)
+                .unwrap(),
+            REWARD
+        );
+    }
+
+    #[test]
+    fn vesting_rounds_down_until_the_last_tranche() {
+        let m = vesting_market(3);
+        assert_eq!(m.vested_reward(10, RESOLVED_AT).unwrap(), 3);
+        assert_eq!(m.vested_reward(10, RESOLVED_AT + ONE_WEEK).unwrap(), 6);
+        assert_eq!(m.vested_reward(10, RESOLVED_AT + 2 * ONE_WEEK).unwrap(), 10);
+    }
+
+    #[test]
+    fn multi_tranche_vesting_requires_resolution() {
+        let m = OpportunityMarket {
+            resolved_at_timestamp: None,
+            ..vesting_market(4)
+        };
+        assert!(m.vested_reward(REWARD, RESOLVED_AT).is_err());
+    }
+
+    #[test]
+    fn no_rebate_without_revealed_losers() {
+        let m = OpportunityMarket {
+            loser_rebate_bp: 5_000,
+            losing_stake_total: 0,
+            ..market()
+        };
+        assert_eq!(m.loser_rebate_pool().unwrap(), 0);
+        assert_eq!(m.winner_reward_pool().unwrap(), REWARD);
+        assert_eq!(m.loser_rebate(1_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn rebate_is_pro_rata_and_never_exceeds_the_pool() {
+        let stakes = [1u64, 333_333, 666_666];
+        let m = OpportunityMarket {
+            loser_rebate_bp: 2_000,
+            losing_stake_total: stakes.iter().sum(),
+            ..market()
+        };
+        let pool = m.loser_rebate_pool().unwrap();
+        assert_eq!(pool, REWARD / 5);
+        assert_eq!(m.winner_reward_pool().unwrap(), REWARD - pool);
+
+        let rebates: Vec<u64> = stakes.iter().map(|&s| m.loser_rebate(s).unwrap()).collect();
+        assert_eq!(m.loser_rebate(m.losing_stake_total).unwrap(), pool);
+        assert!(rebates[2] > rebates[1] && rebates[1] > rebates[0]);
+        assert!(rebates.iter().sum::<u64>() <= pool);
+    }
+
+    fn threshold_market() -> OpportunityMarket {
+        OpportunityMarket {
+            min_total_stake: 1_000,
+            min_participants: 2,
+            total_stake: 999,
+            participant_count: 2,
+            ..market()
+        }
+    }
+
+    #[test]
+    fn void_only_after_stake_end() {
+        let m = threshold_market();
+        assert!(!m.is_void(STAKE_END - 1));
+        // Staking is still open at stake_end itself.
+        assert!(!m.is_void(STAKE_END));
+        assert!(m.is_void(STAKE_END + 1));
+    }
+
+    #[test]
+    fn void_needs_both_minimums_met() {
+        let met = OpportunityMarket {
+            total_stake: 1_000,
+            ..threshold_market()
+        };
+        assert!(!met.is_void(STAKE_END + 1));
+        let too_few = OpportunityMarket {
+            participant_count: 1,
+            ..met.clone()
+        };
+        assert!(too_few.is_void(STAKE_END + 1));
+    }
+
+    #[test]
+    fn resolved_or_unopened_markets_are_not_void() {
+        let resolved = OpportunityMarket {
+            resolved_at_timestamp: Some(RESOLVED_AT),
+            ..threshold_market()
+        };
+        assert!(!resolved.is_void(RESOLVED_AT));
+        let draft = OpportunityMarket {
+            stake_end_timestamp: None,
+            ..threshold_market()
+        };
+        assert!(!draft.is_void(STAKE_END + 1));
+    }
+
+    #[test]
+    fn emergency_voided_market_is_void_in_any_phase() {
+        let draft = OpportunityMarket {
+            emergency_voided: true,
+            stake_end_timestamp: None,
+            ..threshold_market()
+        };
+        assert!(draft.is_void(0));
+        let met = OpportunityMarket {
+            emergency_voided: true,
+            total_stake: 1_000,
+            ..threshold_market()
+        };
+        assert!(met.is_void(STAKE_END + 1));
+    }
+
+    #[test]
+    fn crank_budget_releasable_once_void() {
+        let m = threshold_market();
+        assert!(!m.crank_budget_releasable(STAKE_END).unwrap());
+        assert!(m.crank_budget_releasable(STAKE_END + 1).unwrap());
+    }
+
+    #[test]
+    fn crank_budget_releasable_once_expired() {
+        let m = market();
+        assert!(!m.crank_budget_releasable(STAKE_END + ONE_WEEK - 1).unwrap());
+        assert!(m.crank_budget_releasable(STAKE_END + ONE_WEEK).unwrap());
+    }
+
+    #[test]
+    fn crank_budget_held_until_reveals_end() {
+        let revealing = OpportunityMarket {
+            resolved_at_timestamp: Some(RESOLVED_AT),
+            ..market()
+        };
+        assert!(!revealing
+            .crank_budget_releasable(RESOLVED_AT + 2 * ONE_WEEK)
+            .unwrap());
+        let settled = OpportunityMarket {
+            reveal_ended: true,
+            ..revealing
+        };
+        assert!(settled.crank_budget_releasable(RESOLVED_AT).unwrap());
+    }
+
+    #[test]
+    fn computation_offset_is_consumed_once() {
+        let key = Pubkey::new_unique();
+        let mut stake = StakeAccount {
+            next_computation_seq: 42,
+            ..Default::default()
+        };
+        let offset = stake.next_computation_offset(&key);
+        stake.consume_computation_offset(&key, offset).unwrap();
+        assert!(stake.consume_computation_offset(&key, offset).is_err());
+        assert_ne!(stake.next_computation_offset(&key), offset);
+    }
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_interface::TransferChecked;
+
+// Same as `token_interface::transfer_checked`, but also passes the context's remaining
+// accounts to the token program. Token-2022 looks up a mint's transfer hook program and its
+// extra accounts among them; the token program ignores them for mints without a hook.
+pub fn transfer_checked<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let (ix, account_infos) = transfer_checked_instruction(&ctx, amount, decimals)?;
+    invoke_signed(&ix, &account_infos, ctx.signer_seeds).map_err(Into::into)
+}
+
+fn transfer_checked_instruction<'info>(
+    ctx: &CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<(Instruction, Vec<AccountInfo<'info>>)> {
+    let mut ix = spl_token_2022::instruction::transfer_checked(
+        &ctx.program_id,
+        ctx.accounts.from.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.to.key,
+        ctx.accounts.authority.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+    let mut account_infos = vec![
+        ctx.accounts.from.clone(),
+        ctx.accounts.mint.clone(),
+        ctx.accounts.to.clone(),
+        ctx.accounts.authority.clone(),
+    ];
+    for account in &ctx.remaining_accounts {
+        ix.accounts.push(AccountMeta {
+            pubkey: *account.key,
+            is_signer: false,
+            is_writable: account.is_writable,
+        });
+        account_infos.push(account.clone());
+    }
+    Ok((ix, account_infos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remaining_accounts_are_appended_to_the_transfer() {
+        let keys: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+        let owner = Pubkey::new_unique();
+        let mut lamports = vec![0u64; keys.len()];
+        let mut data = vec![Vec::<u8>::new(); keys.len()];
+        let infos: Vec<AccountInfo> = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .enumerate()
+            // The last account stands in for a writable extra account of the hook.
+            .map(|(i, ((key, lamports), data))| {
+                AccountInfo::new(key, i == 3, i != 3 && i != 4, lamports, data, &owner, false)
+            })
+            .collect();
+
+        let ctx = CpiContext::new(
+            spl_token_2022::ID,
+            TransferChecked {
+                from: infos[0].clone(),
+                mint: infos[1].clone(),
+                to: infos[2].clone(),
+                authority: infos[3].clone(),
+            },
+        )
+        .with_remaining_accounts(infos[4..].to_vec());
+
+        let (ix, account_infos) = transfer_checked_instruction(&ctx, 10, 6).unwrap();
+
+        assert_eq!(ix.program_id, spl_token_2022::ID);
+        let metas: Vec<(Pubkey, bool, bool)> = ix
+            .accounts
+            .iter()
+            .map(|m| (m.pubkey, m.is_signer, m.is_writable))
+            .collect();
+        assert_eq!(
+            metas,
+            vec![
+                (keys[0], false, true),
+                (keys[1], false, false),
+                (keys[2], false, true),
+                (keys[3], true, false),
+                (keys[4], false, false),
+                (keys[5], false, true),
+            ]
+        );
+        let info_keys: Vec<Pubkey> = account_infos.iter().map(|a| *a.key).collect();
+        assert_eq!(info_keys, keys);
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+declare_id!("zfGZqtmS7gj2H5EmsXZizgFeLSzfk1wrpgCanXpUT61");
+
+// The transfer hook interface's Execute discriminator, the first 8 bytes of
+// sha256("spl-transfer-hook-interface:execute").
+const EXECUTE_DISCRIMINATOR: &[u8] = &[105, 37, 101, 197, 75, 251, 102, 26];
+
+// A transfer hook that allows every transfer. Used by the tests to exercise mints with a
+// TransferHook extension. Mints using it need no extra account metas, so Token-2022 only
+// needs this program among the transfer's accounts.
+#[program]
+pub mod transfer_hook_stub {
+    use super::*;
+
+    #[instruction(discriminator = EXECUTE_DISCRIMINATOR)]
+    pub fn execute(_ctx: Context<Execute>, _amount: u64) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Execute<'info> {
+    /// CHECK: source token account, not inspected.
+    pub source: UncheckedAccount<'info>,
+    /// CHECK: mint, not inspected.
+    pub mint: UncheckedAccount<'info>,
+    /// CHECK: destination token account, not inspected.
+    pub destination: UncheckedAccount<'info>,
+    /// CHECK: transfer authority, not inspected.
+    pub authority: UncheckedAccount<'info>,
+}
@@ -0,0 +1,246 @@
+import {
+  address,
+  createSolanaRpc,
+  createKeyPairSignerFromBytes,
+  pipe,
+  createTransactionMessage,
+  setTransactionMessageFeePayer,
+  setTransactionMessageLifetimeUsingBlockhash,
+  appendTransactionMessageInstructions,
+  signTransactionMessageWithSigners,
+  getBase64EncodedWireTransaction,
+  getSignatureFromTransaction,
+  getBase58Decoder,
+  getBase64Encoder,
+  getU64Encoder,
+  type Address,
+  type Base58EncodedBytes,
+  type Instruction,
+  type KeyPairSigner,
+  type Rpc,
+  type SolanaRpcApi,
+  type Signature,
+} from "@solana/kit";
+import {
+  OPPORTUNITY_MARKET_DISCRIMINATOR,
+  STAKE_ACCOUNT_DISCRIMINATOR,
+  getOpportunityMarketDecoder,
+  getStakeAccountDecoder,
+  endRevealPeriod,
+  fetchMarketResult,
+  finalizeRevealStake,
+  getAuditLogAddress,
+  getMarketResultAddress,
+  getOpportunityMarketOptionAddress,
+  type OpportunityMarket,
+  type StakeAccount,
+} from "../js/src";
+import * as fs from "fs";
+import * as os from "os";
+
+// Watches markets past resolution and submits the permissionless lifecycle transactions:
+// finalize_reveal_stake for revealed stakes, then end_reveal_period once the window lapses.
+
+if (!process.env.PROGRAM_ID) throw new Error("PROGRAM_ID env var is required");
+if (!process.env.RPC_URL) throw new Error("RPC_URL env var is required");
+
+const PROGRAM_ID = address(process.env.PROGRAM_ID);
+const RPC_URL = process.env.RPC_URL;
+const POLL_INTERVAL_MS = Number(process.env.POLL_INTERVAL_MS ?? 30_000);
+const PRIORITY_FEE_MICRO_LAMPORTS = BigInt(process.env.PRIORITY_FEE_MICRO_LAMPORTS ?? 0);
+const MAX_RETRIES = Number(process.env.MAX_RETRIES ?? 3);
+
+const COMPUTE_BUDGET_PROGRAM = address("ComputeBudget111111111111111111111111111111");
+const SET_COMPUTE_UNIT_PRICE = 3;
+
+// Byte offset of StakeAccount.market (discriminator, schema_version, encrypted_option, state_nonce, bump, owner).
+const STAKE_ACCOUNT_MARKET_OFFSET = 8 + 1 + 32 + 16 + 1 + 32;
+
+function readSecretKey(path: string): Uint8Array {
+  const file = fs.readFileSync(path);
+  return new Uint8Array(JSON.parse(file.toString()));
+}
+
+function setComputeUnitPrice(microLamports: bigint): Instruction {
+  const data = new Uint8Array(9);
+  data[0] = SET_COMPUTE_UNIT_PRICE;
+  data.set(getU64Encoder().encode(microLamports), 1);
+  return { programAddress: COMPUTE_BUDGET_PROGRAM, data };
+}
+
+async function sendAndConfirmTx(
+  rpc: Rpc<SolanaRpcApi>,
+  signedTx: Parameters<typeof getBase64EncodedWireTransaction>[0]
+): Promise<Signature> {
+  const encodedTx = getBase64EncodedWireTransaction(signedTx);
+  const signature = getSignatureFromTransaction(signedTx);
+  await rpc.sendTransaction(encodedTx, { encoding: "base64" }).send();
+
+  const start = Date.now();
+  const timeout = 60_000;
+  while (Date.now() - start < timeout) {
+    const { value } = await rpc.getSignatureStatuses([signature]).send();
+    const status = value[0];
+    if (status?.confirmationStatus === "confirmed" || status?.confirmationStatus === "finalized") {
+      if (status.err) throw new Error(`Transaction failed: ${JSON.stringify(status.err)}`);
+      return signature;
+    }
+    await new Promise((r) => setTimeout(r, 2000));
+  }
+  throw new Error(`Transaction ${signature} not confirmed within ${timeout / 1000}s`);
+}
+
+async function sendWithRetry(
+  rpc: Rpc<SolanaRpcApi>,
+  payer: KeyPairSigner,
+  ixs: Instruction[],
+  label: string
+): Promise<Signature | null> {
+  const allIxs = PRIORITY_FEE_MICRO_LAMPORTS > 0n
+    ? [setComputeUnitPrice(PRIORITY_FEE_MICRO_LAMPORTS), ...ixs]
+    : ixs;
+
+  for (let attempt = 1; attempt <= MAX_RETRIES; attempt++) {
+    try {
+      // Fresh blockhash per attempt so an expired one doesn't fail every retry.
+      const { value: latestBlockhash } = await rpc.getLatestBlockhash({ commitment: "confirmed" }).send();
+      const signedTx = await signTransactionMessageWithSigners(
+        pipe(
+          createTransactionMessage({ version: 0 }),
+          (msg) => setTransactionMessageFeePayer(payer.address, msg),
+          (msg) => setTransactionMessageLifetimeUsingBlockhash(latestBlockhash, msg),
+          (msg) => appendTransactionMessageInstructions(allIxs, msg)
+        )
+      );
+      const sig = await sendAndConfirmTx(rpc, signedTx);
+      console.log(`  ${label}: ${sig}`);
+      return sig;
+    } catch (err) {
+      console.warn(`  ${label}: attempt ${attempt}/${MAX_RETRIES} failed: ${err}`);
+      await new Promise((r) => setTimeout(r, 1000 * 2 ** attempt));
+    }
+  }
+  console.error(`  ${label}: giving up`);
+  return null;
+}
+
+async function fetchAll<T>(
+  rpc: Rpc<SolanaRpcApi>,
+  discriminator: Uint8Array,
+  decode: (bytes: Uint8Array) => T,
+  stakeAccountMarket?: Address
+): Promise<{ address: Address; data: T }[]> {
+  const filters = [
+    {
+      memcmp: {
+        offset: 0n,
+        bytes: getBase58Decoder().decode(discriminator) as Base58EncodedBytes,
+        encoding: "base58" as const,
+      },
+    },
+  ];
+  if (stakeAccountMarket) {
+    filters.push({
+      memcmp: {
+        offset: BigInt(STAKE_ACCOUNT_MARKET_OFFSET),
+        bytes: stakeAccountMarket as string as Base58EncodedBytes,
+        encoding: "base58" as const,
+      },
+    });
+  }
+  const accounts = await rpc
+    .getProgramAccounts(PROGRAM_ID, { encoding: "base64", filters })
+    .send();
+  return accounts.map(({ pubkey, account }) => ({
+    address: pubkey,
+    data: decode(new Uint8Array(getBase64Encoder().encode(account.data[0]))),
+  }));
+}
+
+async function crankMarket(
+  rpc: Rpc<SolanaRpcApi>,
+  payer: KeyPairSigner,
+  marketAddress: Address,
+  market: OpportunityMarket,
+  now: bigint
+) {
+  if (market.resolvedAtTimestamp.__option !== "Some" || market.revealEnded) return;
+  const resolvedAt = market.resolvedAtTimestamp.value;
+
+  // Finalize any revealed but unscored stakes while the reveal window is still open.
+  const stakes = await fetchAll<StakeAccount>(
+    rpc,
+    STAKE_ACCOUNT_DISCRIMINATOR,
+    (bytes) => getStakeAccountDecoder().decode(bytes),
+    marketAddress
+  );
+  for (const { address: stakeAddress, data: stake } of stakes) {
+    if (stake.revealedOption.__option !== "Some" || stake.score.__option === "Some") continue;
+    const ix = await finalizeRevealStake({
+      signer: payer,
+      owner: stake.owner,
+      market: marketAddress,
+      optionId: stake.revealedOption.value,
+      stakeAccountId: stake.id,
+      programAddress: PROGRAM_ID,
+    });
+    await sendWithRetry(rpc, payer, [ix], `finalize_reveal_stake ${stakeAddress}`);
+  }
+
+  if (now >= resolvedAt + market.revealPeriodSeconds) {
+    // The winners' totals are snapshotted from their option accounts, in the stored order.
+    const [marketResultAddress] = await getMarketResultAddress(marketAddress, PROGRAM_ID);
+    const marketResult = await fetchMarketResult(rpc, marketResultAddress);
+    const winningOptions = await Promise.all(
+      marketResult.data.winningOptions.map(async ({ optionId }) => {
+        const [optionAddress] = await getOpportunityMarketOptionAddress(marketAddress, optionId, PROGRAM_ID);
+        return optionAddress;
+      })
+    );
+    const auditLog = market.auditLogEnabled
+      ? (await getAuditLogAddress(marketAddress, PROGRAM_ID))[0]
+      : undefined;
+    const ix = await endRevealPeriod({
+      signer: payer,
+      platformConfig: market.platform,
+      market: marketAddress,
+      winningOptions,
+      auditLog,
+      programAddress: PROGRAM_ID,
+    });
+    await sendWithRetry(rpc, payer, [ix], `end_reveal_period ${marketAddress}`);
+  }
+}
+
+async function main() {
+  const keypairPath = process.env.DEPLOYER_KEYPAIR_PATH || `${os.homedir()}/.config/solana/id.json`;
+  const payer = await createKeyPairSignerFromBytes(readSecretKey(keypairPath));
+  const rpc = createSolanaRpc(RPC_URL);
+
+  console.log(`Program:        ${PROGRAM_ID}`);
+  console.log(`Payer:          ${payer.address}`);
+  console.log(`Poll interval:  ${POLL_INTERVAL_MS}ms`);
+  console.log(`Priority fee:   ${PRIORITY_FEE_MICRO_LAMPORTS} micro-lamports/CU`);
+
+  while (true) {
+    try {
+      const now = BigInt(Math.floor(Date.now() / 1000));
+      const markets = await fetchAll<OpportunityMarket>(
+        rpc,
+        OPPORTUNITY_MARKET_DISCRIMINATOR,
+        (bytes) => getOpportunityMarketDecoder().decode(bytes)
+      );
+      for (const { address: marketAddress, data: market } of markets) {
+        await crankMarket(rpc, payer, marketAddress, market, now);
+      }
+    } catch (err) {
+      console.error("Crank iteration failed:", err);
+    }
+    await new Promise((r) => setTimeout(r, POLL_INTERVAL_MS));
+  }
+}
+
+main().catch((err) => {
+  console.error("Fatal:", err);
+  process.exit(1);
+});
@@ -106,6 +106,9 @@ async function main() {
         revealAuthority,
         revealPeriodSeconds,
         marketResolutionDeadlineSeconds,
+        // Not set by this script; keep the values already on chain.
+        defaultCuPriceMicro: existing.data.defaultCuPriceMicro,
+        configChangeDelaySeconds: existing.data.configChangeDelaySeconds,
       })
     : await createPlatformConfig(rpc, {
         programAddress: PROGRAM_ID,
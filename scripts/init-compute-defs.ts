@@ -16,6 +16,7 @@ import {
 } from "@solana/kit";
 import {
   getInitCompDefInstruction,
+  getInitializeCompDefsInstruction,
   getCompDefAccount,
   type CompDefCircuitName,
   ALL_COMP_DEF_CIRCUITS,
@@ -115,9 +116,44 @@ async function main() {
     }
   }
 
+  // Marks bootstrap complete; create_market is refused until this has run.
+  try {
+    await completeBootstrap(rpc, payer);
+  } catch (error) {
+    console.error("\nFailed to complete bootstrap, stopping...");
+    console.error(error);
+    process.exit(1);
+  }
+
   console.log("\nAll computation definitions initialized!");
 }
 
+async function completeBootstrap(
+  rpc: Rpc<SolanaRpcApi>,
+  payer: Awaited<ReturnType<typeof createKeyPairSignerFromBytes>>
+): Promise<void> {
+  console.log(`\nRunning initialize_comp_defs...`);
+
+  const ix = await getInitializeCompDefsInstruction(rpc, payer, {
+    programAddress: PROGRAM_ID,
+  });
+
+  const { value: latestBlockhash } = await rpc
+    .getLatestBlockhash({ commitment: "confirmed" })
+    .send();
+
+  const signedTx = await signTransactionMessageWithSigners(
+    pipe(
+      createTransactionMessage({ version: 0 }),
+      (msg) => setTransactionMessageFeePayer(payer.address, msg),
+      (msg) => setTransactionMessageLifetimeUsingBlockhash(latestBlockhash, msg),
+      (msg) => appendTransactionMessageInstructions([ix], msg)
+    )
+  );
+  await sendAndConfirmTx(rpc, signedTx);
+  console.log(`  Bootstrap complete.`);
+}
+
 async function initCompDef(
   rpc: Rpc<SolanaRpcApi>,
   payer: Awaited<ReturnType<typeof createKeyPairSignerFromBytes>>,
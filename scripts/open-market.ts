@@ -14,7 +14,7 @@ import {
   type SolanaRpcApi,
   type Signature,
 } from "@solana/kit";
-import { fetchOpportunityMarket, openMarket } from "../js/src";
+import { fetchOpportunityMarket, getAuditLogAddress, openMarket } from "../js/src";
 import * as fs from "fs";
 import * as os from "os";
 
@@ -75,11 +75,15 @@ async function main() {
   console.log(`Platform cfg:   ${marketAccount.data.platform}`);
   console.log(`Time to stake:  ${timeToStake}s`);
 
-  const ix = openMarket({
+  const auditLog = marketAccount.data.auditLogEnabled
+    ? (await getAuditLogAddress(marketAddress, PROGRAM_ID))[0]
+    : undefined;
+  const ix = await openMarket({
     marketAuthority: payer,
     market: marketAddress,
     platformConfig: marketAccount.data.platform,
     timeToStake,
+    auditLog,
     programAddress: PROGRAM_ID,
   });
 
@@ -24,7 +24,8 @@ import {
   initStakeAccount,
   getStakeAccountAddress,
   fetchOpportunityMarket,
-  randomComputationOffset,
+  getAuditLogAddress,
+  deriveComputationOffset,
   createCipher,
   generateX25519Keypair,
 } from "../js/src";
@@ -196,14 +197,19 @@ async function main() {
   const cipher = createCipher(userX25519Keypair.secretKey, mxePublicKey);
   const inputNonce = randomBytes(16);
   const optionCiphertext = cipher.encrypt([BigInt(optionId)], inputNonce);
-  const computationOffset = randomComputationOffset();
+  // First computation on the freshly initialized stake account.
+  const computationOffset = deriveComputationOffset(stakeAccountAddress, 0n);
 
   console.log(`\nStaking ${amount} tokens on option ${optionId}...`);
+  const auditLog = marketAccount.data.auditLogEnabled
+    ? (await getAuditLogAddress(marketAddress, PROGRAM_ID))[0]
+    : undefined;
   const stakeIx = await stake(
     {
       signer: payer,
       payer,
       market: marketAddress,
+      platformConfig: marketAccount.data.platform,
       stakeAccount: stakeAccountAddress,
       stakeAccountId,
       tokenMint,
@@ -215,6 +221,7 @@ async function main() {
       authorizedReaderNonce: deserializeLE(randomBytes(16)),
       userPubkey: userX25519Keypair.publicKey,
       stateNonce,
+      auditLog,
       programAddress: PROGRAM_ID,
     },
     {
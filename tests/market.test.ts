@@ -1,6 +1,6 @@
 import * as anchor from "@anchor-lang/core";
 import { Program } from "@anchor-lang/core";
-import { address, some, isNone, isSome, unwrapOption, createSolanaRpc, createSolanaRpcSubscriptions, sendAndConfirmTransactionFactory } from "@solana/kit";
+import { address, some, isNone, isSome, unwrapOption, createSolanaRpc, createSolanaRpcSubscriptions, sendAndConfirmTransactionFactory, type Address } from "@solana/kit";
 import { fetchToken } from "@solana-program/token";
 import { expect } from "chai";
 import {
@@ -12,6 +12,8 @@ import {
   OPPORTUNITY_MARKET_ERROR__INVALID_PARAMETERS,
   OPPORTUNITY_MARKET_ERROR__OPTION_STILL_NEEDED,
   OPPORTUNITY_MARKET_ERROR__REVEAL_PERIOD_NOT_OVER,
+  OPPORTUNITY_MARKET_ERROR__STAKE_MIGRATED,
+  OPPORTUNITY_MARKET_ERROR__PLATFORM_PAUSED,
 } from "../js/src";
 
 import { OpportunityMarket } from "../target/types/opportunity_market";
@@ -1338,6 +1340,87 @@ describe("OpportunityMarket", () => {
     expect(marketAtaBalance).to.equal(0n);
   });
 
+  it("void market returns the crank budget", async () => {
+    const budget = 50_000_000n;
+    const timeToStake = 10n;
+    const observer = loadObserverKeypair();
+
+    const platform = await Platform.initialize(provider, programId, {
+      rpcUrl: RPC_URL,
+      wsUrl: WS_URL,
+      numParticipants: 1,
+      airdropLamports: 2_000_000_000n,
+      initialTokenAmount: 2_000_000_000n,
+      marketConfig: {
+        rewardAmount: 0n,
+        timeToStake,
+        authorizedReaderPubkey: observer.publicKey,
+      },
+    });
+    const rpc = platform.getRpc();
+
+    // Nobody stakes, so the market is void once staking ends.
+    await platform.setParticipationThreshold(0n, 1);
+    await platform.fundCrankBudget(budget, 1_000_000n);
+    const stakeEnd = Number(await platform.openMarket());
+
+    await shouldThrowCustomError(
+      () => platform.withdrawCrankBudget(),
+      OPPORTUNITY_MARKET_ERROR__REVEAL_PERIOD_NOT_OVER,
+    );
+
+    await sleepUntilOnChainTimestamp(stakeEnd + ONCHAIN_TIMESTAMP_BUFFER_SECONDS, rpc);
+
+    const balanceBefore = (await rpc.getBalance(platform.market).send()).value;
+    await platform.withdrawCrankBudget();
+    const balanceAfter = (await rpc.getBalance(platform.market).send()).value;
+
+    expect(balanceBefore - balanceAfter).to.equal(budget);
+    expect((await platform.fetchMarket()).data.crankBudgetLamports).to.equal(0n);
+  });
+
+  it("expired market returns the crank budget", async () => {
+    const budget = 50_000_000n;
+    const timeToStake = 10n;
+    const marketResolutionDeadlineSeconds = 10n;
+    const observer = loadObserverKeypair();
+
+    const platform = await Platform.initialize(provider, programId, {
+      rpcUrl: RPC_URL,
+      wsUrl: WS_URL,
+      numParticipants: 1,
+      airdropLamports: 2_000_000_000n,
+      initialTokenAmount: 2_000_000_000n,
+      marketResolutionDeadlineSeconds,
+      marketConfig: {
+        rewardAmount: 0n,
+        timeToStake,
+        authorizedReaderPubkey: observer.publicKey,
+      },
+    });
+    const rpc = platform.getRpc();
+
+    await platform.fundCrankBudget(budget, 1_000_000n);
+    const stakeEnd = Number(await platform.openMarket());
+    await sleepUntilOnChainTimestamp(stakeEnd + ONCHAIN_TIMESTAMP_BUFFER_SECONDS, rpc);
+
+    // Still awaiting resolution.
+    await shouldThrowCustomError(
+      () => platform.withdrawCrankBudget(),
+      OPPORTUNITY_MARKET_ERROR__REVEAL_PERIOD_NOT_OVER,
+    );
+
+    const expiredAt = stakeEnd + Number(marketResolutionDeadlineSeconds);
+    await sleepUntilOnChainTimestamp(expiredAt + ONCHAIN_TIMESTAMP_BUFFER_SECONDS, rpc);
+
+    const balanceBefore = (await rpc.getBalance(platform.market).send()).value;
+    await platform.withdrawCrankBudget();
+    const balanceAfter = (await rpc.getBalance(platform.market).send()).value;
+
+    expect(balanceBefore - balanceAfter).to.equal(budget);
+    expect((await platform.fetchMarket()).data.crankBudgetLamports).to.equal(0n);
+  });
+
   it("rejects staking below the minimum stake amount", async () => {
     const minStakeAmount = 100_000_000n;
 
@@ -1373,6 +1456,114 @@ describe("OpportunityMarket", () => {
     expect(stakeAccount.data.amount > 0n).to.be.true;
   });
 
+  it("rejects revealing a stake migrated to a successor market", async () => {
+    const observer = loadObserverKeypair();
+
+    const platform = await Platform.initialize(provider, programId, {
+      rpcUrl: RPC_URL,
+      wsUrl: WS_URL,
+      numParticipants: 1,
+      airdropLamports: 2_000_000_000n,
+      initialTokenAmount: 2_000_000_000n,
+      marketConfig: {
+        rewardAmount: 0n,
+        timeToStake: 10n,
+        authorizedReaderPubkey: observer.publicKey,
+      },
+    });
+    const successor = await platform.createSuccessorMarket({ timeToStake: 60n });
+
+    await platform.openMarket();
+    await successor.openMarket();
+    const { optionId } = await platform.addOption();
+    const [staker] = platform.participants;
+    const stakeAccountId = await platform.stakeOnOption(staker, 100_000_000n, optionId);
+    const netStake = (await platform.fetchStakeAccountData(staker, stakeAccountId)).data.amount;
+
+    const successorStakeAccountId = await platform.migrateStake(staker, stakeAccountId, successor);
+    const migrated = (await platform.fetchStakeAccountData(staker, stakeAccountId)).data;
+    expect(migrated.migrated).to.be.true;
+    expect(migrated.amount).to.equal(0n);
+    expect(migrated.collectedFees.platformFee + migrated.collectedFees.rewardPoolFee + migrated.collectedFees.creatorFee).to.equal(0n);
+    const moved = (await successor.fetchStakeAccountData(staker, successorStakeAccountId)).data;
+    expect(moved.amount).to.equal(netStake);
+
+    // Once the old market resolves, its copy of the option must stay hidden.
+    await platform.waitForStakeEnd();
+    await platform.selectSingleWinningOption(optionId);
+    await shouldThrowCustomError(
+      () => platform.revealStake(staker, stakeAccountId),
+      OPPORTUNITY_MARKET_ERROR__STAKE_MIGRATED,
+    );
+  });
+
+  it("rejects verifying a stake disclosure while the platform is paused", async () => {
+    const observer = loadObserverKeypair();
+
+    const platform = await Platform.initialize(provider, programId, {
+      rpcUrl: RPC_URL,
+      wsUrl: WS_URL,
+      numParticipants: 1,
+      airdropLamports: 2_000_000_000n,
+      initialTokenAmount: 2_000_000_000n,
+      marketConfig: {
+        rewardAmount: 0n,
+        timeToStake: 60n,
+        authorizedReaderPubkey: observer.publicKey,
+      },
+    });
+
+    await platform.openMarket();
+    const { optionId } = await platform.addOption();
+    const user = platform.participants[0];
+    const stakeAccountId = await platform.stakeOnOption(user, 100_000_000n, optionId);
+
+    await platform.setPlatformPaused(true);
+    await shouldThrowCustomError(
+      () => platform.verifyStakeDisclosure(user, stakeAccountId, optionId),
+      OPPORTUNITY_MARKET_ERROR__PLATFORM_PAUSED,
+    );
+
+    await platform.setPlatformPaused(false);
+    await platform.verifyStakeDisclosure(user, stakeAccountId, optionId);
+  });
+
+  it("transfers the update authority after the nominee accepts", async () => {
+    const observer = loadObserverKeypair();
+
+    const platform = await Platform.initialize(provider, programId, {
+      rpcUrl: RPC_URL,
+      wsUrl: WS_URL,
+      numParticipants: 2,
+      airdropLamports: 2_000_000_000n,
+      initialTokenAmount: 2_000_000_000n,
+      marketConfig: {
+        rewardAmount: 0n,
+        timeToStake: 60n,
+        authorizedReaderPubkey: observer.publicKey,
+      },
+    });
+
+    const [nomineeId, outsiderId] = platform.participants;
+    const nominee = platform.getUserSigner(nomineeId);
+    const previousAuthority = (await platform.fetchPlatformConfig()).data.updateAuthority;
+
+    await platform.nominateUpdateAuthority(nominee.address);
+    let config = await platform.fetchPlatformConfig();
+    expect(config.data.updateAuthority).to.equal(previousAuthority);
+    expect(unwrapOption(config.data.pendingUpdateAuthority)).to.equal(nominee.address);
+
+    await shouldThrowCustomError(
+      () => platform.acceptUpdateAuthority(platform.getUserSigner(outsiderId)),
+      OPPORTUNITY_MARKET_ERROR__UNAUTHORIZED,
+    );
+
+    await platform.acceptUpdateAuthority(nominee);
+    config = await platform.fetchPlatformConfig();
+    expect(config.data.updateAuthority).to.equal(nominee.address);
+    expect(isNone(config.data.pendingUpdateAuthority)).to.be.true;
+  });
+
   it("reveal period authority can close immediately after resolution", async () => {
     const timeToStake = 5n;
     const observer = loadObserverKeypair();
@@ -1534,4 +1725,58 @@ describe("OpportunityMarket", () => {
     expect(await platform.accountExists(await platform.getStakeAccountAddress(staker1, sa1))).to.be.false;
     expect(await platform.accountExists(await platform.getStakeAccountAddress(staker2, sa2))).to.be.false;
   });
+
+  it("stakes and claims with a transfer hook mint", async () => {
+    // Token-2022 rejects a hooked mint's transfers unless the hook program is among the
+    // instruction's accounts, so each transfer below only succeeds if the program forwards it.
+    const transferHookProgram = address(anchor.workspace.TransferHookStub.programId.toBase58());
+    const rewardAmount = 1_000_000_000n;
+    const stakeAmount = 100_000_000n;
+
+    const observer = loadObserverKeypair();
+
+    const platform = await Platform.initialize(provider, programId, {
+      rpcUrl: RPC_URL,
+      wsUrl: WS_URL,
+      numParticipants: 1,
+      airdropLamports: 2_000_000_000n,
+      initialTokenAmount: 2_000_000_000n,
+      transferHookProgram,
+      marketConfig: {
+        rewardAmount,
+        timeToStake: 10n,
+        authorizedReaderPubkey: observer.publicKey,
+      },
+    });
+
+    // Token accounts of a hooked mint carry an extension past fetchToken's fixed layout,
+    // so read balances through the RPC instead.
+    const rpc = platform.getRpc();
+    const balanceOf = async (tokenAccount: Address) =>
+      BigInt((await rpc.getTokenAccountBalance(tokenAccount).send()).value.amount);
+
+    await platform.openMarket();
+    const [staker] = platform.participants;
+    const { optionId } = await platform.addOption();
+    const stakeAccountId = await platform.stakeOnOption(staker, stakeAmount, optionId);
+
+    await platform.waitForStakeEnd();
+    await platform.selectSingleWinningOption(optionId);
+    await platform.revealStake(staker, stakeAccountId);
+    await platform.finalizeRevealStake(staker, optionId, stakeAccountId);
+
+    const stakerTokenAccount = platform.getUserTokenAccount(staker);
+    const netStake = (await platform.fetchStakeAccountData(staker, stakeAccountId)).data.amount;
+    const balanceBeforeUnstake = await balanceOf(stakerTokenAccount);
+    await platform.unstake(staker, stakeAccountId);
+    const balanceAfterUnstake = await balanceOf(stakerTokenAccount);
+    expect(balanceAfterUnstake - balanceBeforeUnstake).to.equal(netStake);
+
+    await platform.endRevealPeriod();
+
+    // The only winner claims the whole reward pool.
+    await platform.closeStakeAccount(staker, optionId, stakeAccountId);
+    const balanceAfterClose = await balanceOf(stakerTokenAccount);
+    expect(balanceAfterClose - balanceAfterUnstake).to.equal(rewardAmount);
+  });
 });
@@ -16,6 +16,7 @@ import {
 } from "@solana/kit";
 import {
   getInitCompDefInstruction,
+  getInitializeCompDefsInstruction,
   getCompDefAccount,
   getCompDefOffsetNumber,
   type CompDefCircuitName,
@@ -127,4 +128,18 @@ export async function initializeAllCompDefs(
   for (const circuitName of (circuits ?? ALL_COMP_DEF_CIRCUITS)) {
     await initCompDef(rpc, sendAndConfirm, provider, payer, payerLegacy, programId, circuitName);
   }
+
+  // Creates bootstrap_state and program_metrics; create_market is refused until this has run.
+  const bootstrapIx = await getInitializeCompDefsInstruction(rpc, payer, { programAddress: programId });
+  const { value: latestBlockhash } = await rpc.getLatestBlockhash({ commitment: "confirmed" }).send();
+  const signedTransaction = await signTransactionMessageWithSigners(
+    pipe(
+      createTransactionMessage({ version: 0 }),
+      (msg) => setTransactionMessageFeePayer(payer.address, msg),
+      (msg) => setTransactionMessageLifetimeUsingBlockhash(latestBlockhash, msg),
+      (msg) => appendTransactionMessageInstructions([bootstrapIx], msg)
+    )
+  );
+  assertIsTransactionWithBlockhashLifetime(signedTransaction);
+  await sendAndConfirm(signedTransaction, { commitment: "confirmed" });
 }
@@ -13,6 +13,7 @@ import {
   lamports,
   sendAndConfirmTransactionFactory,
   unwrapOption,
+  type Instruction,
   type Rpc,
   type SolanaRpcApi,
 } from "@solana/kit";
@@ -24,10 +25,11 @@ import {
 import {
   createMarket,
   fetchOpportunityMarket,
+  fetchPlatformConfig,
   getPlatformConfigAddress,
   claimFees as claimFeesIx,
   claimCreatorFees as claimCreatorFeesIx,
-  randomComputationOffset,
+  deriveComputationOffset,
   createPlatformConfig,
   addMarketOption,
   initStakeAccount,
@@ -52,12 +54,31 @@ import {
   getOpportunityMarketOptionAddress,
   fetchOpportunityMarketOption,
   getOpportunityMarketAddress,
+  fetchMarketResult,
+  getMarketResultAddress,
+  setTransferHookPrograms,
+  setSuccessorMarket as setSuccessorMarketIx,
+  migrateStake as migrateStakeIx,
+  setParticipationThreshold as setParticipationThresholdIx,
+  fundCrankBudget as fundCrankBudgetIx,
+  withdrawCrankBudget as withdrawCrankBudgetIx,
+  setPlatformPaused as setPlatformPausedIx,
+  setUpdateAuthority as setUpdateAuthorityIx,
+  acceptUpdateAuthority as acceptUpdateAuthorityIx,
+  verifyStakeDisclosure as verifyStakeDisclosureIx,
+  withRemainingAccounts,
 } from "../../js/src";
 import { randomBytes } from "crypto";
 import * as anchor from "@anchor-lang/core";
 import { PublicKey } from "@solana/web3.js";
 import { generateX25519Keypair, X25519Keypair, createCipher } from "../../js/src/x25519/keypair";
-import { createTokenMint, createAta, mintTokensTo } from "./spl-token";
+import {
+  createTokenMint,
+  createTransferHookMint,
+  createAta,
+  mintTokensTo,
+  TOKEN_2022_PROGRAM_ADDRESS,
+} from "./spl-token";
 import { sendTransaction, type SendAndConfirmFn } from "./transaction";
 import { nonceToBytes } from "./nonce";
 import { getDeployerKeypair } from "./deployer";
@@ -114,6 +135,8 @@ export interface PlatformConfigArgs {
   revealPeriodSeconds?: bigint;
   revealAuthority?: Address;
   name?: string;
+  /** Use a Token-2022 mint whose transfers call this hook program; the platform allowlists it. */
+  transferHookProgram?: Address;
 }
 
 // Batch input types
@@ -150,7 +173,7 @@ export interface CloseRequest {
 // Default Configuration
 // ============================================================================
 
-const DEFAULT_CONFIG: Required<Omit<PlatformConfigArgs, "name">> = {
+const DEFAULT_CONFIG: Required<Omit<PlatformConfigArgs, "name" | "transferHookProgram">> = {
   rpcUrl: "http://127.0.0.1:8899",
   wsUrl: "ws://127.0.0.1:8900",
   numParticipants: 2,
@@ -225,12 +248,17 @@ export class Platform {
 
   // Market
   private mint: KeyPairSigner;
+  private tokenProgram: Address;
+  // Passed to every instruction that moves tokens, so Token-2022 can call the mint's hook.
+  private transferHookAccounts: Address[];
   private marketAddress: Address;
   private platformConfigAddress: Address;
   private platformName: string;
   private marketCreator: TestUser;
   private marketConfig: MarketConfig;
   private usedOptionIds: Set<number>;
+  // Reward bp per option set by set_winning_option; resolve_market takes these options.
+  private winningOptionBps: Map<number, number>;
   private stakeEndTimestamp: bigint | null = null;
 
   // Users: Map<address string, TestUser>
@@ -240,6 +268,7 @@ export class Platform {
     // Private constructor - use static initialize()
     this.users = new Map();
     this.usedOptionIds = new Set();
+    this.winningOptionBps = new Map();
   }
 
   // ============================================================================
@@ -344,13 +373,39 @@ export class Platform {
       label: `Create platform config (${platformName})`,
     });
 
-    console.log("Creating SPL token mint...");
-    runner.mint = await createTokenMint(
-      runner.rpc,
-      runner.sendAndConfirm,
-      creatorAccountBase.keypair,
-      creatorAccountBase.keypair.address
-    );
+    const { transferHookProgram } = config;
+    if (transferHookProgram) {
+      console.log("Allowlisting transfer hook program...");
+      const setHooksIx = await setTransferHookPrograms({
+        updateAuthority: deployer,
+        platformConfig: platformConfigAddress,
+        transferHookPrograms: [transferHookProgram],
+      });
+      await sendTransaction(runner.rpc, runner.sendAndConfirm, deployer, [setHooksIx], {
+        label: "Set transfer hook programs",
+      });
+
+      console.log("Creating Token-2022 mint with transfer hook...");
+      runner.mint = await createTransferHookMint(
+        runner.rpc,
+        runner.sendAndConfirm,
+        creatorAccountBase.keypair,
+        creatorAccountBase.keypair.address,
+        transferHookProgram
+      );
+      runner.tokenProgram = TOKEN_2022_PROGRAM_ADDRESS;
+      runner.transferHookAccounts = [transferHookProgram];
+    } else {
+      console.log("Creating SPL token mint...");
+      runner.mint = await createTokenMint(
+        runner.rpc,
+        runner.sendAndConfirm,
+        creatorAccountBase.keypair,
+        creatorAccountBase.keypair.address
+      );
+      runner.tokenProgram = TOKEN_PROGRAM_ADDRESS;
+      runner.transferHookAccounts = [];
+    }
     console.log(`  Mint created: ${runner.mint.address}`);
 
     console.log("Whitelisting mint on platform...");
@@ -377,7 +432,8 @@ export class Platform {
         runner.sendAndConfirm,
         creatorAccountBase.keypair,
         runner.mint.address,
-        account.keypair.address
+        account.keypair.address,
+        runner.tokenProgram
       );
       await mintTokensTo(
         runner.rpc,
@@ -385,7 +441,8 @@ export class Platform {
         creatorAccountBase.keypair,
         runner.mint.address,
         ata,
-        initialTokenAmount
+        initialTokenAmount,
+        runner.tokenProgram
       );
       accountsWithTokens.push({
         keypair: account.keypair,
@@ -419,44 +476,7 @@ export class Platform {
     // Also add creator to users map so they can be looked up
     runner.users.set(creatorAcc.keypair.address.toString(), runner.marketCreator);
 
-    // Create the market
-    console.log("Creating market...");
-    const marketIndex = BigInt(Math.floor(Math.random() * 1000000));
-
-    const createMarketIx = await createMarket({
-      creator: runner.marketCreator.solanaKeypair,
-      platformConfig: runner.platformConfigAddress,
-      tokenMint: runner.mint.address,
-      tokenProgram: TOKEN_PROGRAM_ADDRESS,
-      marketIndex,
-      marketAuthority: runner.marketCreator.solanaKeypair.address,
-      allowUnstakingEarly: marketConfig.allowUnstakingEarly,
-      authorizedReaderPubkey: marketConfig.authorizedReaderPubkey,
-      earlinessCutoffSeconds: marketConfig.earlinessCutoffSeconds,
-      earlinessMultiplier: marketConfig.earlinessMultiplier,
-      minStakeAmount: marketConfig.minStakeAmount,
-      creatorFeeClaimer:
-        marketConfig.marketFeeClaimer ?? runner.marketCreator.solanaKeypair.address,
-    });
-
-    await sendTransaction(runner.rpc, runner.sendAndConfirm, runner.marketCreator.solanaKeypair, [createMarketIx], {
-      label: "Create market",
-    });
-
-    const [derivedMarket] = await getOpportunityMarketAddress(
-      runner.platformConfigAddress,
-      runner.marketCreator.solanaKeypair.address,
-      marketIndex,
-      programId,
-    );
-    runner.marketAddress = derivedMarket;
-    console.log(`  Market created: ${runner.marketAddress}`);
-
-    // Add initial reward from creator if configured
-    if (marketConfig.rewardAmount > 0n) {
-      await runner.addReward(runner.marketCreator.solanaKeypair.address, marketConfig.rewardAmount, true);
-      console.log(`  Creator added reward: ${marketConfig.rewardAmount}`);
-    }
+    await runner.createMarketAccount();
 
     return runner;
   }
@@ -511,6 +531,10 @@ export class Platform {
     user.stakeAccounts.push(info);
   }
 
+  private withTransferHook<TInstruction extends Instruction>(ix: TInstruction): TInstruction {
+    return withRemainingAccounts(ix, this.transferHookAccounts);
+  }
+
   private assertComputationSucceeded(result: ComputationResult, operation: string): void {
     if (result.error) {
       throw new Error(`${operation} computation callback failed: ${result.error}`);
@@ -521,13 +545,78 @@ export class Platform {
   // Market Operations
   // ============================================================================
 
+  private async createMarketAccount(): Promise<void> {
+    const marketConfig = this.marketConfig;
+    console.log("Creating market...");
+    const marketIndex = BigInt(Math.floor(Math.random() * 1000000));
+
+    const createMarketIx = await createMarket({
+      creator: this.marketCreator.solanaKeypair,
+      platformConfig: this.platformConfigAddress,
+      tokenMint: this.mint.address,
+      tokenProgram: this.tokenProgram,
+      marketIndex,
+      marketAuthority: this.marketCreator.solanaKeypair.address,
+      allowUnstakingEarly: marketConfig.allowUnstakingEarly,
+      authorizedReaderPubkey: marketConfig.authorizedReaderPubkey,
+      earlinessCutoffSeconds: marketConfig.earlinessCutoffSeconds,
+      earlinessMultiplier: marketConfig.earlinessMultiplier,
+      minStakeAmount: marketConfig.minStakeAmount,
+      creatorFeeClaimer:
+        marketConfig.marketFeeClaimer ?? this.marketCreator.solanaKeypair.address,
+    });
+
+    await sendTransaction(this.rpc, this.sendAndConfirm, this.marketCreator.solanaKeypair, [createMarketIx], {
+      label: "Create market",
+    });
+
+    const [derivedMarket] = await getOpportunityMarketAddress(
+      this.platformConfigAddress,
+      this.marketCreator.solanaKeypair.address,
+      marketIndex,
+      this.programId,
+    );
+    this.marketAddress = derivedMarket;
+    console.log(`  Market created: ${this.marketAddress}`);
+
+    // Add initial reward from creator if configured
+    if (marketConfig.rewardAmount > 0n) {
+      await this.addReward(this.marketCreator.solanaKeypair.address, marketConfig.rewardAmount, true);
+      console.log(`  Creator added reward: ${marketConfig.rewardAmount}`);
+    }
+  }
+
+  /**
+   * Creates another market on the same platform, mint and users, and sets it as this market's
+   * successor. Returns a runner for the new market.
+   */
+  async createSuccessorMarket(marketConfig: Partial<MarketConfig> = {}): Promise<Platform> {
+    const successor = Object.assign(new Platform(), this, {
+      marketConfig: { ...this.marketConfig, ...marketConfig },
+      usedOptionIds: new Set<number>(),
+      winningOptionBps: new Map<number, number>(),
+      stakeEndTimestamp: null,
+    });
+    await successor.createMarketAccount();
+
+    const ix = await setSuccessorMarketIx({
+      creator: this.marketCreator.solanaKeypair,
+      market: this.marketAddress,
+      successorMarket: successor.market,
+    });
+    await sendTransaction(this.rpc, this.sendAndConfirm, this.marketCreator.solanaKeypair, [ix], {
+      label: "Set successor market",
+    });
+    return successor;
+  }
+
   async fundMarket(amount?: bigint): Promise<void> {
     const fundingAmount = amount ?? this.marketConfig.rewardAmount;
 
     const [marketAta] = await findAssociatedTokenPda({
       mint: this.mint.address,
       owner: this.marketAddress,
-      tokenProgram: TOKEN_PROGRAM_ADDRESS,
+      tokenProgram: this.tokenProgram,
     });
 
     const ix = getTransferInstruction({
@@ -543,7 +632,7 @@ export class Platform {
   }
 
   async openMarket(): Promise<bigint> {
-    const ix = openMarketIx({
+    const ix = await openMarketIx({
       marketAuthority: this.marketCreator.solanaKeypair,
       market: this.marketAddress,
       platformConfig: this.platformConfigAddress,
@@ -577,9 +666,15 @@ export class Platform {
       ),
     );
 
-    const resolveIx = resolveMarketIx({
+    const winningOptions = await Promise.all(
+      selections
+        .filter(({ rewardBp }) => rewardBp > 0)
+        .map(({ optionId }) => this.getOptionAddress(optionId)),
+    );
+    const resolveIx = await resolveMarketIx({
       marketAuthority: this.marketCreator.solanaKeypair,
       market: this.marketAddress,
+      winningOptions,
     });
 
     await sendTransaction(
@@ -589,6 +684,9 @@ export class Platform {
       [...setIxs, resolveIx],
       { label: "Set winning options and resolve market" },
     );
+    for (const { optionId, rewardBp } of selections) {
+      this.recordWinningOption(optionId, rewardBp);
+    }
   }
 
   async selectSingleWinningOption(optionId: number): Promise<void> {
@@ -606,12 +704,25 @@ export class Platform {
     await sendTransaction(this.rpc, this.sendAndConfirm, this.marketCreator.solanaKeypair, [ix], {
       label: `Set winning option ${optionId} = ${rewardBp} bp`,
     });
+    this.recordWinningOption(optionId, rewardBp);
+  }
+
+  private recordWinningOption(optionId: number, rewardBp: number): void {
+    if (rewardBp > 0) {
+      this.winningOptionBps.set(optionId, rewardBp);
+    } else {
+      this.winningOptionBps.delete(optionId);
+    }
   }
 
   async resolveMarket(): Promise<void> {
-    const ix = resolveMarketIx({
+    const winningOptions = await Promise.all(
+      Array.from(this.winningOptionBps.keys()).map((optionId) => this.getOptionAddress(optionId)),
+    );
+    const ix = await resolveMarketIx({
       marketAuthority: this.marketCreator.solanaKeypair,
       market: this.marketAddress,
+      winningOptions,
     });
 
     await sendTransaction(this.rpc, this.sendAndConfirm, this.marketCreator.solanaKeypair, [ix], {
@@ -622,15 +733,16 @@ export class Platform {
   async addReward(userId: Address, amount: bigint, lock: boolean = false): Promise<void> {
     const user = this.getUser(userId);
 
-    const ix = await addRewardIx({
+    const ix = this.withTransferHook(await addRewardIx({
       sponsor: user.solanaKeypair,
       market: this.marketAddress,
+      platformConfig: this.platformConfigAddress,
       tokenMint: this.mint.address,
       sponsorTokenAccount: user.tokenAccount,
-      tokenProgram: TOKEN_PROGRAM_ADDRESS,
+      tokenProgram: this.tokenProgram,
       amount,
       lock,
-    });
+    }));
 
     await sendTransaction(this.rpc, this.sendAndConfirm, user.solanaKeypair, [ix], {
       label: "Add reward",
@@ -642,13 +754,14 @@ export class Platform {
     const user = this.getUser(sponsorId);
     const refund = refundTokenAccount ?? user.tokenAccount;
 
-    const ix = await withdrawRewardIx({
+    const ix = this.withTransferHook(await withdrawRewardIx({
       sponsor: user.solanaKeypair,
       market: this.marketAddress,
+      platformConfig: this.platformConfigAddress,
       tokenMint: this.mint.address,
       refundTokenAccount: refund,
-      tokenProgram: TOKEN_PROGRAM_ADDRESS,
-    });
+      tokenProgram: this.tokenProgram,
+    }));
 
     await sendTransaction(this.rpc, this.sendAndConfirm, user.solanaKeypair, [ix], {
       label: "Withdraw reward",
@@ -656,10 +769,17 @@ export class Platform {
   }
 
   async endRevealPeriod(signer?: KeyPairSigner): Promise<void> {
-    const ix = endRevealPeriodIx({
+    // The winners' totals are snapshotted from their option accounts, in the stored order.
+    const [marketResultAddress] = await getMarketResultAddress(this.marketAddress);
+    const marketResult = await fetchMarketResult(this.rpc, marketResultAddress);
+    const winningOptions = await Promise.all(
+      marketResult.data.winningOptions.map(({ optionId }) => this.getOptionAddress(Number(optionId))),
+    );
+    const ix = await endRevealPeriodIx({
       signer: signer ?? this.marketCreator.solanaKeypair,
       platformConfig: this.platformConfigAddress,
       market: this.marketAddress,
+      winningOptions,
     });
 
     await sendTransaction(
@@ -738,18 +858,20 @@ export class Platform {
           // 2. stake
           const inputNonce = randomBytes(16);
           const optionCiphertext = cipher.encrypt([BigInt(p.optionId)], inputNonce);
-          const computationOffset = randomComputationOffset();
+          // The account is created in the same transaction, so this is its first computation.
+          const computationOffset = deriveComputationOffset(stakeAccountAddress, 0n);
 
-          const stakeInstruction = await stakeIx(
+          const stakeInstruction = this.withTransferHook(await stakeIx(
             {
               signer: user.solanaKeypair,
               payer: user.solanaKeypair,
               market: this.marketAddress,
+              platformConfig: this.platformConfigAddress,
               stakeAccount: stakeAccountAddress,
               stakeAccountId,
               tokenMint: this.mint.address,
               signerTokenAccount: user.tokenAccount,
-              tokenProgram: TOKEN_PROGRAM_ADDRESS,
+              tokenProgram: this.tokenProgram,
               amount: p.amount,
               selectedOptionCiphertext: optionCiphertext[0],
               inputNonce: deserializeLE(inputNonce),
@@ -758,7 +880,7 @@ export class Platform {
               stateNonce: stakeAccountNonce,
             },
             this.getArciumConfig(computationOffset)
-          );
+          ));
 
           await sendTransaction(
             this.rpc,
@@ -793,6 +915,49 @@ export class Platform {
     return results.map((r) => r.stakeAccountId);
   }
 
+  /**
+   * Moves a stake into the successor market with migrate_stake and waits for the re-encryption.
+   * Returns the stake account ID in the successor.
+   */
+  async migrateStake(userId: Address, stakeAccountId: number, successor: Platform): Promise<number> {
+    const user = this.getUser(userId);
+    const toStakeAccountId = this.getNextStakeAccountId(user);
+    const [toStakeAccountAddress] = await getStakeAccountAddressPda(userId, successor.market, toStakeAccountId);
+
+    const initIx = await initStakeAccount({
+      payer: user.solanaKeypair,
+      owner: userId,
+      market: successor.market,
+      stakeAccountId: toStakeAccountId,
+    });
+    // The account is created in the same transaction, so this is its first computation.
+    const computationOffset = deriveComputationOffset(toStakeAccountAddress, 0n);
+    const ix = this.withTransferHook(await migrateStakeIx(
+      {
+        signer: user.solanaKeypair,
+        payer: user.solanaKeypair,
+        fromMarket: this.marketAddress,
+        fromStakeAccountId: stakeAccountId,
+        toMarket: successor.market,
+        toStakeAccountId,
+        platformConfig: this.platformConfigAddress,
+        tokenMint: this.mint.address,
+        tokenProgram: this.tokenProgram,
+        authorizedReaderNonce: deserializeLE(randomBytes(16)),
+        stateNonce: deserializeLE(randomBytes(16)),
+      },
+      this.getArciumConfig(computationOffset)
+    ));
+
+    await sendTransaction(this.rpc, this.sendAndConfirm, user.solanaKeypair, [initIx, ix], {
+      label: "Migrate stake",
+    });
+
+    const result = await awaitComputationFinalization(this.rpc, computationOffset);
+    this.assertComputationSucceeded(result, "migrateStake");
+    return toStakeAccountId;
+  }
+
   async stakeOnOption(
     userId: Address,
     amount: bigint,
@@ -805,13 +970,18 @@ export class Platform {
   async revealStakeBatch(reveals: RevealRequest[]): Promise<void> {
     for (const r of reveals) {
       const user = this.getUser(r.userId);
-      const computationOffset = randomComputationOffset();
+      const stakeAccount = await this.fetchStakeAccountData(r.userId, r.stakeAccountId);
+      const computationOffset = deriveComputationOffset(
+        stakeAccount.address,
+        stakeAccount.data.nextComputationSeq,
+      );
 
       const ix = await revealStake(
         {
           signer: user.solanaKeypair,
           owner: user.solanaKeypair.address,
           market: this.marketAddress,
+          platformConfig: this.platformConfigAddress,
           stakeAccountId: r.stakeAccountId,
         },
         this.getArciumConfig(computationOffset)
@@ -826,6 +996,37 @@ export class Platform {
     }
   }
 
+  async verifyStakeDisclosure(
+    userId: Address,
+    stakeAccountId: number,
+    claimedOption: number,
+  ): Promise<void> {
+    const user = this.getUser(userId);
+    const stakeAccount = await this.fetchStakeAccountData(userId, stakeAccountId);
+    const computationOffset = deriveComputationOffset(
+      stakeAccount.address,
+      stakeAccount.data.nextComputationSeq,
+    );
+
+    const ix = await verifyStakeDisclosureIx(
+      {
+        signer: user.solanaKeypair,
+        market: this.marketAddress,
+        platformConfig: this.platformConfigAddress,
+        stakeAccountId,
+        claimedOption: BigInt(claimedOption),
+      },
+      this.getArciumConfig(computationOffset)
+    );
+
+    await sendTransaction(this.rpc, this.sendAndConfirm, user.solanaKeypair, [ix], {
+      label: "Verify stake disclosure",
+    });
+
+    const result = await awaitComputationFinalization(this.rpc, computationOffset);
+    this.assertComputationSucceeded(result, "verifyStakeDisclosure");
+  }
+
   async revealStake(userId: Address, stakeAccountId: number): Promise<void> {
     await this.revealStakeBatch([{ userId, stakeAccountId }]);
   }
@@ -860,15 +1061,16 @@ export class Platform {
     const instructions = await Promise.all(
       closes.map(async (close) => {
         const user = this.getUser(close.userId);
-        const ix = await closeStakeAccount({
+        const ix = this.withTransferHook(await closeStakeAccount({
           owner: user.solanaKeypair,
           market: this.marketAddress,
+          platformConfig: this.platformConfigAddress,
           tokenMint: this.mint.address,
           ownerTokenAccount: user.tokenAccount,
-          tokenProgram: TOKEN_PROGRAM_ADDRESS,
+          tokenProgram: this.tokenProgram,
           optionId: close.optionId,
           stakeAccountId: close.stakeAccountId,
-        });
+        }));
         return { user, ix };
       })
     );
@@ -931,18 +1133,19 @@ export class Platform {
     // Build stake instruction
     const inputNonce = randomBytes(16);
     const optionCiphertext = cipher.encrypt([BigInt(optionId)], inputNonce);
-    const computationOffset = randomComputationOffset();
+    const computationOffset = deriveComputationOffset(stakeAccountAddress, 0n);
 
-    const stakeInstruction = await stakeIx(
+    const stakeInstruction = this.withTransferHook(await stakeIx(
       {
         signer: user.solanaKeypair,
         payer: user.solanaKeypair,
         market: this.marketAddress,
+        platformConfig: this.platformConfigAddress,
         stakeAccount: stakeAccountAddress,
         stakeAccountId,
         tokenMint: this.mint.address,
         signerTokenAccount: user.tokenAccount,
-        tokenProgram: TOKEN_PROGRAM_ADDRESS,
+        tokenProgram: this.tokenProgram,
         amount,
         selectedOptionCiphertext: optionCiphertext[0],
         inputNonce: deserializeLE(inputNonce),
@@ -951,17 +1154,17 @@ export class Platform {
         stateNonce: stakeAccountNonce,
       },
       this.getArciumConfig(computationOffset)
-    );
+    ));
 
     // Build close stuck instruction (codama auto-derives tokenVault/tokenVaultAta from tokenMint)
-    const closeStuckIx = await closeStuckStakeAccountIx({
+    const closeStuckIx = this.withTransferHook(await closeStuckStakeAccountIx({
       signer: user.solanaKeypair,
       market: this.marketAddress,
       tokenMint: this.mint.address,
       signerTokenAccount: user.tokenAccount,
-      tokenProgram: TOKEN_PROGRAM_ADDRESS,
+      tokenProgram: this.tokenProgram,
       stakeAccountId,
-    });
+    }));
 
     // Send both in the same transaction
     await sendTransaction(this.rpc, this.sendAndConfirm, user.solanaKeypair, [stakeInstruction, closeStuckIx], {
@@ -976,15 +1179,16 @@ export class Platform {
       const owner = this.getUser(r.userId);
       const signer = r.signerId ? this.getUser(r.signerId) : owner;
 
-      const ix = await unstakeIx({
+      const ix = this.withTransferHook(await unstakeIx({
         signer: signer.solanaKeypair,
         owner: owner.solanaKeypair.address,
         market: this.marketAddress,
+        platformConfig: this.platformConfigAddress,
         tokenMint: this.mint.address,
         ownerTokenAccount: owner.tokenAccount,
-        tokenProgram: TOKEN_PROGRAM_ADDRESS,
+        tokenProgram: this.tokenProgram,
         stakeAccountId: r.stakeAccountId,
-      });
+      }));
 
       await sendTransaction(this.rpc, this.sendAndConfirm, signer.solanaKeypair, [ix], {
         label: `Unstake`,
@@ -1001,14 +1205,14 @@ export class Platform {
   // ============================================================================
 
   async claimFees(): Promise<void> {
-    const ix = await claimFeesIx({
+    const ix = this.withTransferHook(await claimFeesIx({
       signer: this.marketCreator.solanaKeypair,
       market: this.marketAddress,
       platformConfig: this.platformConfigAddress,
       tokenMint: this.mint.address,
       destinationTokenAccount: this.marketCreator.tokenAccount,
-      tokenProgram: TOKEN_PROGRAM_ADDRESS,
-    });
+      tokenProgram: this.tokenProgram,
+    }));
 
     await sendTransaction(this.rpc, this.sendAndConfirm, this.marketCreator.solanaKeypair, [ix], {
       label: "Claim fees",
@@ -1016,19 +1220,101 @@ export class Platform {
   }
 
   async claimCreatorFees(destinationTokenAccount?: Address): Promise<void> {
-    const ix = await claimCreatorFeesIx({
+    const ix = this.withTransferHook(await claimCreatorFeesIx({
       signer: this.marketCreator.solanaKeypair,
       market: this.marketAddress,
       tokenMint: this.mint.address,
       destinationTokenAccount: destinationTokenAccount ?? this.marketCreator.tokenAccount,
-      tokenProgram: TOKEN_PROGRAM_ADDRESS,
-    });
+      tokenProgram: this.tokenProgram,
+    }));
 
     await sendTransaction(this.rpc, this.sendAndConfirm, this.marketCreator.solanaKeypair, [ix], {
       label: "Claim creator fees",
     });
   }
 
+  // ============================================================================
+  // Platform Settings
+  // ============================================================================
+
+  async setPlatformPaused(paused: boolean): Promise<void> {
+    const deployer = await getDeployerKeypair();
+    const ix = await setPlatformPausedIx({
+      updateAuthority: deployer,
+      platformConfig: this.platformConfigAddress,
+      paused,
+    });
+
+    await sendTransaction(this.rpc, this.sendAndConfirm, deployer, [ix], {
+      label: `Set platform paused = ${paused}`,
+    });
+  }
+
+  async nominateUpdateAuthority(newAuthority: Address): Promise<void> {
+    const deployer = await getDeployerKeypair();
+    const ix = await setUpdateAuthorityIx({
+      updateAuthority: deployer,
+      platformConfig: this.platformConfigAddress,
+      newAuthority,
+    });
+
+    await sendTransaction(this.rpc, this.sendAndConfirm, deployer, [ix], {
+      label: "Nominate update authority",
+    });
+  }
+
+  async acceptUpdateAuthority(newAuthority: KeyPairSigner): Promise<void> {
+    const ix = await acceptUpdateAuthorityIx({
+      newAuthority,
+      platformConfig: this.platformConfigAddress,
+    });
+
+    await sendTransaction(this.rpc, this.sendAndConfirm, newAuthority, [ix], {
+      label: "Accept update authority",
+    });
+  }
+
+  // ============================================================================
+  // Market Settings
+  // ============================================================================
+
+  async setParticipationThreshold(minTotalStake: bigint, minParticipants: number): Promise<void> {
+    const ix = await setParticipationThresholdIx({
+      creator: this.marketCreator.solanaKeypair,
+      market: this.marketAddress,
+      minTotalStake,
+      minParticipants,
+    });
+
+    await sendTransaction(this.rpc, this.sendAndConfirm, this.marketCreator.solanaKeypair, [ix], {
+      label: "Set participation threshold",
+    });
+  }
+
+  async fundCrankBudget(lamports: bigint, crankBountyLamports: bigint): Promise<void> {
+    const ix = await fundCrankBudgetIx({
+      creator: this.marketCreator.solanaKeypair,
+      market: this.marketAddress,
+      lamports,
+      crankBountyLamports,
+    });
+
+    await sendTransaction(this.rpc, this.sendAndConfirm, this.marketCreator.solanaKeypair, [ix], {
+      label: "Fund crank budget",
+    });
+  }
+
+  async withdrawCrankBudget(): Promise<void> {
+    const ix = await withdrawCrankBudgetIx({
+      creator: this.marketCreator.solanaKeypair,
+      market: this.marketAddress,
+    });
+
+    await sendTransaction(this.rpc, this.sendAndConfirm, this.marketCreator.solanaKeypair, [ix], {
+      label: "Withdraw crank budget",
+    });
+  }
+
   // ============================================================================
   // Utility Methods for Tests
   // ============================================================================
@@ -1057,6 +1343,10 @@ export class Platform {
     return fetchOpportunityMarket(this.rpc, this.marketAddress);
   }
 
+  async fetchPlatformConfig() {
+    return fetchPlatformConfig(this.rpc, this.platformConfigAddress);
+  }
+
   get platformConfig(): Address {
     return this.platformConfigAddress;
   }
@@ -1152,7 +1442,7 @@ export class Platform {
     const [ata] = await findAssociatedTokenPda({
       mint: this.mint.address,
       owner: this.marketAddress,
-      tokenProgram: TOKEN_PROGRAM_ADDRESS,
+      tokenProgram: this.tokenProgram,
     });
     return ata;
   }
@@ -1,7 +1,11 @@
 import {
   type Address,
+  type Instruction,
   type KeyPairSigner,
+  AccountRole,
+  address,
   generateKeyPairSigner,
+  getAddressEncoder,
 } from "@solana/kit";
 import { getCreateAccountInstruction } from "@solana-program/system";
 import {
@@ -14,6 +18,14 @@ import {
 } from "@solana-program/token";
 import { sendTransaction, type RpcClient, type SendAndConfirmFn } from "./transaction";
 
+export const TOKEN_2022_PROGRAM_ADDRESS = address("TokenzQdBNbLqP5VEhdkAS6EPFLC1PQnBqCXEpPxuEb");
+
+// Token-2022 mint layout: the base mint padded to the account size, the account type byte,
+// then a TransferHook TLV entry (type + length + authority + program id).
+const TRANSFER_HOOK_MINT_SIZE = 165 + 1 + 2 + 2 + 32 + 32;
+const TRANSFER_HOOK_EXTENSION_INSTRUCTION = 36;
+const TRANSFER_HOOK_INITIALIZE = 0;
+
 /**
  * Creates a new SPL token mint.
  *
@@ -51,6 +63,60 @@ export async function createTokenMint(
   return mint;
 }
 
+/**
+ * Creates a Token-2022 mint whose transfers call `hookProgram`. The hook authority is left
+ * unset, so the hook program can't be changed later.
+ *
+ * @returns The mint KeyPairSigner (address accessible via `.address`)
+ */
+export async function createTransferHookMint(
+  rpc: RpcClient,
+  sendAndConfirm: SendAndConfirmFn,
+  payer: KeyPairSigner,
+  mintAuthority: Address,
+  hookProgram: Address,
+  decimals: number = 0,
+): Promise<KeyPairSigner> {
+  const mint = await generateKeyPairSigner();
+  const space = BigInt(TRANSFER_HOOK_MINT_SIZE);
+  const rent = await rpc.getMinimumBalanceForRentExemption(space).send();
+
+  const createAccountIx = getCreateAccountInstruction({
+    payer,
+    newAccount: mint,
+    lamports: rent,
+    space,
+    programAddress: TOKEN_2022_PROGRAM_ADDRESS,
+  });
+
+  // An all-zero authority is the unset OptionalNonZeroPubkey.
+  const initTransferHookIx: Instruction = {
+    programAddress: TOKEN_2022_PROGRAM_ADDRESS,
+    accounts: [{ address: mint.address, role: AccountRole.WRITABLE }],
+    data: new Uint8Array([
+      TRANSFER_HOOK_EXTENSION_INSTRUCTION,
+      TRANSFER_HOOK_INITIALIZE,
+      ...new Uint8Array(32),
+      ...getAddressEncoder().encode(hookProgram),
+    ]),
+  };
+
+  const initMintIx = getInitializeMintInstruction(
+    {
+      mint: mint.address,
+      decimals,
+      mintAuthority,
+    },
+    { programAddress: TOKEN_2022_PROGRAM_ADDRESS },
+  );
+
+  await sendTransaction(rpc, sendAndConfirm, payer, [createAccountIx, initTransferHookIx, initMintIx], {
+    label: "Create transfer hook mint",
+  });
+
+  return mint;
+}
+
 /**
  * Creates an Associated Token Account for a given owner and mint.
  *
@@ -62,11 +128,13 @@ export async function createAta(
   payer: KeyPairSigner,
   mint: Address,
   owner: Address,
+  tokenProgram: Address = TOKEN_PROGRAM_ADDRESS,
 ): Promise<Address> {
   const createAtaIx = await getCreateAssociatedTokenInstructionAsync({
     payer,
     mint,
     owner,
+    tokenProgram,
   });
 
   await sendTransaction(rpc, sendAndConfirm, payer, [createAtaIx], {
@@ -76,7 +144,7 @@ export async function createAta(
   const [ataAddress] = await findAssociatedTokenPda({
     mint,
     owner,
-    tokenProgram: TOKEN_PROGRAM_ADDRESS,
+    tokenProgram,
   });
 
   return ataAddress;
@@ -94,13 +162,17 @@ export async function mintTokensTo(
   mint: Address,
   destination: Address,
   amount: bigint,
+  tokenProgram: Address = TOKEN_PROGRAM_ADDRESS,
 ): Promise<void> {
-  const mintToIx = getMintToInstruction({
-    mint,
-    token: destination,
-    mintAuthority,
-    amount,
-  });
+  const mintToIx = getMintToInstruction(
+    {
+      mint,
+      token: destination,
+      mintAuthority,
+      amount,
+    },
+    { programAddress: tokenProgram },
+  );
 
   await sendTransaction(rpc, sendAndConfirm, mintAuthority, [mintToIx], {
     label: `Mint ${amount} tokens`,